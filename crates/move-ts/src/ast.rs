@@ -0,0 +1,819 @@
+//! A small TypeScript AST built by the generators and rendered by [crate::printer].
+//!
+//! Nodes describe typed TypeScript. The printer strips types when emitting JavaScript and
+//! derives the declared types of values when emitting declaration files, so generators only
+//! build a single tree per file.
+
+use anyhow::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::ops::Range;
+
+/// A generated file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Module {
+    /// Documentation of the file, rendered with a `@module` tag.
+    pub docs: Option<String>,
+    pub items: Vec<Stmt>,
+    /// Named ranges of [Self::items], which templates can place individually.
+    pub sections: Vec<(String, Range<usize>)>,
+}
+
+impl Module {
+    pub fn new() -> Self {
+        Module::default()
+    }
+
+    /// Sets the documentation of the file.
+    pub fn docs(self, docs: &str) -> Self {
+        Module {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+
+    /// Appends a statement.
+    pub fn item(mut self, item: impl Into<Stmt>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Appends statements.
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Stmt>,
+    {
+        self.items.extend(items.into_iter().map(|i| i.into()));
+        self
+    }
+
+    /// Appends statements as a named section.
+    pub fn section<I>(self, name: &str, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Stmt>,
+    {
+        let start = self.items.len();
+        let mut module = self.items(items);
+        let end = module.items.len();
+        module.sections.push((name.to_string(), start..end));
+        module
+    }
+
+    /// Inserts statements before all other statements, e.g. imports.
+    pub fn prepend<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Stmt>,
+    {
+        let items = items.into_iter().map(|i| i.into()).collect::<Vec<_>>();
+        let count = items.len();
+        self.items.splice(0..0, items);
+        for (_, range) in self.sections.iter_mut() {
+            *range = range.start + count..range.end + count;
+        }
+        self
+    }
+}
+
+/// A top-level statement or a statement of a function body.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Stmt {
+    Import(Import),
+    Export(Export),
+    Interface(Interface),
+    TypeAlias(TypeAlias),
+    Const(Const),
+    Function(Function),
+    Class(Class),
+    /// `return {expr};`
+    Return(Expr),
+    /// `{expr};`
+    Expr(Expr),
+}
+
+impl Stmt {
+    /// Name bound by the statement in its module, if any.
+    pub fn declared_name(&self) -> Option<&str> {
+        match self {
+            Stmt::Export(Export {
+                kind: ExportKind::Namespace(name),
+                ..
+            })
+            | Stmt::Export(Export {
+                kind: ExportKind::Named(_, Some(name)),
+                ..
+            })
+            | Stmt::Export(Export {
+                kind: ExportKind::Named(name, None),
+                ..
+            }) => Some(name),
+            Stmt::Interface(Interface { name, .. })
+            | Stmt::TypeAlias(TypeAlias { name, .. })
+            | Stmt::Const(Const { name, .. })
+            | Stmt::Function(Function { name, .. })
+            | Stmt::Class(Class { name, .. }) => Some(name),
+            Stmt::Import(_) | Stmt::Return(_) | Stmt::Expr(_) => None,
+        }
+    }
+}
+
+/// What an [Import] binds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `import * as {name}`.
+    Namespace(String),
+    /// `import { a, b as c }`.
+    Named(Vec<(String, Option<String>)>),
+}
+
+/// An import of another module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Import {
+    pub kind: ImportKind,
+    pub path: String,
+    /// Whether the import is only used in types, in which case it is omitted from JavaScript.
+    pub type_only: bool,
+}
+
+impl Import {
+    pub fn namespace(name: &str, path: &str) -> Self {
+        Import {
+            kind: ImportKind::Namespace(name.to_string()),
+            path: path.to_string(),
+            type_only: false,
+        }
+    }
+
+    pub fn named(names: Vec<(String, Option<String>)>, path: &str) -> Self {
+        Import {
+            kind: ImportKind::Named(names),
+            path: path.to_string(),
+            type_only: false,
+        }
+    }
+
+    /// Marks the import as only used in types.
+    pub fn type_only(self) -> Self {
+        Import {
+            type_only: true,
+            ..self
+        }
+    }
+}
+
+/// What an [Export] re-exports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExportKind {
+    /// `export * as {name}`.
+    Namespace(String),
+    /// `export { name as alias }`.
+    Named(String, Option<String>),
+}
+
+/// A re-export of another module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Export {
+    pub kind: ExportKind,
+    pub path: String,
+}
+
+impl Export {
+    pub fn namespace(name: &str, path: &str) -> Self {
+        Export {
+            kind: ExportKind::Namespace(name.to_string()),
+            path: path.to_string(),
+        }
+    }
+
+    pub fn named(name: &str, alias: Option<&str>, path: &str) -> Self {
+        Export {
+            kind: ExportKind::Named(name.to_string(), alias.map(|a| a.to_string())),
+            path: path.to_string(),
+        }
+    }
+}
+
+/// A type parameter of an interface or type alias.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeParam {
+    pub name: String,
+    pub default: Option<Type>,
+}
+
+/// An `interface` declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interface {
+    pub docs: Option<String>,
+    pub export: bool,
+    pub name: String,
+    pub type_params: Vec<TypeParam>,
+    pub members: Vec<Property>,
+}
+
+impl Interface {
+    pub fn new(name: &str, members: Vec<Property>) -> Self {
+        Interface {
+            docs: None,
+            export: true,
+            name: name.to_string(),
+            type_params: vec![],
+            members,
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        Interface {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+
+    pub fn type_params(self, type_params: Vec<TypeParam>) -> Self {
+        Interface {
+            type_params,
+            ..self
+        }
+    }
+}
+
+/// A `type` declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeAlias {
+    pub docs: Option<String>,
+    pub export: bool,
+    pub name: String,
+    pub type_params: Vec<TypeParam>,
+    pub ty: Type,
+}
+
+impl TypeAlias {
+    pub fn new(name: &str, ty: Type) -> Self {
+        TypeAlias {
+            docs: None,
+            export: true,
+            name: name.to_string(),
+            type_params: vec![],
+            ty,
+        }
+    }
+
+    pub fn type_params(self, type_params: Vec<TypeParam>) -> Self {
+        TypeAlias {
+            type_params,
+            ..self
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        TypeAlias {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+}
+
+/// A `const` declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Const {
+    pub docs: Option<String>,
+    pub export: bool,
+    pub name: String,
+    /// Type annotation of the const. Declaration files fall back to [Expr::declared_type].
+    pub ty: Option<Type>,
+    pub value: Expr,
+}
+
+impl Const {
+    /// Creates an exported const.
+    pub fn new(name: &str, value: Expr) -> Self {
+        Const {
+            docs: None,
+            export: true,
+            name: name.to_string(),
+            ty: None,
+            value,
+        }
+    }
+
+    /// Creates an exported `{value} as const` declaration of a JSON value.
+    pub fn json<T>(name: &str, value: &T) -> Result<Self>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Const::new(name, Expr::json(value)?.as_const()))
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        Const {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+
+    pub fn docs_opt(self, docs: &Option<String>) -> Self {
+        Const {
+            docs: docs.clone(),
+            ..self
+        }
+    }
+
+    /// Makes the const local to its module.
+    pub fn private(self) -> Self {
+        Const {
+            export: false,
+            ..self
+        }
+    }
+
+    pub fn ty(self, ty: Type) -> Self {
+        Const {
+            ty: Some(ty),
+            ..self
+        }
+    }
+}
+
+/// A `function` declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+    pub docs: Option<String>,
+    pub export: bool,
+    pub name: String,
+    pub params: Vec<Param>,
+    pub return_type: Option<Type>,
+    pub body: Vec<Stmt>,
+}
+
+impl Function {
+    pub fn new(name: &str, params: Vec<Param>, return_type: Type, body: Vec<Stmt>) -> Self {
+        Function {
+            docs: None,
+            export: true,
+            name: name.to_string(),
+            params,
+            return_type: Some(return_type),
+            body,
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        Function {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+}
+
+/// A `class` declaration, whose constructor only stores its parameters as `readonly`
+/// properties of the same name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Class {
+    pub docs: Option<String>,
+    pub export: bool,
+    pub name: String,
+    /// The class extended by this class.
+    pub extends: Option<Expr>,
+    /// Parameters of the constructor, which is inherited if there are none.
+    pub fields: Vec<Param>,
+    pub properties: Vec<ClassProperty>,
+}
+
+impl Class {
+    pub fn new(name: &str, fields: Vec<Param>, properties: Vec<ClassProperty>) -> Self {
+        Class {
+            docs: None,
+            export: true,
+            name: name.to_string(),
+            extends: None,
+            fields,
+            properties,
+        }
+    }
+
+    pub fn extends(self, base: Expr) -> Self {
+        Class {
+            extends: Some(base),
+            ..self
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        Class {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+}
+
+/// A `readonly` property of a class, initialized with a value. Declaration files use
+/// [Expr::declared_type].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassProperty {
+    pub docs: Option<String>,
+    pub name: String,
+    pub value: Expr,
+}
+
+impl ClassProperty {
+    pub fn new(name: &str, value: Expr) -> Self {
+        ClassProperty {
+            docs: None,
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        ClassProperty {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+}
+
+/// A parameter of a function or arrow function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    /// Binding of the parameter, which may be a destructuring pattern such as `{ args }`.
+    pub pattern: String,
+    pub ty: Option<Type>,
+}
+
+impl Param {
+    pub fn new(pattern: &str, ty: Type) -> Self {
+        Param {
+            pattern: pattern.to_string(),
+            ty: Some(ty),
+        }
+    }
+
+    /// A parameter whose type is inferred.
+    pub fn untyped(pattern: &str) -> Self {
+        Param {
+            pattern: pattern.to_string(),
+            ty: None,
+        }
+    }
+}
+
+/// A property of an interface or object type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    pub docs: Option<String>,
+    pub readonly: bool,
+    pub name: String,
+    pub optional: bool,
+    pub ty: Type,
+}
+
+impl Property {
+    pub fn new(name: &str, ty: Type) -> Self {
+        Property {
+            docs: None,
+            readonly: false,
+            name: name.to_string(),
+            optional: false,
+            ty,
+        }
+    }
+
+    pub fn docs(self, docs: &str) -> Self {
+        Property {
+            docs: Some(docs.to_string()),
+            ..self
+        }
+    }
+
+    pub fn docs_opt(self, docs: &Option<String>) -> Self {
+        Property {
+            docs: docs.clone(),
+            ..self
+        }
+    }
+
+    pub fn readonly(self) -> Self {
+        Property {
+            readonly: true,
+            ..self
+        }
+    }
+}
+
+/// An element of a tuple type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TupleElement {
+    pub label: Option<String>,
+    pub ty: Type,
+}
+
+/// A type expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    /// A named type with type arguments, e.g. `p.U64` or `ReadonlyArray<string>`.
+    Ref(String, Vec<Type>),
+    /// An object type literal.
+    Object(Vec<Property>),
+    /// A tuple type, optionally `readonly`.
+    Tuple {
+        readonly: bool,
+        elements: Vec<TupleElement>,
+    },
+    /// A literal type, e.g. `"0x1"`, `1` or `null`.
+    Literal(Value),
+    /// `typeof {name}`.
+    Typeof(String),
+    /// A function type.
+    Function(Vec<Param>, Box<Type>),
+    /// A union of types, e.g. `ICoin | null`.
+    Union(Vec<Type>),
+    /// The return type of a type guard, e.g. `error is p.MoveAbortError`.
+    Predicate(String, Box<Type>),
+}
+
+impl Type {
+    /// A named type without type arguments.
+    pub fn named(name: &str) -> Self {
+        Type::Ref(name.to_string(), vec![])
+    }
+
+    pub fn generic(name: &str, args: Vec<Type>) -> Self {
+        Type::Ref(name.to_string(), args)
+    }
+
+    pub fn string_literal(value: &str) -> Self {
+        Type::Literal(Value::String(value.to_string()))
+    }
+
+    /// A tuple with labeled elements.
+    pub fn labeled_tuple(elements: Vec<(String, Type)>) -> Self {
+        Type::Tuple {
+            readonly: false,
+            elements: elements
+                .into_iter()
+                .map(|(label, ty)| TupleElement {
+                    label: Some(label),
+                    ty,
+                })
+                .collect(),
+        }
+    }
+
+    /// The type of a JSON value declared `as const`.
+    pub fn json(value: &Value) -> Self {
+        match value {
+            Value::Array(items) => Type::Tuple {
+                readonly: true,
+                elements: items
+                    .iter()
+                    .map(|item| TupleElement {
+                        label: None,
+                        ty: Type::json(item),
+                    })
+                    .collect(),
+            },
+            Value::Object(fields) => Type::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| Property::new(k, Type::json(v)).readonly())
+                    .collect(),
+            ),
+            literal => Type::Literal(literal.clone()),
+        }
+    }
+
+    /// The name of a named type without type arguments.
+    pub fn as_named(&self) -> Option<&str> {
+        match self {
+            Type::Ref(name, args) if args.is_empty() => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// A member of an object literal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectMember {
+    /// `{key}: {value}`.
+    Property(String, Expr),
+    /// `{key}`, referring to a binding of the same name.
+    Shorthand(String),
+}
+
+/// An expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    /// An identifier, e.g. `args`.
+    Ident(String),
+    /// A literal value. Strings, numbers, booleans and null are literals; arrays and objects
+    /// are rendered as array and object literals.
+    Literal(Value),
+    /// A bigint literal, e.g. `1000000n`, given by its decimal digits.
+    BigInt(String),
+    Array(Vec<Expr>),
+    Object(Vec<ObjectMember>),
+    /// `{object}.{property}`.
+    Member(Box<Expr>, String),
+    Call(Box<Expr>, Vec<Expr>),
+    Arrow {
+        params: Vec<Param>,
+        return_type: Option<Type>,
+        body: Box<Expr>,
+    },
+    /// `{expr} as {type}`, which is removed from JavaScript.
+    As(Box<Expr>, Type),
+    /// `{expr} as const`, which is removed from JavaScript.
+    AsConst(Box<Expr>),
+    /// `{left} {operator} {right}`, e.g. `value == null`.
+    Binary(Box<Expr>, String, Box<Expr>),
+    /// `{test} ? {consequent} : {alternate}`.
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// A reference to an identifier or to a member of a namespace, e.g. `p.serializers.u64`.
+    pub fn path(path: &str) -> Self {
+        let mut parts = path.split('.');
+        let root = Expr::Ident(parts.next().unwrap_or_default().to_string());
+        parts.fold(root, |expr, part| expr.member(part))
+    }
+
+    pub fn string(value: &str) -> Self {
+        Expr::Literal(Value::String(value.to_string()))
+    }
+
+    pub fn json<T>(value: &T) -> Result<Self>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Expr::Literal(serde_json::to_value(value)?))
+    }
+
+    /// An object literal whose properties refer to the bindings of the same name.
+    pub fn shorthand_object(names: &[&str]) -> Self {
+        Expr::Object(
+            names
+                .iter()
+                .map(|n| ObjectMember::Shorthand(n.to_string()))
+                .collect(),
+        )
+    }
+
+    pub fn member(self, property: &str) -> Self {
+        Expr::Member(Box::new(self), property.to_string())
+    }
+
+    pub fn call(self, args: Vec<Expr>) -> Self {
+        Expr::Call(Box::new(self), args)
+    }
+
+    pub fn arrow(params: Vec<Param>, return_type: Option<Type>, body: Expr) -> Self {
+        Expr::Arrow {
+            params,
+            return_type,
+            body: Box::new(body),
+        }
+    }
+
+    pub fn as_type(self, ty: Type) -> Self {
+        Expr::As(Box::new(self), ty)
+    }
+
+    pub fn as_const(self) -> Self {
+        Expr::AsConst(Box::new(self))
+    }
+
+    pub fn binary(self, operator: &str, right: Expr) -> Self {
+        Expr::Binary(Box::new(self), operator.to_string(), Box::new(right))
+    }
+
+    pub fn conditional(test: Expr, consequent: Expr, alternate: Expr) -> Self {
+        Expr::Conditional(Box::new(test), Box::new(consequent), Box::new(alternate))
+    }
+
+    /// The type of a const initialized with this expression, used in declaration files.
+    pub fn declared_type(&self) -> Result<Type> {
+        match self {
+            Expr::As(_, ty) => Ok(ty.clone()),
+            Expr::AsConst(inner) => inner.const_type(),
+            Expr::Ident(_) | Expr::Member(..) => Ok(Type::Typeof(self.path_name()?)),
+            Expr::Literal(value) if !value.is_array() && !value.is_object() => {
+                Ok(Type::json(value))
+            }
+            Expr::BigInt(_) => Ok(Type::named("bigint")),
+            Expr::Arrow {
+                params,
+                return_type: Some(return_type),
+                ..
+            } if params.iter().all(|p| p.ty.is_some()) => Ok(Type::Function(
+                params.clone(),
+                Box::new(return_type.clone()),
+            )),
+            Expr::Object(members) => Ok(Type::Object(
+                members
+                    .iter()
+                    .map(|member| {
+                        Ok(match member {
+                            ObjectMember::Property(key, value) => {
+                                Property::new(key, value.declared_type()?)
+                            }
+                            ObjectMember::Shorthand(key) => {
+                                Property::new(key, Type::Typeof(key.clone()))
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            _ => bail!("cannot infer the declared type of {:?}", self),
+        }
+    }
+
+    /// The dotted path of an identifier or of a member of a namespace, e.g. `mod_coin.errors`.
+    fn path_name(&self) -> Result<String> {
+        match self {
+            Expr::Ident(name) => Ok(name.clone()),
+            Expr::Member(object, property) => Ok(format!("{}.{}", object.path_name()?, property)),
+            _ => bail!("{:?} is not a path", self),
+        }
+    }
+
+    /// The type of this expression declared `as const`.
+    fn const_type(&self) -> Result<Type> {
+        match self {
+            Expr::Literal(value) => Ok(Type::json(value)),
+            Expr::BigInt(_) => Ok(Type::named("bigint")),
+            Expr::Ident(name) => Ok(Type::Typeof(name.clone())),
+            Expr::Array(items) => Ok(Type::Tuple {
+                readonly: true,
+                elements: items
+                    .iter()
+                    .map(|item| {
+                        Ok(TupleElement {
+                            label: None,
+                            ty: item.const_type()?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            }),
+            Expr::Object(members) => Ok(Type::Object(
+                members
+                    .iter()
+                    .map(|member| {
+                        Ok(match member {
+                            ObjectMember::Property(key, value) => {
+                                Property::new(key, value.const_type()?).readonly()
+                            }
+                            ObjectMember::Shorthand(key) => {
+                                Property::new(key, Type::Typeof(key.clone())).readonly()
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            _ => self.declared_type(),
+        }
+    }
+}
+
+impl From<Import> for Stmt {
+    fn from(value: Import) -> Self {
+        Stmt::Import(value)
+    }
+}
+
+impl From<Export> for Stmt {
+    fn from(value: Export) -> Self {
+        Stmt::Export(value)
+    }
+}
+
+impl From<Interface> for Stmt {
+    fn from(value: Interface) -> Self {
+        Stmt::Interface(value)
+    }
+}
+
+impl From<TypeAlias> for Stmt {
+    fn from(value: TypeAlias) -> Self {
+        Stmt::TypeAlias(value)
+    }
+}
+
+impl From<Const> for Stmt {
+    fn from(value: Const) -> Self {
+        Stmt::Const(value)
+    }
+}
+
+impl From<Function> for Stmt {
+    fn from(value: Function) -> Self {
+        Stmt::Function(value)
+    }
+}
+
+impl From<Class> for Stmt {
+    fn from(value: Class) -> Self {
+        Stmt::Class(value)
+    }
+}
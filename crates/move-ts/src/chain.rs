@@ -0,0 +1,44 @@
+//! Chains the generated code targets.
+//!
+//! Aptos and Sui share the Move language but differ in how entry functions are called: which
+//! arguments are passed by the runtime, how objects are referenced and how calls are encoded.
+
+use std::str::FromStr;
+
+/// Chain whose entry functions are called by the generated code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chain {
+    /// Signers are implicit, `0x1::object::Object<T>`s are passed as addresses and calls are
+    /// encoded as `TransactionPayload`s.
+    Aptos,
+    /// Signers and the `TxContext` are implicit, objects are passed by ID and calls are
+    /// programmable transaction Move calls.
+    Sui,
+}
+
+impl Default for Chain {
+    fn default() -> Self {
+        Chain::Aptos
+    }
+}
+
+impl Chain {
+    /// Length of an account address in bytes, with which addresses are BCS-encoded.
+    pub fn address_length(&self) -> usize {
+        match self {
+            Chain::Aptos | Chain::Sui => 32,
+        }
+    }
+}
+
+impl FromStr for Chain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aptos" => Ok(Chain::Aptos),
+            "sui" => Ok(Chain::Sui),
+            _ => Err(format!("unknown chain `{}`, expected aptos or sui", s)),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Output targets for generated code.
+
+use crate::CodegenContext;
+
+/// Module system used by emitted JavaScript.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModuleFormat {
+    /// ECMAScript modules.
+    Esm,
+    /// CommonJS modules.
+    CommonJs,
+}
+
+/// Kind of file emitted by the generators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmitTarget {
+    /// TypeScript source.
+    TypeScript,
+    /// JavaScript without any type information.
+    JavaScript(ModuleFormat),
+    /// TypeScript declarations accompanying [EmitTarget::JavaScript].
+    Declarations(ModuleFormat),
+}
+
+impl Default for EmitTarget {
+    fn default() -> Self {
+        EmitTarget::TypeScript
+    }
+}
+
+impl EmitTarget {
+    /// File extension of emitted files.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            EmitTarget::TypeScript => "ts",
+            EmitTarget::JavaScript(ModuleFormat::Esm) => "js",
+            EmitTarget::JavaScript(ModuleFormat::CommonJs) => "cjs",
+            EmitTarget::Declarations(ModuleFormat::Esm) => "d.ts",
+            EmitTarget::Declarations(ModuleFormat::CommonJs) => "d.cts",
+        }
+    }
+
+    /// Extension used in relative import specifiers.
+    pub fn import_extension(&self) -> &'static str {
+        if self.module_format() == ModuleFormat::CommonJs {
+            "cjs"
+        } else {
+            "js"
+        }
+    }
+
+    /// Module system of the emitted files.
+    pub fn module_format(&self) -> ModuleFormat {
+        match self {
+            EmitTarget::TypeScript => ModuleFormat::Esm,
+            EmitTarget::JavaScript(format) | EmitTarget::Declarations(format) => *format,
+        }
+    }
+
+    /// Returns true if type declarations are emitted.
+    pub fn emits_types(&self) -> bool {
+        !matches!(self, EmitTarget::JavaScript(_))
+    }
+}
+
+/// Set of targets written for every generated file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// TypeScript sources.
+    TypeScript,
+    /// ESM JavaScript with `.d.ts` declarations, and optionally CommonJS with `.d.cts` declarations.
+    JavaScript { commonjs: bool },
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::TypeScript
+    }
+}
+
+impl OutputFormat {
+    pub fn targets(&self) -> Vec<EmitTarget> {
+        match self {
+            OutputFormat::TypeScript => vec![EmitTarget::TypeScript],
+            OutputFormat::JavaScript { commonjs } => {
+                let mut targets = vec![
+                    EmitTarget::JavaScript(ModuleFormat::Esm),
+                    EmitTarget::Declarations(ModuleFormat::Esm),
+                ];
+                if *commonjs {
+                    targets.push(EmitTarget::JavaScript(ModuleFormat::CommonJs));
+                    targets.push(EmitTarget::Declarations(ModuleFormat::CommonJs));
+                }
+                targets
+            }
+        }
+    }
+}
+
+impl<'info> CodegenContext<'info> {
+    /// Resolves a relative module path without extension to an import specifier.
+    pub fn import_path(&self, path: &str) -> String {
+        format!("{}.{}", path, self.target.import_extension())
+    }
+}
@@ -1,8 +1,109 @@
-pub fn gen_doc_string_opt(string: &Option<String>) -> String {
-    string
-        .as_ref()
-        .map(|s| gen_doc_string(s))
-        .unwrap_or_default()
+use std::str::FromStr;
+
+/// Quotes of generated string literals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// `"double"`.
+    Double,
+    /// `'single'`.
+    Single,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Double
+    }
+}
+
+impl FromStr for QuoteStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "double" => Ok(QuoteStyle::Double),
+            "single" => Ok(QuoteStyle::Single),
+            _ => Err(format!(
+                "unknown quote style `{}`, expected double or single",
+                s
+            )),
+        }
+    }
+}
+
+/// Where commas are printed after the last item of a list broken over several lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrailingCommas {
+    /// After the last item of any list.
+    All,
+    /// After the last item of object and array literals.
+    Es5,
+    /// Never.
+    None,
+}
+
+impl Default for TrailingCommas {
+    fn default() -> Self {
+        TrailingCommas::Es5
+    }
+}
+
+impl FromStr for TrailingCommas {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(TrailingCommas::All),
+            "es5" => Ok(TrailingCommas::Es5),
+            "none" => Ok(TrailingCommas::None),
+            _ => Err(format!(
+                "unknown trailing commas `{}`, expected one of all, es5 or none",
+                s
+            )),
+        }
+    }
+}
+
+/// Formatting of generated code.
+///
+/// The defaults match the defaults of prettier 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Number of spaces per indentation level.
+    pub indent_width: usize,
+    /// Maximum width of a line, which may be exceeded by long strings and identifiers.
+    pub line_width: usize,
+    pub quote_style: QuoteStyle,
+    pub trailing_commas: TrailingCommas,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            indent_width: 2,
+            line_width: 80,
+            quote_style: QuoteStyle::default(),
+            trailing_commas: TrailingCommas::default(),
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Renders a string literal, switching quotes if that requires fewer escapes.
+    pub fn string_literal(&self, value: &str) -> String {
+        let doubles = value.matches('"').count();
+        let singles = value.matches('\'').count();
+        let quote = match self.quote_style {
+            QuoteStyle::Double if doubles > singles => '\'',
+            QuoteStyle::Single if singles <= doubles => '\'',
+            _ => '"',
+        };
+        let escaped = serde_json::Value::String(value.to_string()).to_string();
+        let inner = &escaped[1..escaped.len() - 1];
+        if quote == '"' {
+            return format!("\"{}\"", inner);
+        }
+        format!("'{}'", inner.replace("\\\"", "\"").replace('\'', "\\'"))
+    }
 }
 
 fn trim_lines(string: &str) -> String {
@@ -34,7 +135,11 @@ pub fn gen_doc_string(string: &str) -> String {
             "/**\n{}\n */\n",
             trim_lines(string)
                 .lines()
-                .map(|line| format!(" * {}", line))
+                .map(|line| if line.is_empty() {
+                    " *".to_string()
+                } else {
+                    format!(" * {}", line)
+                })
                 .collect::<Vec<_>>()
                 .join("\n")
                 // move not supported by typedoc yet
@@ -42,16 +147,3 @@ pub fn gen_doc_string(string: &str) -> String {
         )
     }
 }
-
-pub fn indent(text: &str) -> String {
-    text.lines()
-        .map(|line| {
-            if line.is_empty() {
-                line.to_string()
-            } else {
-                format!("  {}", line)
-            }
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
@@ -0,0 +1,79 @@
+//! Extension point for generating additional output for each module.
+//!
+//! A [Generator] is registered on the [crate::idl_package::IDLPackageGenerator] from Rust, or
+//! added to a [GeneratorRegistry] so it can be selected by name, e.g. from the command line.
+
+use crate::{
+    ast::{Module, Stmt},
+    idl_module::IDLModuleGenerator,
+    CodegenContext,
+};
+use anyhow::*;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Contributes additional files and index declarations to each generated module.
+///
+/// Generators build [crate::ast] nodes like the built-in generators, so their output is emitted
+/// for every target, and references made through the [CodegenContext] are imported
+/// automatically.
+pub trait Generator: Send + Sync {
+    /// Names of the additional files of a module, without extension, e.g. `hooks`.
+    ///
+    /// Files are written to the directory of the module.
+    fn module_files(&self, _gen: &IDLModuleGenerator, _ctx: &CodegenContext) -> Vec<String> {
+        vec![]
+    }
+
+    /// Generates an additional file listed by [Generator::module_files].
+    fn generate_module_file(
+        &self,
+        name: &str,
+        _gen: &IDLModuleGenerator,
+        _ctx: &CodegenContext,
+    ) -> Result<Module> {
+        bail!("generator does not declare a module file named `{}`", name)
+    }
+
+    /// Generates declarations appended to the index of a module.
+    fn generate_index_items(
+        &self,
+        _gen: &IDLModuleGenerator,
+        _ctx: &CodegenContext,
+    ) -> Result<Vec<Stmt>> {
+        Ok(vec![])
+    }
+}
+
+/// Generators which can be selected by name.
+#[derive(Clone, Default)]
+pub struct GeneratorRegistry {
+    generators: BTreeMap<String, Arc<dyn Generator>>,
+}
+
+impl GeneratorRegistry {
+    /// Registers a generator, replacing any generator with the same name.
+    pub fn register(&mut self, name: &str, generator: impl Generator + 'static) {
+        self.generators
+            .insert(name.to_string(), Arc::new(generator));
+    }
+
+    /// Names of all registered generators.
+    pub fn names(&self) -> Vec<&str> {
+        self.generators.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns the generator registered under a name.
+    pub fn get(&self, name: &str) -> Result<Arc<dyn Generator>> {
+        self.generators.get(name).cloned().ok_or_else(|| {
+            anyhow!(
+                "unknown generator `{}`, registered generators: {}",
+                name,
+                if self.generators.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.names().join(", ")
+                }
+            )
+        })
+    }
+}
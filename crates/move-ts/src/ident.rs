@@ -0,0 +1,175 @@
+//! Sanitization of generated identifiers.
+//!
+//! Move identifiers may collide with TypeScript reserved words, e.g. a function named `delete`,
+//! or with each other once a [crate::naming::NamingStrategy] changes their casing. Every
+//! generated binding goes through [sanitize_identifier], and bindings sharing a scope are
+//! declared in an [IdentScope] which renames collisions deterministically.
+
+use std::collections::BTreeSet;
+
+/// Words which cannot be used as bindings in strict mode modules or as TypeScript type names,
+/// as well as the bindings of the CommonJS module wrapper, which emitted `.cjs` files rely on.
+const RESERVED_WORDS: &[&str] = &[
+    "any",
+    "arguments",
+    "await",
+    "bigint",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "exports",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "module",
+    "never",
+    "new",
+    "null",
+    "number",
+    "object",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "require",
+    "return",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "symbol",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Returns true if `name` cannot be used as a binding.
+pub fn is_reserved_word(name: &str) -> bool {
+    RESERVED_WORDS.contains(&name)
+}
+
+/// Converts a name into a valid TypeScript identifier.
+///
+/// Invalid characters are replaced with `_`, names starting with a digit are prefixed with `_`
+/// and reserved words are suffixed with `_`.
+pub fn sanitize_identifier(name: &str) -> String {
+    let mut ident = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if is_reserved_word(&ident) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// Identifiers declared in a single scope.
+#[derive(Clone, Debug, Default)]
+pub struct IdentScope {
+    declared: BTreeSet<String>,
+}
+
+impl IdentScope {
+    /// Creates a scope in which the given names are already taken, e.g. by imports.
+    pub fn new<I, S>(taken: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        IdentScope {
+            declared: taken.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+
+    /// Declares a sanitized identifier for `name`, renamed as by [IdentScope::declare_unique].
+    pub fn declare(&mut self, name: &str) -> String {
+        self.declare_unique(sanitize_identifier(name))
+    }
+
+    /// Declares a property name, renamed as by [IdentScope::declare_unique].
+    ///
+    /// Reserved words are valid property names, so they are not escaped.
+    pub fn declare_property(&mut self, name: &str) -> String {
+        self.declare_unique(name.to_string())
+    }
+
+    /// Declares `base`, or if it is already taken, the first free name among `base_2`,
+    /// `base_3`, ...
+    fn declare_unique(&mut self, base: String) -> String {
+        let mut ident = base.clone();
+        let mut counter = 1;
+        while self.declared.contains(&ident) {
+            counter += 1;
+            ident = format!("{}_{}", base, counter);
+        }
+        self.declared.insert(ident.clone());
+        ident
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_escapes_reserved_words_and_invalid_characters() {
+        assert_eq!(sanitize_identifier("delete"), "delete_");
+        assert_eq!(sanitize_identifier("require"), "require_");
+        assert_eq!(sanitize_identifier("2fa"), "_2fa");
+        assert_eq!(sanitize_identifier("a-b"), "a_b");
+        assert_eq!(sanitize_identifier("coin"), "coin");
+    }
+
+    #[test]
+    fn declarations_and_properties_share_a_suffix_scheme() {
+        let mut scope = IdentScope::new(["p"]);
+        assert_eq!(scope.declare("p"), "p_2");
+        assert_eq!(scope.declare("p"), "p_3");
+        assert_eq!(scope.declare("delete"), "delete_");
+        assert_eq!(scope.declare("delete"), "delete__2");
+
+        let mut props = IdentScope::default();
+        assert_eq!(props.declare_property("delete"), "delete");
+        assert_eq!(props.declare_property("delete"), "delete_2");
+        assert_eq!(props.declare_property("delete"), "delete_3");
+    }
+}
@@ -0,0 +1,149 @@
+//! Module constants other than error codes.
+//!
+//! The IDL of `move-idl` only describes the error constants of a module, so other constants
+//! are provided separately, either as a `constants` array in each module of an IDL file or as
+//! a map of constants keyed by module ID.
+
+use anyhow::*;
+use move_idl::IDLType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::{
+    ast::{Const, Expr},
+    ident::IdentScope,
+    read_module_entries, CodegenContext,
+};
+
+/// A constant of a module.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDLConstant {
+    pub name: String,
+    pub doc: Option<String>,
+    pub ty: IDLType,
+    /// Value of the constant. Integers are JSON numbers or decimal strings, addresses are hex
+    /// strings, and vectors are arrays. A `vector<u8>` may also be a hex string.
+    pub value: Value,
+}
+
+/// Constants of the modules of a package, keyed by module ID, e.g. `0x1::coin`.
+pub type IDLConstants = BTreeMap<String, Vec<IDLConstant>>;
+
+/// Reads the `constants` of the modules and dependencies of an IDL file.
+pub fn read_constants(idl: &Value) -> Result<IDLConstants> {
+    read_module_entries(idl, "constants")
+}
+
+/// Generates the exported const of a constant, named `name`.
+pub fn generate_constant(name: &str, constant: &IDLConstant) -> Result<Const> {
+    let value = constant_value(&constant.ty, &constant.value)?;
+    let value = match value {
+        Expr::Array(_) => value.as_const(),
+        _ => value,
+    };
+    Ok(Const::new(name, value).docs_opt(&constant.doc))
+}
+
+/// Generates the constants of a module given its ID.
+pub fn generate_constants(module_id: &str, ctx: &CodegenContext) -> Result<Vec<Const>> {
+    let mut constants = ctx.module_constants(module_id).iter().collect::<Vec<_>>();
+    ctx.order()
+        .sort_by_name(&mut constants, |c| c.name.as_str());
+    let mut scope = IdentScope::default();
+    constants
+        .into_iter()
+        .map(|c| {
+            generate_constant(&scope.declare(&c.name), c)
+                .with_context(|| format!("constant `{}` of {}", c.name, module_id))
+        })
+        .collect()
+}
+
+/// Converts the JSON value of a constant of type `ty` into an expression.
+///
+/// `u8`s are numbers and `u64`s and `u128`s are bigints. Addresses and byte vectors are hex
+/// strings, like the arguments of entry functions.
+///
+/// Unlike the `p.U64` and `p.U128` of arguments, which accept any representation, constants
+/// are values, so they keep the exact bigint that the BCS decoders also produce. Bigints are
+/// assignable to `p.U64` and `p.U128`, so constants can be passed as arguments as is.
+fn constant_value(ty: &IDLType, value: &Value) -> Result<Expr> {
+    Ok(match ty {
+        IDLType::Bool => Expr::Literal(Value::Bool(value.as_bool().context("expected a boolean")?)),
+        IDLType::U8 => {
+            let digits = integer_digits(value)?;
+            let value = digits
+                .parse::<u8>()
+                .with_context(|| format!("{} is not a u8", digits))?;
+            Expr::Literal(value.into())
+        }
+        IDLType::U64 => {
+            let digits = integer_digits(value)?;
+            let value = digits
+                .parse::<u64>()
+                .with_context(|| format!("{} is not a u64", digits))?;
+            Expr::BigInt(value.to_string())
+        }
+        IDLType::U128 => {
+            let digits = integer_digits(value)?;
+            let value = digits
+                .parse::<u128>()
+                .with_context(|| format!("{} is not a u128", digits))?;
+            Expr::BigInt(value.to_string())
+        }
+        IDLType::Address => Expr::string(&hex_string(value)?),
+        IDLType::Vector(inner) if **inner == IDLType::U8 => Expr::string(&match value {
+            Value::Array(bytes) => format!(
+                "0x{}",
+                bytes
+                    .iter()
+                    .map(|b| -> Result<String> {
+                        let byte = b
+                            .as_u64()
+                            .filter(|b| *b <= 0xff)
+                            .context("expected a byte")?;
+                        Ok(format!("{:02x}", byte))
+                    })
+                    .collect::<Result<String>>()?
+            ),
+            _ => hex_string(value)?,
+        }),
+        IDLType::Vector(inner) => Expr::Array(
+            value
+                .as_array()
+                .context("expected an array")?
+                .iter()
+                .map(|item| constant_value(inner, item))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        IDLType::Signer | IDLType::Struct(_) | IDLType::TypeParam(_) | IDLType::Tuple(_) => {
+            bail!("unsupported constant type {:?}", ty)
+        }
+    })
+}
+
+/// Decimal digits of an integer given as a JSON number or string.
+fn integer_digits(value: &Value) -> Result<String> {
+    let digits = match value {
+        Value::Number(n) if n.is_u64() => n.to_string(),
+        Value::String(s) => s.clone(),
+        _ => bail!("expected an unsigned integer, got {}", value),
+    };
+    ensure!(
+        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+        "expected an unsigned integer, got {}",
+        value
+    );
+    Ok(digits)
+}
+
+/// Normalizes a `0x`-prefixed hex string.
+fn hex_string(value: &Value) -> Result<String> {
+    let hex = value
+        .as_str()
+        .and_then(|s| s.strip_prefix("0x"))
+        .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .with_context(|| format!("expected a hex string, got {}", value))?;
+    Ok(format!("0x{}", hex.to_lowercase()))
+}
@@ -0,0 +1,349 @@
+//! Move 2 enums.
+//!
+//! The IDL of `move-idl` has no enums, so they are provided separately, either as an `enums`
+//! array in each module of an IDL file or as a map of enums keyed by module ID. Enums are
+//! generated as unions of one interface per variant, discriminated by a `__variant` property.
+
+use anyhow::*;
+use itertools::Itertools;
+use move_idl::{IDLField, IDLType, IDLTypeParam, StructTagData};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::{
+    ast::{
+        Const, Expr, Interface, ObjectMember, Param, Property, Stmt, Type, TypeAlias, TypeParam,
+    },
+    ident::IdentScope,
+    idl_type::{generate_idl_type_with_type_args, is_string_type, ADDRESS_LENGTH_NAME},
+    read_module_entries, Codegen, CodegenContext,
+};
+
+/// Name of the property holding the name of the variant of an enum value.
+pub const VARIANT_PROPERTY: &str = "__variant";
+
+/// A variant of an enum.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDLVariant {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    pub fields: Vec<IDLField>,
+}
+
+/// An enum of a module.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IDLEnum {
+    pub name: StructTagData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<IDLTypeParam>,
+    /// Variants of the enum, in the order of their BCS indices.
+    pub variants: Vec<IDLVariant>,
+}
+
+/// Enums of the modules of a package, keyed by module ID, e.g. `0x1::coin`.
+pub type IDLEnums = BTreeMap<String, Vec<IDLEnum>>;
+
+/// Reads the `enums` of the modules and dependencies of an IDL file.
+pub fn read_enums(idl: &Value) -> Result<IDLEnums> {
+    read_module_entries(idl, "enums")
+}
+
+impl IDLEnum {
+    /// Name of the union type of the enum.
+    pub fn type_name(&self, ctx: &CodegenContext) -> String {
+        ctx.naming().struct_type_name(self.name.name.as_str())
+    }
+
+    /// Name of the interface of a variant, e.g. `IStatusActive`.
+    pub fn variant_type_name(&self, variant: &IDLVariant, ctx: &CodegenContext) -> String {
+        ctx.naming()
+            .struct_type_name(&format!("{}{}", self.name.name, variant.name))
+    }
+
+    /// Type parameters of the generated types. Phantom type parameters are omitted.
+    fn ts_type_params(&self) -> Vec<TypeParam> {
+        self.type_params
+            .iter()
+            .filter(|p| !p.is_phantom)
+            .map(|p| TypeParam {
+                name: format!("_{}", p.name),
+                default: Some(Type::named("unknown")),
+            })
+            .collect()
+    }
+
+    /// Type arguments of the fields of the variants, by type parameter index. Phantom type
+    /// parameters are `unknown`.
+    fn field_type_args(&self) -> Vec<Type> {
+        self.type_params
+            .iter()
+            .map(|p| {
+                if p.is_phantom {
+                    Type::named("unknown")
+                } else {
+                    Type::named(&format!("_{}", p.name))
+                }
+            })
+            .collect()
+    }
+
+    /// Returns true if values of the enum can be decoded from BCS.
+    ///
+    /// Generic enums and enums with fields of other structs than strings cannot be decoded.
+    /// Decoders read values with the `BcsReader` of the bundled prelude, so no enum is
+    /// decodable with another prelude.
+    pub fn is_decodable(&self, ctx: &CodegenContext) -> bool {
+        ctx.prelude().is_bundled()
+            && self.type_params.iter().all(|p| p.is_phantom)
+            && self
+                .variants
+                .iter()
+                .flat_map(|v| v.fields.iter())
+                .all(|f| is_decodable_type(&f.ty, ctx))
+    }
+}
+
+/// Generates the properties of the interface of a variant, starting with its `__variant` tag.
+fn generate_variant_properties(
+    variant: &IDLVariant,
+    ctx: &CodegenContext,
+    type_args: &[Type],
+    parse_args: bool,
+) -> Result<Vec<Property>> {
+    let names = ctx.naming().field_names(
+        std::iter::once(VARIANT_PROPERTY).chain(variant.fields.iter().map(|f| f.name.as_str())),
+    );
+    let mut properties = vec![Property::new(
+        VARIANT_PROPERTY,
+        Type::string_literal(&variant.name),
+    )];
+    for (field, name) in variant.fields.iter().zip(names.iter().skip(1)) {
+        properties.push(
+            Property::new(
+                name,
+                generate_idl_type_with_type_args(&field.ty, ctx, type_args, parse_args)?,
+            )
+            .docs_opt(&field.doc),
+        );
+    }
+    Ok(properties)
+}
+
+/// Generates the type of an enum with type arguments which is not declared by a generated
+/// module, as a union of object types.
+pub(crate) fn generate_enum_with_type_args(
+    enum_def: &IDLEnum,
+    ctx: &CodegenContext,
+    type_args: &[Type],
+    parse_args: bool,
+) -> Result<Type> {
+    Ok(Type::Union(
+        enum_def
+            .variants
+            .iter()
+            .map(|v| {
+                Ok(Type::Object(generate_variant_properties(
+                    v, ctx, type_args, parse_args,
+                )?))
+            })
+            .collect::<Result<Vec<_>>>()?,
+    ))
+}
+
+/// References the union type of an enum declared in a generated module.
+pub(crate) fn generate_enum_ref(
+    enum_def: &IDLEnum,
+    ctx: &CodegenContext,
+    type_args: &[Type],
+) -> Option<Type> {
+    let args = enum_def
+        .type_params
+        .iter()
+        .zip(type_args)
+        .filter(|(param, _)| !param.is_phantom)
+        .map(|(_, arg)| arg.clone())
+        .collect();
+    ctx.module_type(
+        &enum_def.name.module_id().short_str_lossless(),
+        &enum_def.type_name(ctx),
+        args,
+    )
+}
+
+impl Codegen for IDLEnum {
+    /// The interfaces of the variants followed by the union type of the enum.
+    type Node = Vec<Stmt>;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Vec<Stmt>> {
+        let type_params = self.ts_type_params();
+        let type_args = type_params
+            .iter()
+            .map(|p| Type::named(&p.name))
+            .collect::<Vec<_>>();
+        let field_type_args = self.field_type_args();
+
+        let mut stmts = vec![];
+        let mut variants = vec![];
+        for variant in self.variants.iter() {
+            let name = self.variant_type_name(variant, ctx);
+            let docs = [
+                variant.doc.clone().unwrap_or_default(),
+                format!("Variant `{}` of `{}`.", variant.name, self.name),
+            ]
+            .iter()
+            .filter(|s| !s.is_empty())
+            .join("\n\n");
+            stmts.push(
+                Interface::new(
+                    &name,
+                    generate_variant_properties(variant, ctx, &field_type_args, true)?,
+                )
+                .type_params(type_params.clone())
+                .docs(&docs)
+                .into(),
+            );
+            variants.push(Type::generic(&name, type_args.clone()));
+        }
+
+        let docs = [
+            self.doc.clone().unwrap_or_default(),
+            format!("Type name: `{}`", self.name),
+        ]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .join("\n\n");
+        let ty = match variants.len() {
+            0 => Type::named("never"),
+            1 => variants.remove(0),
+            _ => Type::Union(variants),
+        };
+        stmts.push(
+            TypeAlias::new(&self.type_name(ctx), ty)
+                .type_params(type_params)
+                .docs(&docs)
+                .into(),
+        );
+        Ok(stmts)
+    }
+}
+
+/// Returns true if values of this type can be decoded from BCS by a generated decoder.
+fn is_decodable_type(ty: &IDLType, ctx: &CodegenContext) -> bool {
+    match ty {
+        IDLType::Bool | IDLType::U8 | IDLType::U64 | IDLType::U128 | IDLType::Address => true,
+        IDLType::Vector(inner) => is_decodable_type(inner, ctx),
+        IDLType::Struct(inner) => {
+            is_string_type(inner)
+                || ctx.find_enum(&inner.name).map_or(false, |e| {
+                    // Decoders of other modules are referenced through their index.
+                    e.is_decodable(ctx)
+                        && ctx
+                            .generated_module_output_name(&e.name.module_id().short_str_lossless())
+                            .is_some()
+                })
+        }
+        IDLType::Signer | IDLType::TypeParam(_) | IDLType::Tuple(_) => false,
+    }
+}
+
+/// Name of the BCS decoder of an enum in the `decoders` file of its module.
+pub fn decoder_name(enum_def: &IDLEnum, ctx: &CodegenContext) -> String {
+    IdentScope::new([ctx.prelude().namespace.as_str(), "mod", ADDRESS_LENGTH_NAME])
+        .declare(enum_def.name.name.as_str())
+}
+
+/// Generates an expression decoding a value of type `ty` from the BCS reader `reader`.
+fn decode_value(ty: &IDLType, ctx: &CodegenContext, module_id: &str) -> Result<Expr> {
+    let reader = |method: &str, args: Vec<Expr>| Expr::path("reader").member(method).call(args);
+    Ok(match ty {
+        IDLType::Bool => reader("bool", vec![]),
+        IDLType::U8 => reader("u8", vec![]),
+        IDLType::U64 => reader("u64", vec![]),
+        IDLType::U128 => reader("u128", vec![]),
+        IDLType::Address => reader("address", vec![Expr::path(ADDRESS_LENGTH_NAME)]),
+        IDLType::Vector(inner) if **inner == IDLType::U8 => reader("bytes", vec![]),
+        IDLType::Vector(inner) => reader(
+            "vector",
+            vec![Expr::arrow(
+                vec![Param::untyped("reader")],
+                None,
+                decode_value(inner, ctx, module_id)?,
+            )],
+        ),
+        IDLType::Struct(inner) if is_string_type(inner) => reader("string", vec![]),
+        IDLType::Struct(inner) => {
+            let enum_def = ctx
+                .find_enum(&inner.name)
+                .with_context(|| format!("cannot decode struct {}", inner.name))?;
+            let enum_module_id = enum_def.name.module_id().short_str_lossless();
+            let decoder = if enum_module_id == module_id {
+                Expr::path(&decoder_name(enum_def, ctx))
+            } else {
+                ctx.module_value(
+                    &enum_module_id,
+                    &format!("decoders.{}", decoder_name(enum_def, ctx)),
+                )
+                .with_context(|| format!("module {} is not generated", enum_module_id))?
+            };
+            decoder.call(vec![Expr::path("reader")])
+        }
+        IDLType::Signer | IDLType::TypeParam(_) | IDLType::Tuple(_) => {
+            bail!("cannot decode {:?}", ty)
+        }
+    })
+}
+
+/// Generates the BCS decoder of an enum, which reads the index of the variant and then its
+/// fields.
+pub fn generate_decoder(enum_def: &IDLEnum, ctx: &CodegenContext) -> Result<Const> {
+    let module_id = enum_def.name.module_id().short_str_lossless();
+    ctx.use_sibling("mod", "index", false);
+    let enum_type = Type::named(&format!("mod.{}", enum_def.type_name(ctx)));
+    let variants = enum_def
+        .variants
+        .iter()
+        .map(|variant| {
+            let names = ctx.naming().field_names(
+                std::iter::once(VARIANT_PROPERTY)
+                    .chain(variant.fields.iter().map(|f| f.name.as_str())),
+            );
+            let mut members = vec![ObjectMember::Property(
+                VARIANT_PROPERTY.to_string(),
+                Expr::string(&variant.name),
+            )];
+            for (field, name) in variant.fields.iter().zip(names.into_iter().skip(1)) {
+                members.push(ObjectMember::Property(
+                    name,
+                    decode_value(&field.ty, ctx, &module_id)
+                        .with_context(|| format!("field `{}` of {}", field.name, variant.name))?,
+                ));
+            }
+            // Variants without fields read nothing past the index.
+            let params = if variant.fields.is_empty() {
+                vec![]
+            } else {
+                vec![Param::untyped("reader")]
+            };
+            Ok(Expr::arrow(
+                params,
+                Some(enum_type.clone()),
+                Expr::Object(members),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Const::new(
+        &decoder_name(enum_def, ctx),
+        Expr::arrow(
+            vec![Param::new("reader", ctx.prelude_type("BcsReader"))],
+            Some(enum_type),
+            Expr::path("reader")
+                .member("variant")
+                .call(vec![Expr::Array(variants)]),
+        ),
+    )
+    .docs(&format!("Decodes a BCS-encoded `{}`.", enum_def.name)))
+}
@@ -1,276 +1,722 @@
-use crate::{
-    format::{gen_doc_string, gen_doc_string_opt},
-    CodeText,
+use crate::ast::{Class, ClassProperty, Const, Export, Expr, Module, Param, Stmt, Type};
+
+use super::{
+    chain::Chain,
+    ident::IdentScope,
+    idl_constant::generate_constants,
+    idl_enum::{generate_decoder, IDLEnum},
+    idl_struct::is_resource,
+    idl_type::ADDRESS_LENGTH_NAME,
+    script_function::ScriptFunctionType,
+    Codegen, CodegenContext,
 };
-
-use super::{script_function::ScriptFunctionType, Codegen, CodegenContext};
 use anyhow::*;
-use move_idl::{IDLAbility, IDLError, IDLModule, IDLScriptFunction};
+use heck::ToPascalCase;
+use move_idl::{IDLError, IDLModule, IDLScriptFunction, IDLStruct};
 use serde::Serialize;
 use std::collections::BTreeMap;
 
-const PRELUDE: &str = "import * as p from \"@movingco/prelude\";\n";
-
 #[derive(Serialize)]
 struct ErrorInfo {
     /// Error code.
     code: u64,
+    /// Name of the `std::error` category of the code, if it has one.
+    category: Option<&'static str>,
+    /// Reason of the code, which is the code itself if it has no category.
+    reason: u64,
     /// Error.
     #[serde(flatten)]
     error: IDLError,
 }
 
+/// An error of a module, with the names of its declarations in the `errors` file.
+pub struct ErrorDeclaration<'info> {
+    pub code: u64,
+    pub error: &'info IDLError,
+    /// Name of the const describing the error.
+    pub const_name: String,
+    /// Name of the class of the error, if error classes are generated.
+    pub class_name: Option<String>,
+}
+
+/// Name of the class of an error, e.g. `ENotOwner` for `E_NOT_OWNER` or `ENotOwner`.
+///
+/// Only an `E_` prefix, or the `E` of a camel case name followed by a capitalized word, is
+/// replaced by the `E` of the class. Other names keep all their letters, so `EMPTY_POOL` becomes
+/// `EEmptyPool`.
+pub fn error_class_name(name: &str) -> String {
+    let is_camel_case = !name.contains('_') && name.contains(|c: char| c.is_ascii_lowercase());
+    let name = name
+        .strip_prefix("E_")
+        .or_else(|| {
+            name.strip_prefix('E')
+                .filter(|rest| is_camel_case && rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        })
+        .unwrap_or(name);
+    format!("E{}", name.to_pascal_case())
+}
+
+/// Categories of the error codes built by `std::error`, by value.
+const ERROR_CATEGORIES: &[(u64, &str)] = &[
+    (0x1, "INVALID_ARGUMENT"),
+    (0x2, "OUT_OF_RANGE"),
+    (0x3, "INVALID_STATE"),
+    (0x4, "UNAUTHENTICATED"),
+    (0x5, "PERMISSION_DENIED"),
+    (0x6, "NOT_FOUND"),
+    (0x7, "ABORTED"),
+    (0x8, "ALREADY_EXISTS"),
+    (0x9, "RESOURCE_EXHAUSTED"),
+    (0xA, "CANCELLED"),
+    (0xB, "INTERNAL"),
+    (0xC, "NOT_IMPLEMENTED"),
+    (0xD, "UNAVAILABLE"),
+];
+
+/// Splits an error code into its `std::error` category and reason.
+///
+/// `std::error` encodes codes as `category << 16 | reason`. Codes which do not fit this encoding
+/// or have an unknown category are returned as is, without a category.
+pub fn decompose_error_code(code: u64) -> (Option<&'static str>, u64) {
+    if code >> 24 != 0 {
+        return (None, code);
+    }
+    ERROR_CATEGORIES
+        .iter()
+        .find(|(value, _)| *value == code >> 16)
+        .map_or((None, code), |(_, name)| (Some(*name), code & 0xffff))
+}
+
+impl ErrorInfo {
+    fn new(code: u64, error: &IDLError) -> Self {
+        let (category, reason) = decompose_error_code(code);
+        ErrorInfo {
+            code,
+            category,
+            reason,
+            error: error.clone(),
+        }
+    }
+}
+
+/// Files generated for each module, without extension.
+const MODULE_FILES: &[&str] = &[
+    "entry",
+    "entryNames",
+    "payloads",
+    "bcs",
+    "idl",
+    "errors",
+    "constants",
+    "decoders",
+    "index",
+];
+
+/// Names declared by the module index besides struct interfaces and payload argument types.
+const INDEX_EXPORTS: &[&str] = &[
+    "entry",
+    "payloads",
+    "entryNames",
+    "bcs",
+    "idl",
+    "ADDRESS",
+    "FULL_NAME",
+    "NAME",
+    "id",
+    "errors",
+    "constants",
+    "decoders",
+    "errorCodes",
+    "functions",
+    "resources",
+    "structs",
+    "enums",
+    "fieldNames",
+    "moduleImpl",
+    "moduleDefinition",
+];
+
 pub struct IDLModuleGenerator<'info> {
     pub module: &'info IDLModule,
+    /// Structs of the module, in generation order.
+    pub structs: Vec<&'info IDLStruct>,
+    /// Enums of the module, in generation order.
+    pub enums: Vec<IDLEnum>,
+    /// Script functions of the module, in generation order.
     pub script_fns: Vec<ScriptFunctionType<'info>>,
+    /// Name of the output directory of the module.
+    pub output_name: String,
 }
 
 impl<'info> IDLModuleGenerator<'info> {
-    pub fn new(module: &'info IDLModule) -> Self {
-        let script_fns = module
-            .functions
-            .iter()
-            .map(|script_fn| ScriptFunctionType::new(module, script_fn))
+    pub fn new(module: &'info IDLModule, ctx: &CodegenContext) -> Self {
+        let naming = ctx.naming();
+        let order = ctx.order();
+        let mut enums = ctx
+            .module_enums(&module.module_id.short_str_lossless())
+            .to_vec();
+        order.sort_by_name(&mut enums, |e| e.name.name.as_str());
+        // Builders share entry.ts and bcs.ts with their imports, payload types share
+        // payloads.ts, and argument types share the module index with the struct interfaces
+        // and its exports.
+        let mut fn_scope = IdentScope::new([
+            ctx.prelude().namespace.as_str(),
+            "mod",
+            "payloads",
+            ADDRESS_LENGTH_NAME,
+        ]);
+        let mut payload_scope = IdentScope::default();
+        let mut args_scope = IdentScope::new(
+            module
+                .structs
+                .iter()
+                .map(|s| naming.struct_type_name(&s.name.name.to_string()))
+                .chain(enums.iter().flat_map(|e| {
+                    std::iter::once(e.type_name(ctx))
+                        .chain(e.variants.iter().map(|v| e.variant_type_name(v, ctx)))
+                }))
+                .chain(INDEX_EXPORTS.iter().map(|s| s.to_string()))
+                .chain([ctx.prelude().namespace.clone()]),
+        );
+        let mut structs = module.structs.iter().collect::<Vec<_>>();
+        order.sort_by_name(&mut structs, |s| s.name.name.as_str());
+        let mut functions = module.functions.iter().collect::<Vec<_>>();
+        order.sort_by_name(&mut functions, |f| f.name.as_str());
+
+        let script_fns = functions
+            .into_iter()
+            .map(|script_fn| {
+                let fn_name = fn_scope.declare(&naming.function_name(&script_fn.name));
+                let type_name = payload_scope.declare(&naming.payload_type_name(&script_fn.name));
+                let args_type_name = args_scope.declare(&format!("{}Args", type_name));
+                ScriptFunctionType::new(module, script_fn, naming, ctx.chain()).with_names(
+                    fn_name,
+                    type_name,
+                    args_type_name,
+                )
+            })
             .collect::<Vec<_>>();
-        IDLModuleGenerator { module, script_fns }
+        IDLModuleGenerator {
+            module,
+            structs,
+            enums,
+            script_fns,
+            output_name: ctx.module_output_name(module),
+        }
+    }
+
+    /// Sets the name of the output directory of the module.
+    pub fn with_output_name(self, output_name: String) -> Self {
+        IDLModuleGenerator {
+            output_name,
+            ..self
+        }
     }
 
     fn generate_module_header(&self) -> String {
         format!("**Module ID:** `{}`", self.module.module_id)
     }
 
+    /// Documentation of the module index.
     pub fn generate_module_doc(&self) -> String {
-        gen_doc_string(
-            &[
-                self.module.doc.clone().unwrap_or_default(),
-                self.generate_module_header(),
-                "@module".to_string(),
-            ]
-            .into_iter()
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-            .join("\n\n"),
-        )
+        [
+            self.module.doc.clone().unwrap_or_default(),
+            self.generate_module_header(),
+        ]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
     }
 
     pub fn has_entrypoints(&self) -> bool {
         !self.module.functions.is_empty()
     }
 
-    pub fn generate_entrypoint_bodies(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        ctx.try_join(&self.script_fns)
+    /// Returns true if the module has BCS payload builders, which need the bundled prelude.
+    pub fn has_bcs(&self, ctx: &CodegenContext) -> bool {
+        self.has_entrypoints() && ctx.prelude().is_bundled()
     }
 
-    pub fn generate_entrypoint_module(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        Ok(format!(
-            "{}{}\nimport * as mod from './index.js';\nimport * as payloads from './payloads.js';\n{}",
-            gen_doc_string("Entrypoint builders.\n\n@module"),
-            PRELUDE,
-            self.generate_entrypoint_bodies(ctx)?
-        )
-        .into())
+    pub fn generate_entrypoint_bodies(&self, ctx: &CodegenContext) -> Result<Vec<Stmt>> {
+        self.script_fns.iter().map(|f| ctx.generate(f)).collect()
     }
 
-    pub fn generate_idl_module(&self) -> Result<CodeText> {
-        Ok(format!(
-            "{}{}export const idl = {} as const;",
-            gen_doc_string("The IDL of the module.\n\n@module"),
-            gen_doc_string("The IDL of the module."),
-            &serde_json::to_string(self.module)?,
-        )
-        .into())
+    /// Path of a file of the module, relative to the output root and without extension.
+    fn file(&self, name: &str) -> String {
+        format!("{}/{}", self.output_name, name)
     }
 
-    pub fn generate_errors_module(&self) -> Result<Option<CodeText>> {
-        if self.module.errors.is_empty() {
-            return Ok(None);
-        }
+    pub fn generate_entrypoint_module(&self, ctx: &CodegenContext) -> Result<Module> {
+        ctx.generate_file(&self.file("entry"), || {
+            Ok(Module::new()
+                .docs("Entrypoint builders.")
+                .items(self.generate_entrypoint_bodies(ctx)?))
+        })
+    }
 
-        let errors = self
-            .module
-            .errors
-            .iter()
-            .map(|(code, error)| -> Result<CodeText> {
-                Ok(format!(
-                    "{}export const {} = {} as const;",
-                    gen_doc_string_opt(&error.doc),
-                    error.name,
-                    serde_json::to_string_pretty(&ErrorInfo {
-                        code: *code,
-                        error: error.clone()
-                    })?
+    /// Generates the builders of BCS-encoded transaction payloads.
+    pub fn generate_bcs_module(&self, ctx: &CodegenContext) -> Result<Module> {
+        ctx.generate_file(&self.file("bcs"), || {
+            Ok(Module::new()
+                .docs(match ctx.chain() {
+                    Chain::Aptos => {
+                        "Builders of BCS-encoded `TransactionPayload`s calling the entry functions."
+                    }
+                    Chain::Sui => {
+                        "Builders of the Move calls of the entry functions, with BCS-encoded pure arguments."
+                    }
+                })
+                .item(
+                    Const::new(
+                        ADDRESS_LENGTH_NAME,
+                        Expr::json(&ctx.chain().address_length())?.as_const(),
+                    )
+                    .private()
+                    .docs("Length of an account address in bytes."),
                 )
-                .into())
-            })
-            .collect::<Result<Vec<CodeText>>>()?;
-
-        Ok(Some(
-            format!(
-                "{}{}",
-                gen_doc_string("Module errors.\n\n@module"),
-                CodeText::try_join_with_separator(&errors, "\n\n")?
-            )
-            .into(),
-        ))
+                .items(
+                    self.script_fns
+                        .iter()
+                        .map(|f| f.generate_bcs_encoder(ctx))
+                        .collect::<Result<Vec<_>>>()?,
+                ))
+        })
     }
 
-    pub fn generate_entry_payloads_module(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        Ok(ctx
-            .try_join(
-                &self
-                    .script_fns
-                    .iter()
-                    .map(|f| f.generate_entry_payload_struct(ctx))
-                    .collect::<Result<Vec<_>>>()?,
-            )?
-            .module_docs("Entrypoint script function payloads."))
+    pub fn generate_idl_module(&self, ctx: &CodegenContext) -> Result<Module> {
+        ctx.generate_file(&self.file("idl"), || {
+            Ok(Module::new()
+                .docs("The IDL of the module.")
+                .item(Const::json("idl", self.module)?.docs("The IDL of the module.")))
+        })
     }
 
-    pub fn generate_entry_names_module(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        Ok(ctx
-            .try_join(
-                &self
-                    .script_fns
-                    .iter()
-                    .map(|f| {
-                        Ok(CodeText::new_const_export(f.name(), &f.full_name())?
-                            .docs(&format!("Script function type for `{}`.", f.full_name())))
-                    })
-                    .collect::<Result<Vec<CodeText>>>()?,
-            )?
-            .module_docs("Names of all script functions."))
+    /// Errors of the module in declaration order, with the names of their declarations in the
+    /// `errors` file.
+    pub fn error_declarations(&self, ctx: &CodegenContext) -> Vec<ErrorDeclaration<'info>> {
+        let mut errors = self.module.errors.iter().collect::<Vec<_>>();
+        ctx.order()
+            .sort_by_name(&mut errors, |(_, error)| error.name.as_str());
+        let mut scope = IdentScope::default();
+        let mut errors = errors
+            .into_iter()
+            .map(|(code, error)| ErrorDeclaration {
+                code: *code,
+                error,
+                const_name: scope.declare(&error.name),
+                class_name: None,
+            })
+            .collect::<Vec<_>>();
+        if ctx.error_classes() {
+            for error in errors.iter_mut() {
+                error.class_name = Some(scope.declare(&error_class_name(&error.error.name)));
+            }
+        }
+        errors
     }
-}
 
-impl Codegen for IDLModule {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
-        let gen = IDLModuleGenerator::new(self);
-        let name = self.module_id.name();
+    pub fn generate_errors_module(&self, ctx: &CodegenContext) -> Result<Option<Module>> {
+        if self.module.errors.is_empty() {
+            return Ok(None);
+        }
 
-        let function_payloads = ctx.try_join(
-            &gen.script_fns
+        let errors = self.error_declarations(ctx);
+        let mut scope = IdentScope::new(errors.iter().flat_map(|error| {
+            std::iter::once(error.const_name.clone()).chain(error.class_name.clone())
+        }));
+        Ok(Some(ctx.generate_file(&self.file("errors"), || {
+            let consts = errors
                 .iter()
-                .filter_map(|f| {
-                    if f.should_render_payload_struct() {
-                        Some(f.payload())
-                    } else {
-                        None
-                    }
+                .map(|error| -> Result<Const> {
+                    Ok(
+                        Const::json(&error.const_name, &ErrorInfo::new(error.code, error.error))?
+                            .docs_opt(&error.error.doc),
+                    )
                 })
-                .collect::<Vec<_>>(),
-        )?;
+                .collect::<Result<Vec<_>>>()?;
+            let classes = errors
+                .iter()
+                .filter_map(|error| {
+                    Some(self.generate_error_class(error, error.class_name.as_ref()?, ctx))
+                })
+                .collect::<Vec<_>>();
+            let error_type = Type::Union(
+                errors
+                    .iter()
+                    .map(|error| Type::Typeof(error.const_name.clone()))
+                    .collect(),
+            );
+            // `isError` compares codes with a helper of the bundled prelude.
+            let is_error = ctx.prelude().is_bundled().then(|| {
+                Const::new(
+                    &scope.declare("isError"),
+                    Expr::arrow(
+                        vec![
+                            Param::new("code", Type::named("number")),
+                            Param::new("error", error_type),
+                        ],
+                        Some(Type::named("boolean")),
+                        ctx.prelude_value("matchesError")
+                            .call(vec![Expr::path("code"), Expr::path("error")]),
+                    ),
+                )
+                .docs(
+                    "Whether the abort code `code` is the error `error` of this module.\n\n\
+                    Codes are compared by their reason, so that an error declared without a \
+                    `std::error` category matches aborts with any category, and conversely.",
+                )
+            });
+            Ok(Module::new()
+                .docs("Module errors.")
+                .items(consts)
+                .items(classes)
+                .items(is_error))
+        })?))
+    }
 
-        let struct_types = ctx.try_join(&self.structs)?;
+    /// Generates the class of an error, which is thrown for the aborts with its code.
+    fn generate_error_class(
+        &self,
+        error: &ErrorDeclaration,
+        class_name: &str,
+        ctx: &CodegenContext,
+    ) -> Class {
+        let docs = format!(
+            "Error `{}` of `{}`, with code `{}`.{}",
+            error.error.name,
+            self.module.module_id.short_str_lossless(),
+            error.code,
+            error
+                .error
+                .doc
+                .as_ref()
+                .map(|doc| format!("\n\n{}", doc))
+                .unwrap_or_default()
+        );
+        Class::new(
+            class_name,
+            vec![],
+            vec![
+                ClassProperty::new(
+                    "module",
+                    Expr::string(&self.module.module_id.short_str_lossless()),
+                ),
+                ClassProperty::new("code", Expr::Literal(error.code.into())),
+                ClassProperty::new("errorName", Expr::string(&error.error.name)),
+            ],
+        )
+        .extends(ctx.prelude_value("MoveAbortError"))
+        .docs(&docs)
+    }
 
-        let mut fn_map: BTreeMap<String, IDLScriptFunction> = BTreeMap::new();
-        for script_fn in self.functions.iter() {
-            fn_map.insert(script_fn.name.clone(), script_fn.clone());
+    /// Generates the constants of the module other than error codes, or `None` if it has none.
+    pub fn generate_constants_module(&self, ctx: &CodegenContext) -> Result<Option<Module>> {
+        let module_id = self.module.module_id.short_str_lossless();
+        if ctx.module_constants(&module_id).is_empty() {
+            return Ok(None);
         }
+        Ok(Some(ctx.generate_file(&self.file("constants"), || {
+            Ok(Module::new()
+                .docs("Module constants.")
+                .items(generate_constants(&module_id, ctx)?))
+        })?))
+    }
 
-        let mut resources: BTreeMap<String, String> = BTreeMap::new();
-        for struct_info in self
-            .structs
-            .iter()
-            .filter(|s| s.abilities.contains(&IDLAbility::Key))
-        {
-            resources.insert(
-                struct_info.name.name.to_string(),
-                struct_info.name.to_string(),
-            );
+    /// Generates the BCS decoders of the enums of the module, or `None` if none can be decoded.
+    pub fn generate_decoders_module(&self, ctx: &CodegenContext) -> Result<Option<Module>> {
+        if !self.has_decoders(ctx) {
+            return Ok(None);
         }
+        Ok(Some(ctx.generate_file(&self.file("decoders"), || {
+            Ok(Module::new()
+                .docs("BCS decoders of the enums of the module.")
+                .item(
+                    Const::new(
+                        ADDRESS_LENGTH_NAME,
+                        Expr::json(&ctx.chain().address_length())?.as_const(),
+                    )
+                    .private()
+                    .docs("Length of an account address in bytes."),
+                )
+                .items(
+                    self.enums
+                        .iter()
+                        .filter(|e| e.is_decodable(ctx))
+                        .map(|e| {
+                            generate_decoder(e, ctx)
+                                .with_context(|| format!("decoder of {}", e.name))
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                ))
+        })?))
+    }
 
-        let mut structs: BTreeMap<String, String> = BTreeMap::new();
-        for struct_info in self.structs.iter() {
-            structs.insert(
-                struct_info.name.name.to_string(),
-                struct_info.name.to_string(),
-            );
+    /// Returns true if any enum of the module can be decoded from BCS.
+    pub fn has_decoders(&self, ctx: &CodegenContext) -> bool {
+        self.enums.iter().any(|e| e.is_decodable(ctx))
+    }
+
+    pub fn generate_entry_payloads_module(&self, ctx: &CodegenContext) -> Result<Module> {
+        ctx.generate_file(&self.file("payloads"), || {
+            Ok(Module::new()
+                .docs("Entrypoint script function payloads.")
+                .items(
+                    self.script_fns
+                        .iter()
+                        .map(|f| f.generate_entry_payload_struct(ctx))
+                        .collect::<Result<Vec<_>>>()?,
+                ))
+        })
+    }
+
+    /// Generates the additional files of the module contributed by the generators of `ctx`,
+    /// keyed by file name without extension.
+    pub fn generate_extension_modules(
+        &self,
+        ctx: &CodegenContext,
+    ) -> Result<BTreeMap<String, Module>> {
+        let mut modules = BTreeMap::new();
+        for generator in ctx.generators() {
+            for name in generator.module_files(self, ctx) {
+                if MODULE_FILES.contains(&name.as_str()) || modules.contains_key(&name) {
+                    bail!(
+                        "file `{}` of module {} is generated more than once",
+                        name,
+                        self.module.module_id
+                    );
+                }
+                let module = ctx.generate_file(&self.file(&name), || {
+                    generator.generate_module_file(&name, self, ctx)
+                })?;
+                modules.insert(name, module);
+            }
         }
+        Ok(modules)
+    }
+
+    pub fn generate_entry_names_module(&self, ctx: &CodegenContext) -> Result<Module> {
+        ctx.generate_file(&self.file("entryNames"), || {
+            Ok(Module::new().docs("Names of all script functions.").items(
+                self.script_fns.iter().map(|f| {
+                    Const::new(f.name(), Expr::string(&f.full_name()).as_const())
+                        .docs(&format!("Script function type for `{}`.", f.full_name()))
+                }),
+            ))
+        })
+    }
+}
+
+impl Codegen for IDLModule {
+    type Node = Module;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Module> {
+        let gen = IDLModuleGenerator::new(self, ctx);
+        ctx.generate_file(&gen.file("index"), || {
+            generate_module_index(self, &gen, ctx)
+        })
+    }
+}
+
+/// Generates the index of a module, which declares its types and re-exports its other files.
+fn generate_module_index(
+    module: &IDLModule,
+    gen: &IDLModuleGenerator,
+    ctx: &CodegenContext,
+) -> Result<Module> {
+    let name = module.module_id.name();
+
+    let function_payloads = gen
+        .script_fns
+        .iter()
+        .filter(|f| f.should_render_payload_struct())
+        .map(|f| ctx.generate(&f.payload()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let struct_types = gen
+        .structs
+        .iter()
+        .map(|s| ctx.generate(*s))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten();
+
+    let enum_types = gen
+        .enums
+        .iter()
+        .map(|e| ctx.generate(e))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten();
+
+    let mut fn_map: BTreeMap<String, IDLScriptFunction> = BTreeMap::new();
+    for script_fn in module.functions.iter() {
+        fn_map.insert(script_fn.name.clone(), script_fn.clone());
+    }
+
+    let mut resources: BTreeMap<String, String> = BTreeMap::new();
+    for struct_info in module.structs.iter().filter(|s| is_resource(s)) {
+        resources.insert(
+            struct_info.name.name.to_string(),
+            struct_info.name.to_string(),
+        );
+    }
 
-        let ts = format!(
-            r#"{}{}
-
-{}
-
-{}
-
-{}
-
-export {{ idl }} from "./idl.js";
-
-/** The address of the module. */
-export const ADDRESS = "{}" as const;
-/** The full module name. */
-export const FULL_NAME = "{}" as const;
-/** The name of the module. */
-export const NAME = "{}" as const;
-
-/** Module ID information. */
-export const id = {{
-  ADDRESS,
-  FULL_NAME,
-  NAME,
-}} as const;
-
-{}
-
-/** Module error codes. */
-export const errorCodes = {} as const;
-
-/** All module function IDLs. */
-export const functions = {} as const;
-
-/** All struct types with ability `key`. */
-export const resources = {} as const;
-
-/** All struct types. */
-export const structs = {} as const;
-
-/** Payload generators for module `{}`. */
-const moduleImpl = {{
-  ...id,
-  errorCodes,
-  functions,
-  resources,
-  structs,
-}} as const;
-
-{}export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<"{}", "{}"> as typeof moduleImpl;
-"#,
-            gen.generate_module_doc(),
-            PRELUDE,
-            struct_types,
-            function_payloads,
-            if gen.has_entrypoints() {
-                CodeText::try_join_with_separator(
-                    &[
-                        CodeText::new_reexport("entry", "./entry.js"),
-                        CodeText::new_reexport("payloads", "./payloads.js"),
-                        CodeText::new_reexport("entryNames", "./entryNames.js"),
-                    ],
-                    "\n",
-                )?
-            } else {
-                CodeText::new("")
-            },
-            self.module_id.address().to_hex_literal(),
-            self.module_id.short_str_lossless(),
-            self.module_id.name(),
-            if !gen.module.errors.is_empty() {
-                "export * as errors from \"./errors.js\";"
-            } else {
-                ""
-            },
-            serde_json::to_string_pretty(&self.errors)?,
-            serde_json::to_string_pretty(&fn_map)?,
-            serde_json::to_string_pretty(&resources)?,
-            serde_json::to_string_pretty(&structs)?,
-            self.module_id.short_str_lossless(),
-            gen_doc_string_opt(&self.doc),
-            self.module_id.address().to_hex_literal(),
-            name,
+    let mut structs: BTreeMap<String, String> = BTreeMap::new();
+    for struct_info in module.structs.iter() {
+        structs.insert(
+            struct_info.name.name.to_string(),
+            struct_info.name.to_string(),
         );
+    }
+
+    let mut enums: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for enum_info in gen.enums.iter() {
+        enums.insert(
+            enum_info.name.name.to_string(),
+            enum_info.variants.iter().map(|v| v.name.clone()).collect(),
+        );
+    }
+
+    let mut field_names: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    for struct_info in module.structs.iter() {
+        let renamed = ctx
+            .naming()
+            .field_names(struct_info.fields.iter().map(|f| f.name.as_str()))
+            .into_iter()
+            .zip(struct_info.fields.iter().map(|f| f.name.clone()))
+            .filter(|(ts_name, move_name)| ts_name != move_name)
+            .collect::<BTreeMap<_, _>>();
+        if !renamed.is_empty() {
+            field_names.insert(struct_info.name.name.to_string(), renamed);
+        }
+    }
+
+    let address = module.module_id.address().to_hex_literal();
+    let full_name = module.module_id.short_str_lossless();
+    let module_name = name.to_string();
+
+    let id_fields = ["ADDRESS", "FULL_NAME", "NAME"];
+    let impl_fields = [
+        "ADDRESS",
+        "FULL_NAME",
+        "NAME",
+        "errorCodes",
+        "functions",
+        "resources",
+        "structs",
+    ];
+
+    let mut exports = vec![];
+    if gen.has_entrypoints() {
+        exports.push(Export::namespace("entry", &ctx.import_path("./entry")));
+        exports.push(Export::namespace(
+            "payloads",
+            &ctx.import_path("./payloads"),
+        ));
+        exports.push(Export::namespace(
+            "entryNames",
+            &ctx.import_path("./entryNames"),
+        ));
+    }
+    if gen.has_bcs(ctx) {
+        exports.push(Export::namespace("bcs", &ctx.import_path("./bcs")));
+    }
+    exports.push(Export::named("idl", None, &ctx.import_path("./idl")));
+    if gen.has_decoders(ctx) {
+        exports.push(Export::namespace(
+            "decoders",
+            &ctx.import_path("./decoders"),
+        ));
+    }
 
-        Ok(ts)
+    let mut errors = vec![];
+    if !gen.module.errors.is_empty() {
+        errors.push(Export::namespace("errors", &ctx.import_path("./errors")));
     }
+    let mut constants = vec![];
+    if !ctx
+        .module_constants(&module.module_id.short_str_lossless())
+        .is_empty()
+    {
+        constants.push(Export::namespace(
+            "constants",
+            &ctx.import_path("./constants"),
+        ));
+    }
+
+    let mut metadata = vec![
+        Const::json("errorCodes", &module.errors)?.docs("Module error codes."),
+        Const::json("functions", &fn_map)?.docs("All module function IDLs."),
+        Const::json("resources", &resources)?.docs("All struct types with ability `key`."),
+        Const::json("structs", &structs)?.docs("All struct types."),
+    ];
+    if !enums.is_empty() {
+        metadata.push(
+            Const::json("enums", &enums)?
+                .docs("Variant names of the enum types, in the order of their indices."),
+        );
+    }
+    if !field_names.is_empty() {
+        metadata.push(Const::json("fieldNames", &field_names)?.docs(
+            "Move names of the struct fields renamed in the generated types, keyed by struct name.",
+        ));
+    }
+
+    Ok(Module::new()
+        .docs(&gen.generate_module_doc())
+        .section(
+            "types",
+            struct_types.chain(enum_types).chain(function_payloads),
+        )
+        .section("exports", exports)
+        .section(
+            "id",
+            [
+                Const::new("ADDRESS", Expr::string(&address).as_const())
+                    .docs("The address of the module."),
+                Const::new("FULL_NAME", Expr::string(&full_name).as_const())
+                    .docs("The full module name."),
+                Const::new("NAME", Expr::string(&module_name).as_const())
+                    .docs("The name of the module."),
+                Const::new("id", Expr::shorthand_object(&id_fields).as_const())
+                    .docs("Module ID information."),
+            ],
+        )
+        .section("errors", errors)
+        .section("constants", constants)
+        .section("metadata", metadata)
+        .section(
+            "definition",
+            [
+                Const::new(
+                    "moduleImpl",
+                    Expr::shorthand_object(&impl_fields).as_const(),
+                )
+                .private()
+                .docs(&format!("Payload generators for module `{}`.", full_name)),
+                Const::new(
+                    "moduleDefinition",
+                    Expr::path("moduleImpl")
+                        .as_type(ctx.prelude_generic(
+                            "MoveModuleDefinition",
+                            vec![
+                                Type::string_literal(&address),
+                                Type::string_literal(&module_name),
+                            ],
+                        ))
+                        .as_type(Type::Typeof("moduleImpl".to_string())),
+                )
+                .docs_opt(&module.doc),
+            ],
+        )
+        .section(
+            "extensions",
+            ctx.generators()
+                .iter()
+                .map(|generator| generator.generate_index_items(gen, ctx))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .flatten(),
+        ))
 }
@@ -1,82 +1,816 @@
 use anyhow::*;
-use heck::ToSnakeCase;
-use move_idl::{IDLModule, IDLPackage};
+use heck::{ToKebabCase, ToPascalCase, ToSnakeCase};
+use move_idl::{IDLError, IDLModule, IDLPackage};
+use serde_json::{json, Value};
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
 
-use crate::{idl_module::IDLModuleGenerator, CodeText, CodegenContext};
+use crate::{
+    ast::{Class, ClassProperty, Const, Export, Expr, Module, ObjectMember, Param, Stmt, Type},
+    chain::Chain,
+    emit::{EmitTarget, OutputFormat},
+    format::FormatOptions,
+    generator::Generator,
+    ident::{sanitize_identifier, IdentScope},
+    idl_constant::IDLConstants,
+    idl_enum::IDLEnums,
+    idl_module::IDLModuleGenerator,
+    idl_struct::{generate_resource_fetcher, is_resource},
+    idl_view::{generate_view_method, IDLViews},
+    module_filter::ModuleFilter,
+    naming::NamingStrategy,
+    ordering::DeclarationOrder,
+    prelude::{bundled_prelude, PreludeConfig, PreludeSource, BUNDLED_PRELUDE_NAME},
+    templates::Templates,
+    CodeText, CodegenContext,
+};
 
 /// Generates the module re-exports for the given module names.
-pub fn generate_module_reexports<'a, I>(prefix: &str, module_names: I) -> Result<CodeText>
+///
+/// Export names colliding with each other or with the exports of the errmap, e.g.
+/// `{prefix}_errmap`, are renamed.
+pub fn generate_module_reexports<'a, I>(
+    ctx: &CodegenContext,
+    prefix: &str,
+    module_names: I,
+) -> Vec<Export>
 where
     I: IntoIterator<Item = &'a String>,
 {
-    Ok(module_names
+    let mut scope = IdentScope::new([
+        errmap_export_name(prefix),
+        parse_abort_export_name(prefix),
+        to_abort_error_export_name(prefix),
+        is_error_export_name(prefix),
+    ]);
+    module_names
         .into_iter()
         .map(|name| {
-            format!(
-                "export * as {}_{} from \"./{}/index.js\";",
-                prefix, name, name
+            Export::namespace(
+                &scope.declare(&ctx.naming().module_export_name(prefix, name)),
+                &ctx.import_path(&format!("./{}/index", name)),
             )
         })
-        .collect::<Vec<_>>()
-        .join("\n")
-        .into())
+        .collect()
+}
+
+/// Name of the errmap export of the package index.
+fn errmap_export_name(prefix: &str) -> String {
+    sanitize_identifier(&format!("{}_errmap", prefix))
+}
+
+/// Name of the `toAbortError` export of the package index.
+fn to_abort_error_export_name(prefix: &str) -> String {
+    sanitize_identifier(&format!("{}_to_abort_error", prefix))
+}
+
+/// Name of the `isPackageError` export of the package index.
+fn is_error_export_name(prefix: &str) -> String {
+    sanitize_identifier(&format!("{}_is_error", prefix))
+}
+
+/// Name of the `parseAbort` export of the package index.
+fn parse_abort_export_name(prefix: &str) -> String {
+    sanitize_identifier(&format!("{}_parse_abort", prefix))
+}
+
+/// Generates the `exports` entry of a compiled module at `path`, relative to the package root.
+fn package_export_entry(path: &str, format: OutputFormat) -> Value {
+    match format {
+        OutputFormat::TypeScript => json!({
+            "types": format!("./dist/{}.d.ts", path),
+            "import": format!("./dist/{}.js", path),
+        }),
+        OutputFormat::JavaScript { commonjs: false } => json!({
+            "types": format!("./{}.d.ts", path),
+            "import": format!("./{}.js", path),
+        }),
+        OutputFormat::JavaScript { commonjs: true } => json!({
+            "import": {
+                "types": format!("./{}.d.ts", path),
+                "default": format!("./{}.js", path),
+            },
+            "require": {
+                "types": format!("./{}.d.cts", path),
+                "default": format!("./{}.cjs", path),
+            },
+        }),
+    }
 }
 
 pub struct IDLPackageGenerator<'info> {
     idl: &'info IDLPackage,
     pub modules_to_generate: Vec<IDLModule>,
     pub ctx: CodegenContext<'info>,
+    /// Whether the package index exports a client class.
+    client: bool,
 }
 
-fn get_modules_to_generate(idl: &IDLPackage, with_dependencies: bool) -> Vec<IDLModule> {
-    let mut modules = idl.modules.values().cloned().collect::<Vec<_>>();
+fn get_modules_to_generate(
+    idl: &IDLPackage,
+    with_dependencies: bool,
+    filter: &ModuleFilter,
+) -> Vec<IDLModule> {
+    let mut modules = idl
+        .modules
+        .values()
+        .filter(|m| filter.includes_module(m))
+        .cloned()
+        .collect::<Vec<_>>();
     if with_dependencies {
-        modules.append(&mut idl.dependencies.values().cloned().collect::<Vec<_>>());
+        modules.extend(
+            idl.dependencies
+                .values()
+                .filter(|m| filter.includes_dependency(m))
+                .cloned(),
+        );
     }
     modules
 }
 
+/// Sorts modules by `order`, keeping the modules of the package before its dependencies.
+fn sort_modules(idl: &IDLPackage, modules: &mut [IDLModule], order: DeclarationOrder) {
+    modules.sort_by(|a, b| {
+        let a_dep = !idl.modules.contains_key(&a.module_id);
+        let b_dep = !idl.modules.contains_key(&b.module_id);
+        a_dep.cmp(&b_dep).then_with(|| order.compare_modules(a, b))
+    });
+}
+
 impl<'info> IDLPackageGenerator<'info> {
     pub fn new(idl: &'info IDLPackage, with_dependencies: bool) -> Self {
-        IDLPackageGenerator {
+        Self::new_filtered(idl, with_dependencies, &ModuleFilter::default())
+    }
+
+    /// Creates a generator for the modules selected by `filter`.
+    pub fn new_filtered(
+        idl: &'info IDLPackage,
+        with_dependencies: bool,
+        filter: &ModuleFilter,
+    ) -> Self {
+        let mut modules_to_generate = get_modules_to_generate(idl, with_dependencies, filter);
+        sort_modules(idl, &mut modules_to_generate, DeclarationOrder::default());
+        let gen = IDLPackageGenerator {
             idl,
-            modules_to_generate: get_modules_to_generate(idl, with_dependencies),
+            modules_to_generate,
             ctx: CodegenContext::new(idl),
+            client: false,
+        };
+        let module_output_names = gen
+            .modules_to_generate
+            .iter()
+            .map(|m| (m.module_id.short_str_lossless(), gen.module_output_name(m)))
+            .collect();
+        IDLPackageGenerator {
+            ctx: gen.ctx.with_module_output_names(module_output_names),
+            ..gen
+        }
+    }
+
+    /// Sets the prelude import configuration used by all generated modules.
+    pub fn with_prelude(self, prelude: PreludeConfig) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_prelude(prelude),
+            ..self
+        }
+    }
+
+    /// Sets the kind of file to emit.
+    pub fn with_target(self, target: EmitTarget) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_target(target),
+            ..self
+        }
+    }
+
+    /// Sets the naming conventions of generated identifiers.
+    pub fn with_naming(self, naming: NamingStrategy) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_naming(naming),
+            ..self
+        }
+    }
+
+    /// Sets the formatting of generated code.
+    pub fn with_format(self, format: FormatOptions) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_format(format),
+            ..self
+        }
+    }
+
+    /// Adds a generator of additional output for each module.
+    pub fn with_generator(self, generator: Arc<dyn Generator>) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_generator(generator),
+            ..self
+        }
+    }
+
+    /// Sets the templates which lay out the generated files.
+    pub fn with_templates(self, templates: Templates) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_templates(templates),
+            ..self
+        }
+    }
+
+    /// Sets whether each error constant gets an error class, and the errmap a type guard of
+    /// the errors of the package.
+    ///
+    /// The classes extend `MoveAbortError` of the bundled prelude, so [Self::generate_all]
+    /// fails unless the prelude is bundled.
+    pub fn with_error_classes(self, error_classes: bool) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_error_classes(error_classes),
+            ..self
+        }
+    }
+
+    /// Sets the constants of the modules other than error codes, keyed by module ID.
+    pub fn with_constants(self, constants: IDLConstants) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_constants(constants),
+            ..self
+        }
+    }
+
+    /// Sets the enums of the modules, keyed by module ID.
+    pub fn with_enums(self, enums: IDLEnums) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_enums(enums),
+            ..self
+        }
+    }
+
+    /// Sets the view functions of the modules, keyed by module ID, which are called by the
+    /// client class.
+    pub fn with_views(self, views: IDLViews) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_views(views),
+            ..self
+        }
+    }
+
+    /// Sets whether the package index exports a client class, see [Self::generate_client].
+    ///
+    /// The client relies on the providers of the bundled prelude, so [Self::generate_all]
+    /// fails unless the prelude is bundled.
+    pub fn with_client(self, client: bool) -> Self {
+        IDLPackageGenerator { client, ..self }
+    }
+
+    /// Sets the chain whose entry functions are called.
+    pub fn with_chain(self, chain: Chain) -> Self {
+        IDLPackageGenerator {
+            ctx: self.ctx.with_chain(chain),
+            ..self
+        }
+    }
+
+    /// Sets the order of generated modules and declarations.
+    pub fn with_order(self, order: DeclarationOrder) -> Self {
+        let mut modules_to_generate = self.modules_to_generate;
+        sort_modules(self.idl, &mut modules_to_generate, order);
+        IDLPackageGenerator {
+            modules_to_generate,
+            ctx: self.ctx.with_order(order),
+            ..self
         }
     }
 
-    pub fn generate_index(&self) -> Result<CodeText> {
+    /// Name of the output directory of a generated module, which is also used in its export name.
+    ///
+    /// This is the module name, unless several generated modules share the same name, in which
+    /// case the module address is prepended, e.g. `0x1_coin`.
+    pub fn module_output_name(&self, module: &IDLModule) -> String {
+        let name = module.module_id.name().to_string();
+        let collides = self
+            .modules_to_generate
+            .iter()
+            .filter(|m| m.module_id.name().to_string() == name)
+            .count()
+            > 1;
+        if collides {
+            format!("{}_{}", module.module_id.address().to_hex_literal(), name)
+        } else {
+            name
+        }
+    }
+
+    /// Output names of all generated modules. See [Self::module_output_name].
+    pub fn module_output_names(&self) -> Vec<String> {
+        self.modules_to_generate
+            .iter()
+            .map(|m| self.module_output_name(m))
+            .collect()
+    }
+
+    pub fn generate_index(&self) -> Result<Module> {
         let prefix = &self.idl.name.to_snake_case();
-        let index: CodeText = format!(
-            "{}\n{}",
-            generate_module_reexports(
-                prefix,
-                &self
-                    .modules_to_generate
-                    .iter()
-                    .map(|m| m.module_id.name().to_string())
+        let doc = format!(
+            "This module contains generated types and helper functions for the package `{}`.",
+            self.idl.name
+        );
+        self.ctx.generate_file("index", || {
+            Ok(Module::new()
+                .docs(&doc)
+                .section(
+                    "modules",
+                    generate_module_reexports(&self.ctx, prefix, &self.module_output_names()),
+                )
+                .section(
+                    "errmap",
+                    std::iter::once(Export::named(
+                        "errmap",
+                        Some(&errmap_export_name(prefix)),
+                        &self.ctx.import_path("./errmap"),
+                    ))
+                    .chain(self.ctx.prelude().is_bundled().then(|| {
+                        Export::named(
+                            "parseAbort",
+                            Some(&parse_abort_export_name(prefix)),
+                            &self.ctx.import_path("./errmap"),
+                        )
+                    }))
+                    .chain(
+                        self.ctx
+                            .error_classes()
+                            .then(|| {
+                                [
+                                    Export::named(
+                                        "toAbortError",
+                                        Some(&to_abort_error_export_name(prefix)),
+                                        &self.ctx.import_path("./errmap"),
+                                    ),
+                                    Export::named(
+                                        "isPackageError",
+                                        Some(&is_error_export_name(prefix)),
+                                        &self.ctx.import_path("./errmap"),
+                                    ),
+                                ]
+                            })
+                            .into_iter()
+                            .flatten(),
+                    )
                     .collect::<Vec<_>>(),
-            )?,
-            CodeText::new_named_reexport(&format!("errmap as {}_errmap", prefix), "./errmap.js")
-        )
-        .into();
+                )
+                .section(
+                    "client",
+                    if self.client {
+                        Some(self.generate_client()?)
+                    } else {
+                        None
+                    },
+                ))
+        })
+    }
 
-        Ok(index.module_docs(&format!(
-            "This module contains generated types and helper functions for the package `{}`.",
+    /// Name of the client class of the package.
+    pub fn client_name(&self) -> String {
+        sanitize_identifier(&format!("{}Client", self.idl.name.to_pascal_case()))
+    }
+
+    /// Generates the client class of the package.
+    ///
+    /// The client has a property for each generated module, whose `entry` methods submit calls
+    /// of its entry functions with a `p.MoveSigner`, and whose `views` and `resources` methods
+    /// call its view functions and read its resources with a `p.MoveProvider`.
+    pub fn generate_client(&self) -> Result<Class> {
+        let ctx = &self.ctx;
+        let mut scope = IdentScope::new(["provider", "signer"]);
+        let mut properties = vec![];
+        for gen in self.module_generators() {
+            let mut members = vec![];
+            if gen.has_entrypoints() {
+                let methods = gen
+                    .script_fns
+                    .iter()
+                    .map(|f| f.generate_client_method(ctx))
+                    .collect::<Result<Vec<_>>>()?;
+                members.push(ObjectMember::Property(
+                    "entry".to_string(),
+                    Expr::Object(methods),
+                ));
+            }
+            let module_id = gen.module.module_id.short_str_lossless();
+            let mut views = ctx.module_views(&module_id).iter().collect::<Vec<_>>();
+            ctx.order().sort_by_name(&mut views, |v| v.name.as_str());
+            let mut view_scope = IdentScope::default();
+            let views = views
+                .into_iter()
+                .map(|view| {
+                    let name = view_scope.declare(&ctx.naming().function_name(&view.name));
+                    generate_view_method(&module_id, view, &name, ctx)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if !views.is_empty() {
+                members.push(ObjectMember::Property(
+                    "views".to_string(),
+                    Expr::Object(views),
+                ));
+            }
+            let fetchers = gen
+                .structs
+                .iter()
+                .filter(|s| is_resource(s))
+                .map(|s| generate_resource_fetcher(s, ctx))
+                .collect::<Result<Vec<_>>>()?;
+            if !fetchers.is_empty() {
+                members.push(ObjectMember::Property(
+                    "resources".to_string(),
+                    Expr::Object(fetchers),
+                ));
+            }
+            if members.is_empty() {
+                continue;
+            }
+            let name = scope.declare(&ctx.naming().field_name(&gen.output_name));
+            properties.push(
+                ClassProperty::new(&name, Expr::Object(members)).docs(&format!(
+                    "Entry functions, view functions and resources of `{}`.",
+                    gen.module.module_id
+                )),
+            );
+        }
+        Ok(Class::new(
+            &self.client_name(),
+            vec![
+                Param::new("provider", ctx.prelude_type("MoveProvider")),
+                Param::new("signer", ctx.prelude_type("MoveSigner")),
+            ],
+            properties,
+        )
+        .docs(&format!(
+            "Client of the package `{}`.\n\nTransactions are submitted by the `signer` and resources are read by the `provider`.",
             self.idl.name
         )))
     }
 
-    pub fn generate_errmap_module(&self) -> Result<CodeText> {
-        Ok(CodeText::new_const_export("errmap", &self.idl.errors)?
-            .docs("All errors in this package.")
-            .module_docs("Module containing all errors in this package."))
+    /// Errors of all generated modules, keyed by module ID.
+    fn errmap(&self) -> BTreeMap<String, BTreeMap<u64, IDLError>> {
+        self.modules_to_generate
+            .iter()
+            .filter(|m| !m.errors.is_empty())
+            .map(|m| {
+                (
+                    m.module_id.short_str_lossless(),
+                    m.errors
+                        .iter()
+                        .map(|(code, error)| (*code, error.clone()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Generates `parseAbort`, which decodes the abort of a VM status into the error of this
+    /// package which caused it. It needs the bundled prelude.
+    fn generate_parse_abort(&self) -> Const {
+        let ctx = &self.ctx;
+        Const::new(
+            "parseAbort",
+            Expr::arrow(
+                vec![Param::new("vmStatus", Type::named("string"))],
+                Some(Type::Union(vec![
+                    ctx.prelude_type("DecodedAbort"),
+                    Type::named("null"),
+                ])),
+                ctx.prelude_value("decodeAbort")
+                    .call(vec![Expr::path("errmap"), Expr::path("vmStatus")]),
+            ),
+        )
+        .docs(
+            "Decodes the abort of a VM status into the error of this package which caused it.\n\n\
+            Returns `null` if the transaction did not abort in a module of this package or with an \
+            unknown code.",
+        )
+    }
+
+    /// Generates the error classes of the package, keyed by module ID and error code, with
+    /// `toAbortError` and the `isPackageError` type guard.
+    fn generate_error_classes(&self) -> Result<Vec<Stmt>> {
+        let ctx = &self.ctx;
+        let mut modules = vec![];
+        let mut types = vec![];
+        for gen in self.module_generators() {
+            let module_id = gen.module.module_id.short_str_lossless();
+            let mut classes = vec![];
+            for error in gen.error_declarations(ctx) {
+                let class_name = error.class_name.context("missing error class name")?;
+                let path = format!("errors.{}", class_name);
+                classes.push(ObjectMember::Property(
+                    error.code.to_string(),
+                    ctx.module_value(&module_id, &path)
+                        .ok_or_else(|| anyhow!("module {} is not generated", module_id))?,
+                ));
+                types.push(
+                    ctx.module_type(&module_id, &path, vec![])
+                        .ok_or_else(|| anyhow!("module {} is not generated", module_id))?,
+                );
+            }
+            if !classes.is_empty() {
+                modules.push(ObjectMember::Property(module_id, Expr::Object(classes)));
+            }
+        }
+        let error_type = if types.is_empty() {
+            Type::named("never")
+        } else {
+            Type::Union(types)
+        };
+        Ok(vec![
+            Const::new("errorClasses", Expr::Object(modules))
+                .docs("Error classes of this package, keyed by module ID and error code.")
+                .into(),
+            Const::new(
+                "toAbortError",
+                Expr::arrow(
+                    vec![Param::new("vmStatus", Type::named("string"))],
+                    Some(Type::Union(vec![
+                        ctx.prelude_type("MoveAbortError"),
+                        Type::named("null"),
+                    ])),
+                    ctx.prelude_value("abortError")
+                        .call(vec![Expr::path("errorClasses"), Expr::path("vmStatus")]),
+                ),
+            )
+            .docs(
+                "Decodes the abort of a VM status into an instance of the error class of this \
+                package which caused it, which can be thrown.\n\n\
+                Returns `null` if the transaction did not abort in a module of this package or with \
+                an unknown code.",
+            )
+            .into(),
+            Const::new(
+                "isPackageError",
+                Expr::arrow(
+                    vec![Param::new("error", Type::named("unknown"))],
+                    Some(Type::Predicate(
+                        "error".to_string(),
+                        Box::new(error_type),
+                    )),
+                    ctx.prelude_value("isErrorOf")
+                        .call(vec![Expr::path("errorClasses"), Expr::path("error")]),
+                ),
+            )
+            .docs("Whether `error` is an instance of an error class of this package.")
+            .into(),
+        ])
+    }
+
+    pub fn generate_errmap_module(&self) -> Result<Module> {
+        self.ctx.generate_file("errmap", || {
+            let module = Module::new()
+                .docs("Module containing all errors in this package.")
+                .item(
+                    Const::json("errmap", &self.errmap())?
+                        .docs("All errors in this package, keyed by module ID and error code."),
+                );
+            let module = if self.ctx.prelude().is_bundled() {
+                module.item(self.generate_parse_abort())
+            } else {
+                module
+            };
+            Ok(if self.ctx.error_classes() {
+                module.items(self.generate_error_classes()?)
+            } else {
+                module
+            })
+        })
+    }
+
+    /// Name of the npm package generated by [Self::generate_package_json].
+    pub fn npm_package_name(&self) -> String {
+        self.idl.name.to_kebab_case()
+    }
+
+    /// Generates a `package.json` which allows publishing the output directory as an npm package.
+    ///
+    /// TypeScript output is compiled into `dist/` by the `tsconfig.json` from
+    /// [Self::generate_tsconfig], while JavaScript output is published as is.
+    pub fn generate_package_json(&self, version: &str, format: OutputFormat) -> Result<CodeText> {
+        let mut exports = serde_json::Map::new();
+        exports.insert(".".to_string(), package_export_entry("index", format));
+        for name in self.module_output_names() {
+            exports.insert(
+                format!("./{}", name),
+                package_export_entry(&format!("{}/index", name), format),
+            );
+        }
+
+        let mut package = json!({
+            "name": self.npm_package_name(),
+            "version": version,
+            "description": format!("Generated TypeScript bindings for the Move package `{}`.", self.idl.name),
+            "type": "module",
+            "exports": exports,
+        });
+        match format {
+            OutputFormat::TypeScript => {
+                package["main"] = json!("./dist/index.js");
+                package["types"] = json!("./dist/index.d.ts");
+                package["files"] = json!(["dist/", "**/*.ts"]);
+                package["scripts"] = json!({
+                    "build": "tsc",
+                    "prepublishOnly": "tsc",
+                });
+                package["devDependencies"] = json!({
+                    "typescript": "^4.7.4",
+                });
+            }
+            OutputFormat::JavaScript { commonjs } => {
+                package["main"] = json!(if commonjs {
+                    "./index.cjs"
+                } else {
+                    "./index.js"
+                });
+                package["types"] = json!("./index.d.ts");
+                package["files"] = json!(["**/*.js", "**/*.cjs", "**/*.d.ts", "**/*.d.cts"]);
+            }
+        }
+        if let PreludeSource::Package(prelude) = &self.ctx.prelude().source {
+            package["peerDependencies"] = json!({ prelude.as_str(): "*" });
+        }
+
+        Ok(format!("{}\n", serde_json::to_string_pretty(&package)?).into())
+    }
+
+    /// Generates a `tsconfig.json` which compiles the output directory into `dist/`.
+    pub fn generate_tsconfig(&self) -> Result<CodeText> {
+        let tsconfig = json!({
+            "compilerOptions": {
+                "target": "ES2020",
+                "module": "ES2020",
+                "moduleResolution": "node",
+                "declaration": true,
+                "strict": true,
+                "skipLibCheck": true,
+                "rootDir": ".",
+                "outDir": "dist",
+            },
+            "include": ["**/*.ts"],
+            "exclude": ["dist", "node_modules"],
+        });
+        Ok(format!("{}\n", serde_json::to_string_pretty(&tsconfig)?).into())
+    }
+
+    /// Summary of a module, which is the first line of its documentation.
+    fn module_summary(module: &IDLModule) -> Option<&str> {
+        module
+            .doc
+            .as_ref()
+            .and_then(|doc| doc.lines().map(|l| l.trim()).find(|l| !l.is_empty()))
+    }
+
+    /// IDL data of the package passed to every template.
+    fn package_template_data(&self) -> Value {
+        let modules = self
+            .modules_to_generate
+            .iter()
+            .map(|module| {
+                json!({
+                    "id": module.module_id.short_str_lossless(),
+                    "name": module.module_id.name().to_string(),
+                    "outputName": self.module_output_name(module),
+                    "summary": Self::module_summary(module),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "package": {
+                "name": self.idl.name,
+                "npmName": self.npm_package_name(),
+                "modules": modules,
+            },
+        })
+    }
+
+    /// IDL data of a module passed to the templates of its files.
+    fn module_template_data(&self, module: &IDLModule) -> Result<Value> {
+        let mut data = self.package_template_data();
+        data["module"] = json!({
+            "id": module.module_id.short_str_lossless(),
+            "address": module.module_id.address().to_hex_literal(),
+            "name": module.module_id.name().to_string(),
+            "outputName": self.module_output_name(module),
+            "summary": Self::module_summary(module),
+            "idl": serde_json::to_value(module)?,
+        });
+        Ok(data)
+    }
+
+    /// Generates a `README.md` listing all generated modules, using the `readme` template.
+    pub fn generate_readme(&self) -> Result<CodeText> {
+        Ok(self
+            .ctx
+            .templates()
+            .render("readme", &self.package_template_data())?
+            .into())
     }
 
     pub fn module_generators(&'info self) -> Vec<IDLModuleGenerator<'info>> {
         self.modules_to_generate
             .iter()
-            .map(|m| self.ctx.get_module_generator(m))
+            .map(|m| {
+                self.ctx
+                    .get_module_generator(m)
+                    .with_output_name(self.module_output_name(m))
+            })
             .collect::<Vec<_>>()
     }
+
+    /// Generates the files which allow publishing the output directory as an npm package,
+    /// keyed by path relative to the output directory.
+    pub fn generate_package_files(
+        &self,
+        version: &str,
+        format: OutputFormat,
+    ) -> Result<BTreeMap<PathBuf, CodeText>> {
+        let mut files = BTreeMap::new();
+        files.insert(
+            PathBuf::from("package.json"),
+            self.generate_package_json(version, format)?,
+        );
+        if format == OutputFormat::TypeScript {
+            files.insert(PathBuf::from("tsconfig.json"), self.generate_tsconfig()?);
+        }
+        files.insert(PathBuf::from("README.md"), self.generate_readme()?);
+        Ok(files)
+    }
+
+    /// Generates all files of the package for the target of [Self::ctx], keyed by path
+    /// relative to the output directory.
+    ///
+    /// The bundled prelude is included if the prelude is bundled, while the package files of
+    /// [Self::generate_package_json], [Self::generate_tsconfig] and [Self::generate_readme]
+    /// are not.
+    ///
+    /// Fails if error classes or the client are requested without the bundled prelude.
+    pub fn generate_all(&self) -> Result<BTreeMap<PathBuf, CodeText>> {
+        let ctx = &self.ctx;
+        ensure!(
+            !ctx.error_classes() || ctx.prelude().is_bundled(),
+            "error classes need the bundled prelude"
+        );
+        ensure!(
+            !self.client || ctx.prelude().is_bundled(),
+            "the client needs the bundled prelude"
+        );
+        let ext = ctx.target().extension();
+        let mut files = BTreeMap::new();
+        for module in self.modules_to_generate.iter() {
+            let gen = IDLModuleGenerator::new(module, ctx);
+            let data = self.module_template_data(module)?;
+            let mut render = |name: &str, file: Module| -> Result<()> {
+                let path = format!("{}/{}", gen.output_name, name);
+                let code = ctx.render(&format!("module/{}", name), &path, &file, data.clone())?;
+                files.insert(PathBuf::from(format!("{}.{}", path, ext)), code);
+                Ok(())
+            };
+
+            if gen.has_entrypoints() {
+                render("entry", gen.generate_entrypoint_module(ctx)?)?;
+                render("entryNames", gen.generate_entry_names_module(ctx)?)?;
+                render("payloads", gen.generate_entry_payloads_module(ctx)?)?;
+            }
+            if gen.has_bcs(ctx) {
+                render("bcs", gen.generate_bcs_module(ctx)?)?;
+            }
+            render("idl", gen.generate_idl_module(ctx)?)?;
+            if let Some(errors_module) = gen.generate_errors_module(ctx)? {
+                render("errors", errors_module)?;
+            }
+            if let Some(constants_module) = gen.generate_constants_module(ctx)? {
+                render("constants", constants_module)?;
+            }
+            if let Some(decoders_module) = gen.generate_decoders_module(ctx)? {
+                render("decoders", decoders_module)?;
+            }
+            for (name, extension) in gen.generate_extension_modules(ctx)? {
+                render(&name, extension)?;
+            }
+            render("index", ctx.generate(module)?)?;
+        }
+
+        if ctx.prelude().is_bundled() {
+            files.insert(
+                PathBuf::from(format!("{}.{}", BUNDLED_PRELUDE_NAME, ext)),
+                bundled_prelude(ctx.target()).into(),
+            );
+        }
+        let data = self.package_template_data();
+        files.insert(
+            PathBuf::from(format!("errmap.{}", ext)),
+            ctx.render(
+                "errmap",
+                "errmap",
+                &self.generate_errmap_module()?,
+                data.clone(),
+            )?,
+        );
+        files.insert(
+            PathBuf::from(format!("index.{}", ext)),
+            ctx.render("index", "index", &self.generate_index()?, data)?,
+        );
+        Ok(files)
+    }
 }
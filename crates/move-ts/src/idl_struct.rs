@@ -1,81 +1,142 @@
 use super::{Codegen, CodegenContext};
-use crate::{format::gen_doc_string, idl_type::generate_idl_type_with_type_args, CodeText};
+use crate::{
+    ast::{Expr, Interface, ObjectMember, Param, Property, Stmt, Type, TypeParam},
+    idl_type::{generate_idl_type_with_type_args, has_fields},
+};
 use anyhow::*;
 use itertools::Itertools;
-use move_idl::{IDLStruct, IDLType};
+use move_idl::{IDLAbility, IDLStruct};
 
-fn generate_struct_fields(s: &IDLStruct, ctx: &CodegenContext) -> Result<CodeText> {
-    Ok(s.fields
+fn generate_struct_fields(s: &IDLStruct, ctx: &CodegenContext) -> Result<Vec<Property>> {
+    // Type parameters are referenced by index, so phantom parameters keep their position.
+    let type_args = s
+        .type_params
         .iter()
-        .map(|field| {
-            let ts = &generate_idl_type_with_type_args(
-                &field.ty,
-                ctx,
-                &s.type_params
-                    .iter()
-                    .filter(|t| !t.is_phantom)
-                    .map(|t| format!("_{}", t.name))
-                    .collect::<Vec<_>>(),
-                true,
-            )?;
-            Ok(format!(
-                "{}{}: {};",
-                field
-                    .doc
-                    .as_ref()
-                    .map(|doc| format!("\n{}", gen_doc_string(doc)))
-                    .unwrap_or_default(),
-                field.name,
-                ts
-            ))
+        .map(|t| {
+            if t.is_phantom {
+                Type::named("unknown")
+            } else {
+                Type::named(&format!("_{}", t.name))
+            }
+        })
+        .collect::<Vec<_>>();
+    let names = ctx
+        .naming()
+        .field_names(s.fields.iter().map(|f| f.name.as_str()));
+    s.fields
+        .iter()
+        .zip(names)
+        .map(|(field, name)| {
+            let ts = generate_idl_type_with_type_args(&field.ty, ctx, &type_args, true)?;
+            Ok(Property::new(&name, ts).docs_opt(&field.doc))
         })
-        .collect::<Result<Vec<_>>>()?
-        .join("\n")
-        .trim()
-        .to_string()
-        .into())
+        .collect()
 }
 
 impl Codegen for IDLStruct {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
-        if self.fields.len() == 1
-            && self.fields[0].name == "dummy_field"
-            && self.fields[0].ty == IDLType::Bool
-        {
-            return Ok("".to_string());
+    /// The interface of the struct, or `None` for structs without fields.
+    type Node = Option<Stmt>;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Option<Stmt>> {
+        if !has_fields(self) {
+            return Ok(None);
         }
 
-        let generics = if !self.type_params.iter().any(|p| !p.is_phantom) {
-            "".to_string()
-        } else {
-            format!(
-                "<{}>",
-                self.type_params
-                    .iter()
-                    .filter(|p| !p.is_phantom)
-                    .map(|p| format!("_{} = unknown", p.name))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            )
-        };
+        let type_params = self
+            .type_params
+            .iter()
+            .filter(|p| !p.is_phantom)
+            .map(|p| TypeParam {
+                name: format!("_{}", p.name),
+                default: Some(Type::named("unknown")),
+            })
+            .collect::<Vec<_>>();
 
-        Ok(CodeText::new(&format!(
-            r#"export interface I{}{} {{
-{}
-}};"#,
-            self.name.name,
-            generics,
-            generate_struct_fields(self, ctx)?.indent()
+        Ok(Some(
+            Interface::new(
+                &ctx.naming().struct_type_name(&self.name.name.to_string()),
+                generate_struct_fields(self, ctx)?,
+            )
+            .type_params(type_params)
+            .docs(
+                &[
+                    self.doc.clone().unwrap_or_default(),
+                    format!("Type name: `{}`", self.name),
+                ]
+                .iter()
+                .filter(|s| !s.is_empty())
+                .join("\n\n"),
+            )
+            .into(),
         ))
-        .docs(
-            &[
-                self.doc.clone().unwrap_or_default(),
-                format!("Type name: `{}`", self.name),
-            ]
-            .iter()
-            .filter(|s| !s.is_empty())
-            .join("\n\n"),
-        )
-        .into())
     }
 }
+
+/// Returns true if the struct can be stored as a resource.
+pub(crate) fn is_resource(s: &IDLStruct) -> bool {
+    s.abilities.contains(&IDLAbility::Key)
+}
+
+/// Generates the method of the package client reading a resource of this struct, keyed by the
+/// name of the struct.
+pub(crate) fn generate_resource_fetcher(
+    s: &IDLStruct,
+    ctx: &CodegenContext,
+) -> Result<ObjectMember> {
+    let module_id = s.name.module_id().short_str_lossless();
+    let resource_type = if has_fields(s) {
+        ctx.module_type(
+            &module_id,
+            &ctx.naming().struct_type_name(s.name.name.as_str()),
+            vec![],
+        )
+        .ok_or_else(|| anyhow!("module {} is not generated", module_id))?
+    } else {
+        Type::Object(vec![])
+    };
+
+    let mut params = vec![Param::new("owner", ctx.prelude_type("RawAddress"))];
+    let tag = if s.type_params.is_empty() {
+        Expr::string(&s.name.to_string())
+    } else {
+        let names = ctx
+            .naming()
+            .field_names(s.type_params.iter().map(|p| p.name.as_str()));
+        params.push(Param::new(
+            "typeArgs",
+            Type::Object(
+                names
+                    .iter()
+                    .map(|name| Property::new(name, Type::named("string")))
+                    .collect(),
+            ),
+        ));
+        ctx.prelude_value("structTag").call(vec![
+            Expr::string(&s.name.to_string()),
+            Expr::Array(
+                names
+                    .iter()
+                    .map(|name| Expr::path("typeArgs").member(name))
+                    .collect(),
+            ),
+        ])
+    };
+    let return_type = Type::generic(
+        "Promise",
+        vec![Type::Union(vec![resource_type, Type::named("null")])],
+    );
+    let address = ctx
+        .prelude_value("serializers.hexString")
+        .call(vec![Expr::path("owner")]);
+    Ok(ObjectMember::Property(
+        s.name.name.to_string(),
+        Expr::arrow(
+            params,
+            Some(return_type.clone()),
+            Expr::As(
+                Box::new(Expr::path("this.provider.getResource").call(vec![address, tag])),
+                return_type,
+            ),
+        ),
+    ))
+}
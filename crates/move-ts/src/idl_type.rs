@@ -1,132 +1,392 @@
 use super::{Codegen, CodegenContext};
-use crate::{format::gen_doc_string, CodeText};
+use crate::{
+    ast::{Expr, ObjectMember, Param, Property, Type},
+    chain::Chain,
+    idl_enum::{generate_enum_ref, generate_enum_with_type_args},
+};
 use anyhow::*;
-use move_idl::{IDLField, IDLStructType, IDLType};
+use move_idl::{IDLField, IDLStruct, IDLStructType, IDLType};
+use serde_json::Value;
 
-pub fn serialize_arg(arg: &str, ty: &IDLType, ctx: &CodegenContext) -> Result<String> {
-    let ts_type = &ctx.generate(ty)?.to_string();
-    if ts_type.starts_with("ReadonlyArray") {
-        if let IDLType::Vector(inner) = ty {
+/// Name of the constant holding [Chain::address_length] in generated BCS encoders.
+pub const ADDRESS_LENGTH_NAME: &str = "ADDRESS_LENGTH";
+
+/// Names of the Move string types, which are generated as `string`.
+const STRING_TYPES: &[&str] = &["0x1::ASCII::String", "0x1::string::String"];
+
+/// Name of the Move `Option` type, which is passed as a vector of zero or one elements.
+const OPTION_TYPE: &str = "0x1::option::Option";
+
+/// Name of the Aptos `Object<T>`, which is passed as the address of the object.
+const APTOS_OBJECT_TYPE: &str = "0x1::object::Object";
+
+/// Name of the `ID` of Sui objects, which is passed as an address.
+const SUI_ID_TYPE: &str = "0x2::object::ID";
+
+/// Name of the `UID` of Sui objects. Structs whose first field is an `id: UID` are objects,
+/// which are passed by reference to their ID.
+const SUI_UID_TYPE: &str = "0x2::object::UID";
+
+/// Name of the Sui `TxContext`, which is passed to entry functions by the runtime.
+const SUI_TX_CONTEXT_TYPE: &str = "0x2::tx_context::TxContext";
+
+/// Returns true if the type is a Move string, which is generated as `string`.
+pub(crate) fn is_string_type(ty: &IDLStructType) -> bool {
+    STRING_TYPES.contains(&ty.name.to_string().as_str())
+}
+
+/// How an entry function argument of a struct type is passed.
+enum StructArg<'a> {
+    /// A UTF-8 string.
+    String,
+    /// The address of an object.
+    Object,
+    /// A Sui object, passed by reference to its ID rather than by value.
+    SuiObject,
+    /// An optional value of the type.
+    Option(&'a IDLType),
+}
+
+impl<'a> StructArg<'a> {
+    /// Classifies an argument of a struct type, failing if it cannot be passed to an entry
+    /// function.
+    fn classify(ty: &'a IDLStructType, ctx: &CodegenContext) -> Result<Self> {
+        let name = ty.name.to_string();
+        if STRING_TYPES.contains(&name.as_str()) {
+            return Ok(StructArg::String);
+        }
+        let object_type = match ctx.chain() {
+            Chain::Aptos => APTOS_OBJECT_TYPE,
+            Chain::Sui => SUI_ID_TYPE,
+        };
+        if name == object_type {
+            return Ok(StructArg::Object);
+        }
+        if name == OPTION_TYPE {
+            return match ty.ty_args.as_slice() {
+                [inner] => Ok(StructArg::Option(inner)),
+                _ => bail!("{} must have exactly one type argument", name),
+            };
+        }
+        let is_object = ctx.chain() == Chain::Sui
+            && ctx
+                .pkg
+                .structs
+                .iter()
+                .find(|sd| sd.name == ty.name)
+                .and_then(|sd| sd.fields.first())
+                .map_or(false, |field| match &field.ty {
+                    IDLType::Struct(id) => {
+                        field.name == "id" && id.name.to_string() == SUI_UID_TYPE
+                    }
+                    _ => false,
+                });
+        if is_object {
+            return Ok(StructArg::SuiObject);
+        }
+        bail!(
+            "unsupported struct argument type {}: only strings, options and objects can be passed to entry functions",
+            name
+        )
+    }
+}
+
+/// Returns true if an argument of this type is passed to entry functions by the runtime
+/// rather than by the caller: signers, which are the senders of the transaction, and the Sui
+/// `TxContext`.
+pub(crate) fn is_implicit_arg(ty: &IDLType, chain: Chain) -> bool {
+    match ty {
+        IDLType::Signer => true,
+        IDLType::Struct(inner) => {
+            chain == Chain::Sui && inner.name.to_string() == SUI_TX_CONTEXT_TYPE
+        }
+        _ => false,
+    }
+}
+
+/// Generates the type of an entry function argument of type `ty`.
+///
+/// If `parse_args` is false, generates the type of the argument serialized into a JSON payload.
+pub(crate) fn generate_arg_type(
+    ty: &IDLType,
+    ctx: &CodegenContext,
+    parse_args: bool,
+) -> Result<Type> {
+    match ty {
+        IDLType::Vector(inner) if **inner != IDLType::U8 => Ok(Type::generic(
+            "ReadonlyArray",
+            vec![generate_arg_type(inner, ctx, parse_args)?],
+        )),
+        IDLType::Struct(inner) => Ok(match StructArg::classify(inner, ctx)? {
+            StructArg::String => Type::named("string"),
+            StructArg::Object | StructArg::SuiObject if parse_args => {
+                ctx.prelude_type("RawAddress")
+            }
+            StructArg::Object | StructArg::SuiObject => Type::named("string"),
+            StructArg::Option(inner) if parse_args => Type::Union(vec![
+                generate_arg_type(inner, ctx, parse_args)?,
+                Type::named("null"),
+                Type::named("undefined"),
+            ]),
+            StructArg::Option(inner) => Type::generic(
+                "ReadonlyArray",
+                vec![generate_arg_type(inner, ctx, parse_args)?],
+            ),
+        }),
+        IDLType::Tuple(_) => bail!("tuples cannot be passed to entry functions"),
+        _ => generate_idl_type_with_type_args(ty, ctx, &[], parse_args),
+    }
+}
+
+/// Generates an expression serializing `arg` of type `ty` into its JSON payload representation.
+///
+/// Integers wider than `u32` are serialized as strings, addresses, objects and byte vectors as
+/// hex strings, and options as vectors, recursing into the elements of vectors and options.
+pub fn serialize_arg(arg: &str, ty: &IDLType, ctx: &CodegenContext) -> Result<Expr> {
+    let serializer = match ty {
+        IDLType::Bool | IDLType::U8 | IDLType::TypeParam(_) => return Ok(Expr::path(arg)),
+        IDLType::U64 => "serializers.u64",
+        IDLType::U128 => "serializers.u128",
+        IDLType::Address | IDLType::Signer => "serializers.hexString",
+        IDLType::Vector(inner) if **inner == IDLType::U8 => "serializers.hexString",
+        IDLType::Vector(inner) => {
             let inner_arg = format!("inner_{}", arg.replace('.', "__"));
             let inner_serialized = serialize_arg(&inner_arg, inner, ctx)?;
-            Ok(format!(
-                "{}.map(({}) => {})",
-                arg, inner_arg, inner_serialized
-            ))
-        } else {
-            anyhow::bail!("Expected vector type for {}", arg)
+            // Elements which are passed as is don't need to be mapped.
+            if inner_serialized == Expr::path(&inner_arg) {
+                return Ok(Expr::path(arg));
+            }
+            return Ok(Expr::path(arg).member("map").call(vec![Expr::arrow(
+                vec![Param::untyped(&inner_arg)],
+                None,
+                inner_serialized,
+            )]));
         }
+        IDLType::Struct(inner) => match StructArg::classify(inner, ctx)? {
+            StructArg::String => return Ok(Expr::path(arg)),
+            StructArg::Object | StructArg::SuiObject => "serializers.hexString",
+            // Options are vectors of zero or one elements.
+            StructArg::Option(inner) => {
+                return Ok(Expr::conditional(
+                    Expr::path(arg).binary("==", Expr::Literal(Value::Null)),
+                    Expr::Array(vec![]),
+                    Expr::Array(vec![serialize_arg(arg, inner, ctx)?]),
+                ));
+            }
+        },
+        IDLType::Tuple(_) => bail!("cannot serialize tuple argument {}", arg),
+    };
+    Ok(ctx.prelude_value(serializer).call(vec![Expr::path(arg)]))
+}
+
+/// Generates an expression BCS-encoding `arg` of type `ty` with the encoders of the prelude.
+///
+/// Arguments of a type parameter of the function must already be encoded.
+pub fn encode_arg(arg: &str, ty: &IDLType, ctx: &CodegenContext) -> Result<Expr> {
+    let encoder = match ty {
+        IDLType::Bool => "bcs.bool",
+        IDLType::U8 => "bcs.u8",
+        IDLType::U64 => "bcs.u64",
+        IDLType::U128 => "bcs.u128",
+        IDLType::Address | IDLType::Signer => return Ok(encode_address(arg, ctx)),
+        IDLType::Vector(inner) if **inner == IDLType::U8 => "bcs.bytes",
+        IDLType::Vector(inner) => {
+            let inner_arg = format!("inner_{}", arg.replace('.', "__"));
+            let inner_encoded = encode_arg(&inner_arg, inner, ctx)?;
+            return Ok(ctx.prelude_value("bcs.vector").call(vec![
+                Expr::path(arg),
+                Expr::arrow(vec![Param::untyped(&inner_arg)], None, inner_encoded),
+            ]));
+        }
+        IDLType::Struct(inner) => match StructArg::classify(inner, ctx)? {
+            StructArg::String => "bcs.string",
+            StructArg::Object => return Ok(encode_address(arg, ctx)),
+            StructArg::SuiObject => bail!(
+                "cannot BCS-encode Sui object argument {}: objects are passed by reference",
+                arg
+            ),
+            StructArg::Option(inner) => {
+                let inner_arg = format!("inner_{}", arg.replace('.', "__"));
+                let inner_encoded = encode_arg(&inner_arg, inner, ctx)?;
+                return Ok(ctx.prelude_value("bcs.option").call(vec![
+                    Expr::path(arg),
+                    Expr::arrow(vec![Param::untyped(&inner_arg)], None, inner_encoded),
+                ]));
+            }
+        },
+        IDLType::TypeParam(_) => "bcs.encoded",
+        IDLType::Tuple(_) => bail!("cannot BCS-encode tuple argument {}", arg),
+    };
+    Ok(ctx.prelude_value(encoder).call(vec![Expr::path(arg)]))
+}
+
+/// BCS-encodes an address with the length of the build.
+fn encode_address(arg: &str, ctx: &CodegenContext) -> Expr {
+    ctx.prelude_value("bcs.address")
+        .call(vec![Expr::path(arg), Expr::path(ADDRESS_LENGTH_NAME)])
+}
+
+/// Generates the argument of a Sui Move call passing `arg` of type `ty`: the ID of an object,
+/// or a BCS-encoded pure value.
+pub fn encode_move_call_arg(arg: &str, ty: &IDLType, ctx: &CodegenContext) -> Result<Expr> {
+    let is_object = match ty {
+        IDLType::Struct(inner) => matches!(StructArg::classify(inner, ctx)?, StructArg::SuiObject),
+        _ => false,
+    };
+    Ok(if is_object {
+        Expr::Object(vec![ObjectMember::Property(
+            "object".to_string(),
+            ctx.prelude_value("serializers.hexString")
+                .call(vec![Expr::path(arg)]),
+        )])
     } else {
-        let serializer = if ts_type == "p.U64" {
-            "p.serializers.u64"
-        } else if ts_type == "p.U128" {
-            "p.serializers.u128"
-        } else if ts_type == "p.HexStringArg"
-            || ts_type == "p.RawAddress"
-            || ts_type == "p.RawSigner"
-            || ts_type == "p.ByteString"
-        {
-            "p.serializers.hexString"
-        } else {
-            return Ok(arg.to_string());
-        };
-        Ok(format!("{}({})", serializer, &arg))
-    }
+        Expr::Object(vec![ObjectMember::Property(
+            "pure".to_string(),
+            encode_arg(arg, ty, ctx)?,
+        )])
+    })
 }
 
 fn generate_field_with_type_args(
     ty: &IDLField,
+    name: &str,
     ctx: &CodegenContext,
-    type_args: &[String],
+    type_args: &[Type],
     parse_args: bool,
-) -> Result<String> {
-    Ok(format!(
-        "{}{}: {};",
-        ty.doc
-            .as_ref()
-            .map(|doc| format!("\n{}", gen_doc_string(doc)))
-            .unwrap_or_default(),
-        ty.name,
-        generate_idl_type_with_type_args(&ty.ty, ctx, type_args, parse_args)?
-    ))
+) -> Result<Property> {
+    Ok(Property::new(
+        name,
+        generate_idl_type_with_type_args(&ty.ty, ctx, type_args, parse_args)?,
+    )
+    .docs_opt(&ty.doc))
 }
 
 fn generate_struct_with_type_args(
     ty: &IDLStructType,
     ctx: &CodegenContext,
-    type_args: &[String],
+    type_args: &[Type],
     parse_args: bool,
-) -> Result<String> {
-    let struct_def = ctx
-        .pkg
-        .structs
-        .iter()
-        .find(|sd| sd.name == ty.name)
-        .unwrap();
+) -> Result<Type> {
+    // Types missing from the IDL, e.g. enums which were not provided, are not known.
+    let struct_def = match ctx.pkg.structs.iter().find(|sd| sd.name == ty.name) {
+        Some(struct_def) => struct_def,
+        None => return Ok(Type::named("unknown")),
+    };
 
-    let fields_gen: CodeText = struct_def
-        .fields
-        .iter()
-        .map(|v| generate_field_with_type_args(v, ctx, type_args, parse_args))
-        .collect::<Result<Vec<_>>>()?
-        .join("\n")
-        .into();
+    let names = ctx
+        .naming()
+        .field_names(struct_def.fields.iter().map(|f| f.name.as_str()));
+    Ok(Type::Object(
+        struct_def
+            .fields
+            .iter()
+            .zip(names)
+            .map(|(v, name)| generate_field_with_type_args(v, &name, ctx, type_args, parse_args))
+            .collect::<Result<Vec<_>>>()?,
+    ))
+}
 
-    Ok(format!("{{\n{}\n}}", fields_gen.trim().indent()))
+/// Returns true if the struct has fields other than the placeholder of empty structs.
+pub(crate) fn has_fields(s: &IDLStruct) -> bool {
+    !(s.fields.len() == 1 && s.fields[0].name == "dummy_field" && s.fields[0].ty == IDLType::Bool)
+}
+
+/// References the interface of a struct declared in a generated module.
+fn generate_struct_ref(
+    ty: &IDLStructType,
+    ctx: &CodegenContext,
+    type_args: &[Type],
+) -> Option<Type> {
+    let struct_def = ctx.pkg.structs.iter().find(|sd| sd.name == ty.name)?;
+    if !has_fields(struct_def) {
+        return None;
+    }
+    let args = struct_def
+        .type_params
+        .iter()
+        .zip(type_args)
+        .filter(|(param, _)| !param.is_phantom)
+        .map(|(_, arg)| arg.clone())
+        .collect();
+    ctx.module_type(
+        &ty.name.module_id().short_str_lossless(),
+        &ctx.naming().struct_type_name(ty.name.name.as_str()),
+        args,
+    )
 }
 
 pub(crate) fn generate_idl_type_with_type_args(
     idl_type: &IDLType,
     ctx: &CodegenContext,
-    type_args: &[String],
+    type_args: &[Type],
     parse_args: bool,
-) -> Result<String> {
-    let result = match idl_type {
-        IDLType::Bool => "boolean".to_string(),
-        IDLType::U8 => "number".to_string(),
-        IDLType::U64 => "p.U64".to_string(),
-        IDLType::U128 => "p.U128".to_string(),
-        IDLType::Address => "p.RawAddress".to_string(),
-        IDLType::Signer => "p.RawSigner".to_string(),
+) -> Result<Type> {
+    // Arguments are accepted as strings before they are parsed.
+    let prelude_type = |name: &str| {
+        if parse_args {
+            ctx.prelude_type(name)
+        } else {
+            Type::named("string")
+        }
+    };
+    Ok(match idl_type {
+        IDLType::Bool => Type::named("boolean"),
+        IDLType::U8 => Type::named("number"),
+        IDLType::U64 => prelude_type("U64"),
+        IDLType::U128 => prelude_type("U128"),
+        IDLType::Address => prelude_type("RawAddress"),
+        IDLType::Signer => prelude_type("RawSigner"),
         IDLType::Vector(inner) => match *inner.clone() {
-            IDLType::U8 => "p.ByteString".to_string(),
-            inner => format!(
-                "ReadonlyArray<{}>",
-                generate_idl_type_with_type_args(&inner, ctx, type_args, parse_args)?
+            IDLType::U8 => prelude_type("ByteString"),
+            inner => Type::generic(
+                "ReadonlyArray",
+                vec![generate_idl_type_with_type_args(
+                    &inner, ctx, type_args, parse_args,
+                )?],
             ),
         },
         IDLType::Struct(inner) => {
-            if inner.name.to_string() == *"0x1::ASCII::String"
-                || inner.name.to_string() == *"0x1::string::String"
-            {
-                "string".to_string()
+            if is_string_type(inner) {
+                Type::named("string")
             } else {
                 let next_type_args = inner
                     .ty_args
                     .iter()
                     .map(|arg| generate_idl_type_with_type_args(arg, ctx, type_args, parse_args))
                     .collect::<Result<Vec<_>>>()?;
-                generate_struct_with_type_args(inner, ctx, &next_type_args, parse_args)?
+                // Move 2 enums are referenced by struct types in the IDL.
+                let enum_def = ctx.find_enum(&inner.name);
+                let type_ref = match enum_def {
+                    _ if !parse_args => None,
+                    Some(enum_def) => generate_enum_ref(enum_def, ctx, &next_type_args),
+                    None => generate_struct_ref(inner, ctx, &next_type_args),
+                };
+                match (type_ref, enum_def) {
+                    (Some(type_ref), _) => type_ref,
+                    (None, Some(enum_def)) => {
+                        generate_enum_with_type_args(enum_def, ctx, &next_type_args, parse_args)?
+                    }
+                    (None, None) => {
+                        generate_struct_with_type_args(inner, ctx, &next_type_args, parse_args)?
+                    }
+                }
             }
         }
         IDLType::TypeParam(v) => {
             let result = type_args.get(*v as usize);
             match result {
                 Some(v) => v.clone(),
-                None => "unknown".to_string(),
+                None => Type::named("unknown"),
             }
         }
         IDLType::Tuple(_) => todo!(),
-    };
-    if !parse_args && result.starts_with("p.") {
-        Ok("string".to_string())
-    } else {
-        Ok(result)
-    }
+    })
 }
 
 impl Codegen for IDLType {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
+    type Node = Type;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Type> {
         generate_idl_type_with_type_args(self, ctx, &[], true)
     }
 }
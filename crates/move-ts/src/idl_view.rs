@@ -0,0 +1,168 @@
+//! View functions.
+//!
+//! The IDL of `move-idl` only describes the entry functions of a module, so view functions,
+//! i.e. public functions annotated with `#[view]`, are provided separately, either as a `views`
+//! array in each module of an IDL file or as a map of view functions keyed by module ID. They
+//! are called by the package client through its `p.MoveProvider`.
+
+use anyhow::*;
+use move_idl::{IDLArgument, IDLType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::{
+    ast::{Expr, ObjectMember, Param, Property, TupleElement, Type},
+    idl_type::{
+        generate_arg_type, generate_idl_type_with_type_args, is_implicit_arg, serialize_arg,
+    },
+    read_module_entries, CodegenContext,
+};
+
+/// A view function of a module.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IDLViewFunction {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ty_args: Vec<String>,
+    #[serde(default)]
+    pub args: Vec<IDLArgument>,
+    /// Types of the returned values.
+    pub returns: Vec<IDLType>,
+}
+
+/// View functions of the modules of a package, keyed by module ID, e.g. `0x1::coin`.
+pub type IDLViews = BTreeMap<String, Vec<IDLViewFunction>>;
+
+/// Reads the `views` of the modules and dependencies of an IDL file.
+pub fn read_views(idl: &Value) -> Result<IDLViews> {
+    read_module_entries(idl, "views")
+}
+
+/// Generates the type of a value returned by a view function, as encoded in JSON.
+fn generate_return_type(ty: &IDLType, ctx: &CodegenContext) -> Result<Type> {
+    match ty {
+        IDLType::Tuple(_) => bail!("view functions cannot return nested tuples"),
+        IDLType::Vector(inner) if **inner != IDLType::U8 => Ok(Type::generic(
+            "ReadonlyArray",
+            vec![generate_return_type(inner, ctx)?],
+        )),
+        _ => generate_idl_type_with_type_args(ty, ctx, &[], false),
+    }
+}
+
+/// Generates the method of the package client calling a view function of the module
+/// `module_id`, keyed by the name of the function.
+pub(crate) fn generate_view_method(
+    module_id: &str,
+    view: &IDLViewFunction,
+    name: &str,
+    ctx: &CodegenContext,
+) -> Result<ObjectMember> {
+    let context = || format!("view function `{}::{}`", module_id, view.name);
+    let args = view
+        .args
+        .iter()
+        .filter(|arg| !is_implicit_arg(&arg.ty, ctx.chain()))
+        .collect::<Vec<_>>();
+    let arg_names = ctx
+        .naming()
+        .field_names(args.iter().map(|a| a.name.as_str()));
+    let ty_arg_names = ctx
+        .naming()
+        .field_names(view.ty_args.iter().map(|a| a.as_str()));
+
+    let mut patterns = vec![];
+    let mut properties = vec![];
+    if !args.is_empty() {
+        patterns.push("args");
+        properties.push(Property::new(
+            "args",
+            Type::Object(
+                args.iter()
+                    .zip(&arg_names)
+                    .map(|(arg, name)| {
+                        Ok(Property::new(name, generate_arg_type(&arg.ty, ctx, true)?))
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(context)?,
+            ),
+        ));
+    }
+    if !view.ty_args.is_empty() {
+        patterns.push("typeArgs");
+        properties.push(Property::new(
+            "typeArgs",
+            Type::Object(
+                ty_arg_names
+                    .iter()
+                    .map(|name| Property::new(name, Type::named("string")))
+                    .collect(),
+            ),
+        ));
+    }
+    let params = if patterns.is_empty() {
+        vec![]
+    } else {
+        vec![Param::new(
+            &format!("{{ {} }}", patterns.join(", ")),
+            Type::Object(properties),
+        )]
+    };
+
+    let payload = Expr::Object(vec![
+        ObjectMember::Property(
+            "function".to_string(),
+            Expr::string(&format!("{}::{}", module_id, view.name)),
+        ),
+        ObjectMember::Property(
+            "type_arguments".to_string(),
+            Expr::Array(
+                ty_arg_names
+                    .iter()
+                    .map(|name| Expr::path("typeArgs").member(name))
+                    .collect(),
+            ),
+        ),
+        ObjectMember::Property(
+            "arguments".to_string(),
+            Expr::Array(
+                args.iter()
+                    .zip(&arg_names)
+                    .map(|(arg, name)| serialize_arg(&format!("args.{}", name), &arg.ty, ctx))
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(context)?,
+            ),
+        ),
+    ]);
+    let return_type = Type::generic(
+        "Promise",
+        vec![Type::Tuple {
+            readonly: true,
+            elements: view
+                .returns
+                .iter()
+                .map(|ty| {
+                    Ok(TupleElement {
+                        label: None,
+                        ty: generate_return_type(ty, ctx)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+                .with_context(context)?,
+        }],
+    );
+    Ok(ObjectMember::Property(
+        name.to_string(),
+        Expr::arrow(
+            params,
+            Some(return_type.clone()),
+            Expr::As(
+                Box::new(Expr::path("this.provider.view").call(vec![payload])),
+                return_type,
+            ),
+        ),
+    ))
+}
@@ -0,0 +1,204 @@
+//! Collection of the imports of a generated file.
+//!
+//! Generators reference other modules through [crate::CodegenContext], which records each
+//! reference in the [ImportCollector] of the file being generated. Once the file is complete,
+//! the collector emits a single import per referenced module, which is type-only unless the
+//! module is used at runtime.
+
+use crate::{
+    ast::{Expr, Import, Module, Type},
+    ident::sanitize_identifier,
+    CodegenContext,
+};
+use anyhow::*;
+use std::collections::BTreeMap;
+
+/// A module imported by generated code.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportSource {
+    /// A module specifier which is used as is, e.g. an npm package.
+    Package(String),
+    /// A generated file, relative to the output root and without extension, e.g. `coin/index`.
+    Local(String),
+}
+
+/// Imports referenced by the file being generated.
+#[derive(Clone, Debug, Default)]
+pub struct ImportCollector {
+    /// Path of the file being generated, relative to the output root and without extension.
+    file: String,
+    /// Namespace and runtime usage of each imported module.
+    namespaces: BTreeMap<ImportSource, (String, bool)>,
+}
+
+impl ImportCollector {
+    pub fn new(file: &str) -> Self {
+        ImportCollector {
+            file: file.to_string(),
+            namespaces: BTreeMap::new(),
+        }
+    }
+
+    /// Path of the file being generated, relative to the output root and without extension.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Directory of the file being generated, relative to the output root.
+    pub fn dir(&self) -> &str {
+        self.file.rsplit_once('/').map_or("", |(dir, _)| dir)
+    }
+
+    /// Records a reference to a module imported as `namespace`.
+    ///
+    /// The import is type-only unless any reference is used at `runtime`.
+    pub fn use_namespace(&mut self, namespace: &str, source: ImportSource, runtime: bool) {
+        let entry = self
+            .namespaces
+            .entry(source)
+            .or_insert_with(|| (namespace.to_string(), false));
+        entry.1 |= runtime;
+    }
+
+    /// Resolves a generated file to a relative module specifier without extension.
+    pub fn relative_path(&self, path: &str) -> String {
+        let from = self
+            .dir()
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        let to = path.split('/').collect::<Vec<_>>();
+        let common = from
+            .iter()
+            .zip(&to[..to.len() - 1])
+            .take_while(|(a, b)| a == b)
+            .count();
+        let rest = to[common..].join("/");
+        match from.len() - common {
+            0 => format!("./{}", rest),
+            up => format!("{}{}", "../".repeat(up), rest),
+        }
+    }
+
+    /// Generates the import block of the file.
+    ///
+    /// Packages are imported before generated files, and both are sorted by specifier.
+    /// `extension` is the extension of relative import specifiers.
+    pub fn into_imports(self, extension: &str) -> Vec<Import> {
+        let mut imports = self
+            .namespaces
+            .iter()
+            .map(|(source, (namespace, runtime))| {
+                let path = match source {
+                    ImportSource::Package(path) => path.clone(),
+                    ImportSource::Local(path) => {
+                        format!("{}.{}", self.relative_path(path), extension)
+                    }
+                };
+                let import = Import::namespace(namespace, &path);
+                let import = if *runtime { import } else { import.type_only() };
+                (matches!(source, ImportSource::Local(_)), path, import)
+            })
+            .collect::<Vec<_>>();
+        imports.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+        imports.into_iter().map(|(_, _, import)| import).collect()
+    }
+}
+
+impl<'info> CodegenContext<'info> {
+    /// Generates a file, prepending the imports of the modules it references.
+    ///
+    /// `file` is the path of the file relative to the output root and without extension,
+    /// e.g. `coin/index`.
+    pub fn generate_file<F>(&self, file: &str, generate: F) -> Result<Module>
+    where
+        F: FnOnce() -> Result<Module>,
+    {
+        let previous = self.imports.replace(Some(ImportCollector::new(file)));
+        let result = generate();
+        let collector = self.imports.replace(previous).unwrap_or_default();
+        let imports = collector.into_imports(self.target.import_extension());
+        Ok(result?.prepend(imports))
+    }
+
+    /// References a module imported as `namespace` from the current file.
+    pub fn use_namespace(&self, namespace: &str, source: ImportSource, runtime: bool) {
+        if let Some(imports) = self.imports.borrow_mut().as_mut() {
+            imports.use_namespace(namespace, source, runtime);
+        }
+    }
+
+    /// References a file in the directory of the current file, e.g. `index`.
+    pub fn use_sibling(&self, namespace: &str, file: &str, runtime: bool) {
+        let dir = self
+            .imports
+            .borrow()
+            .as_ref()
+            .map(|imports| imports.dir().to_string())
+            .unwrap_or_default();
+        let path = if dir.is_empty() {
+            file.to_string()
+        } else {
+            format!("{}/{}", dir, file)
+        };
+        self.use_namespace(namespace, ImportSource::Local(path), runtime);
+    }
+
+    /// References a type exported by the prelude.
+    pub fn prelude_type(&self, name: &str) -> Type {
+        self.prelude_generic(name, vec![])
+    }
+
+    /// References a generic type exported by the prelude.
+    pub fn prelude_generic(&self, name: &str, args: Vec<Type>) -> Type {
+        self.use_namespace(&self.prelude.namespace, self.prelude.import_source(), false);
+        Type::generic(&self.prelude.ident(name), args)
+    }
+
+    /// References a value exported by the prelude, e.g. `serializers.u64`.
+    pub fn prelude_value(&self, path: &str) -> Expr {
+        self.use_namespace(&self.prelude.namespace, self.prelude.import_source(), true);
+        Expr::path(&self.prelude.ident(path))
+    }
+
+    /// References the index of a generated module from the current file.
+    ///
+    /// Returns the namespace the index is imported as, `Some(None)` if the current file is the
+    /// index itself, or `None` if the module is not generated.
+    fn use_module_index(&self, module_id: &str, runtime: bool) -> Option<Option<String>> {
+        let output_name = self.generated_module_output_name(module_id)?;
+        let index = format!("{}/index", output_name);
+        let is_current = self
+            .imports
+            .borrow()
+            .as_ref()
+            .map_or(false, |imports| imports.file() == index);
+        if is_current {
+            return Some(None);
+        }
+        let namespace = sanitize_identifier(&format!("mod_{}", output_name));
+        self.use_namespace(&namespace, ImportSource::Local(index), runtime);
+        Some(Some(namespace))
+    }
+
+    /// References a type exported by the index of a generated module.
+    ///
+    /// Types of other modules are imported through a `mod_{output name}` namespace. Returns
+    /// `None` if the module is not generated.
+    pub fn module_type(&self, module_id: &str, name: &str, args: Vec<Type>) -> Option<Type> {
+        Some(match self.use_module_index(module_id, false)? {
+            Some(namespace) => Type::generic(&format!("{}.{}", namespace, name), args),
+            None => Type::generic(name, args),
+        })
+    }
+
+    /// References a value exported by the index of a generated module, e.g. `entry.transfer`.
+    ///
+    /// Returns `None` if the module is not generated.
+    pub fn module_value(&self, module_id: &str, path: &str) -> Option<Expr> {
+        Some(match self.use_module_index(module_id, true)? {
+            Some(namespace) => Expr::path(&format!("{}.{}", namespace, path)),
+            None => Expr::path(path),
+        })
+    }
+}
@@ -1,34 +1,85 @@
 //! Generates TypeScript code from a Move IDL.
 
+pub mod ast;
+pub mod chain;
+pub mod emit;
 pub mod format;
+pub mod generator;
+pub mod ident;
+pub mod idl_constant;
+pub mod idl_enum;
 pub mod idl_module;
 pub mod idl_package;
 pub mod idl_struct;
 pub mod idl_type;
+pub mod idl_view;
+pub mod imports;
+pub mod module_filter;
+pub mod naming;
+pub mod ordering;
+pub mod prelude;
+pub mod printer;
 pub mod script_function;
+pub mod templates;
 
-use crate::format::indent;
 use anyhow::*;
-use format::gen_doc_string;
+use ast::Module;
+use chain::Chain;
+use emit::EmitTarget;
+use format::FormatOptions;
+use generator::Generator;
+use idl_constant::{IDLConstant, IDLConstants};
+use idl_enum::{IDLEnum, IDLEnums};
 use idl_module::IDLModuleGenerator;
-use move_idl::{IDLModule, IDLPackage};
-use serde::Serialize;
-use std::fmt::Display;
+use idl_view::{IDLViewFunction, IDLViews};
+use imports::ImportCollector;
+use move_idl::{IDLModule, IDLPackage, StructTagData};
+use naming::NamingStrategy;
+use ordering::DeclarationOrder;
+use prelude::PreludeConfig;
+use printer::Printer;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{cell::RefCell, collections::BTreeMap, fmt::Display, sync::Arc};
+use templates::Templates;
 
-/// Generate TypeScript code for a value.
+/// Reads an array `key` which the modules and dependencies of an IDL file may carry besides the
+/// fields of `move-idl`, keyed by module ID.
+pub(crate) fn read_module_entries<T>(idl: &Value, key: &str) -> Result<BTreeMap<String, Vec<T>>>
+where
+    T: DeserializeOwned,
+{
+    let mut entries = BTreeMap::new();
+    for modules_key in ["modules", "dependencies"] {
+        let modules = match idl.get(modules_key).and_then(Value::as_object) {
+            Some(modules) => modules,
+            None => continue,
+        };
+        for (module_id, module) in modules {
+            if let Some(value) = module.get(key) {
+                entries.insert(
+                    module_id.clone(),
+                    serde_json::from_value(value.clone())
+                        .with_context(|| format!("parsing the {} of {}", key, module_id))?,
+                );
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Generate TypeScript nodes for a value.
 pub trait Codegen {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String>;
+    /// The [ast] node generated for the value.
+    type Node;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Self::Node>;
 }
 
+/// Source code of a generated file.
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CodeText(String);
 
-impl Codegen for CodeText {
-    fn generate_typescript(&self, _ctx: &CodegenContext) -> Result<String> {
-        Ok(self.to_string())
-    }
-}
-
 impl AsRef<[u8]> for CodeText {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -45,100 +96,218 @@ impl CodeText {
     pub fn new(s: &str) -> Self {
         CodeText(s.to_string())
     }
+}
 
-    pub fn new_reexport(name: &str, path: &str) -> Self {
-        format!("export * as {} from \"{}\";", name, path).into()
+pub struct CodegenContext<'info> {
+    pkg: &'info IDLPackage,
+    prelude: PreludeConfig,
+    target: EmitTarget,
+    naming: NamingStrategy,
+    format: FormatOptions,
+    order: DeclarationOrder,
+    /// Chain whose entry functions are called.
+    chain: Chain,
+    /// Generators of additional output for each module.
+    generators: Vec<Arc<dyn Generator>>,
+    templates: Templates,
+    /// Whether each error constant gets an error class.
+    error_classes: bool,
+    /// Constants of the modules other than error codes.
+    constants: IDLConstants,
+    /// Enums of the modules.
+    enums: IDLEnums,
+    /// View functions of the modules.
+    views: IDLViews,
+    /// Output names of the generated modules, keyed by module ID.
+    module_output_names: BTreeMap<String, String>,
+    /// Imports of the file being generated.
+    imports: RefCell<Option<ImportCollector>>,
+}
+
+impl<'info> CodegenContext<'info> {
+    pub fn new(pkg: &'info IDLPackage) -> Self {
+        CodegenContext {
+            pkg,
+            prelude: PreludeConfig::default(),
+            target: EmitTarget::default(),
+            naming: NamingStrategy::default(),
+            format: FormatOptions::default(),
+            order: DeclarationOrder::default(),
+            chain: Chain::default(),
+            generators: vec![],
+            templates: Templates::default(),
+            error_classes: false,
+            constants: IDLConstants::new(),
+            enums: IDLEnums::new(),
+            views: IDLViews::new(),
+            module_output_names: BTreeMap::new(),
+            imports: RefCell::new(None),
+        }
     }
 
-    pub fn new_named_reexport(name: &str, path: &str) -> Self {
-        format!("export {{ {} }} from \"{}\";", name, path).into()
+    /// Sets the prelude import configuration.
+    pub fn with_prelude(self, prelude: PreludeConfig) -> Self {
+        CodegenContext { prelude, ..self }
     }
 
-    pub fn new_fields_export(name: &str, fields: &str) -> Self {
-        format!("export type {} = {{\n{} }};", name, fields).into()
+    pub fn prelude(&self) -> &PreludeConfig {
+        &self.prelude
     }
 
-    pub fn append_newline(&self) -> Self {
-        CodeText(format!("{}\n", self.0))
+    /// Sets the kind of file to emit.
+    pub fn with_target(self, target: EmitTarget) -> Self {
+        CodegenContext { target, ..self }
     }
 
-    /// Creates a `export const {name} = {value} as const` statement.
-    pub fn new_const_export<T>(name: &str, value: &T) -> Result<Self>
-    where
-        T: ?Sized + Serialize,
-    {
-        Ok(format!(
-            "export const {} = {} as const;",
-            name,
-            serde_json::to_string_pretty(value)?
-        )
-        .into())
+    pub fn target(&self) -> EmitTarget {
+        self.target
     }
 
-    pub fn try_join_with_separator<'a, I>(values: I, separator: &str) -> Result<CodeText>
-    where
-        I: IntoIterator<Item = &'a CodeText>,
-    {
-        Ok(values
-            .into_iter()
-            .map(|v| v.to_string())
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-            .join(separator)
-            .into())
+    /// Sets the naming conventions of generated identifiers.
+    pub fn with_naming(self, naming: NamingStrategy) -> Self {
+        CodegenContext { naming, ..self }
     }
 
-    pub fn docs(&self, docs: &str) -> CodeText {
-        format!("{}{}", gen_doc_string(docs), self).into()
+    pub fn naming(&self) -> &NamingStrategy {
+        &self.naming
     }
 
-    pub fn module_docs(&self, docs: &str) -> CodeText {
-        format!(
-            "{}\n{}",
-            gen_doc_string(&format!("{}\n\n@module", docs)),
-            self
-        )
-        .into()
+    /// Sets the formatting of generated code.
+    pub fn with_format(self, format: FormatOptions) -> Self {
+        CodegenContext { format, ..self }
     }
-}
 
-pub struct CodegenContext<'info> {
-    pkg: &'info IDLPackage,
-}
+    pub fn format(&self) -> &FormatOptions {
+        &self.format
+    }
 
-impl<'info> CodegenContext<'info> {
-    pub fn new(pkg: &'info IDLPackage) -> Self {
-        CodegenContext { pkg }
+    /// Sets the order of generated modules and declarations.
+    pub fn with_order(self, order: DeclarationOrder) -> Self {
+        CodegenContext { order, ..self }
     }
 
-    pub fn get_module_generator(&self, value: &'info IDLModule) -> IDLModuleGenerator<'info> {
-        IDLModuleGenerator::new(value)
+    pub fn order(&self) -> DeclarationOrder {
+        self.order
+    }
+
+    /// Sets the chain whose entry functions are called.
+    pub fn with_chain(self, chain: Chain) -> Self {
+        CodegenContext { chain, ..self }
     }
 
-    pub fn generate<T: Codegen>(&self, value: &T) -> Result<CodeText> {
-        Ok(CodeText(value.generate_typescript(self)?))
+    pub fn chain(&self) -> Chain {
+        self.chain
     }
 
-    pub fn try_join_with_separator<'a, I, T>(&self, values: I, separator: &str) -> Result<CodeText>
-    where
-        I: IntoIterator<Item = &'a T>,
-        T: Codegen + 'a,
-    {
-        CodeText::try_join_with_separator(
-            &values
-                .into_iter()
-                .map(|v| self.generate(v))
-                .collect::<Result<Vec<_>>>()?,
-            separator,
-        )
+    /// Adds a generator of additional output for each module.
+    pub fn with_generator(mut self, generator: Arc<dyn Generator>) -> Self {
+        self.generators.push(generator);
+        self
     }
 
-    pub fn try_join<'a, I, T>(&self, values: I) -> Result<CodeText>
-    where
-        I: IntoIterator<Item = &'a T>,
-        T: Codegen + 'a,
-    {
-        self.try_join_with_separator(values, "\n\n")
+    pub fn generators(&self) -> &[Arc<dyn Generator>] {
+        &self.generators
+    }
+
+    /// Sets the templates which lay out the generated files.
+    pub fn with_templates(self, templates: Templates) -> Self {
+        CodegenContext { templates, ..self }
+    }
+
+    pub fn templates(&self) -> &Templates {
+        &self.templates
+    }
+
+    /// Sets whether each error constant gets a `p.MoveAbortError` subclass.
+    pub fn with_error_classes(self, error_classes: bool) -> Self {
+        CodegenContext {
+            error_classes,
+            ..self
+        }
+    }
+
+    pub fn error_classes(&self) -> bool {
+        self.error_classes
+    }
+
+    /// Sets the constants of the modules, keyed by module ID.
+    pub fn with_constants(self, constants: IDLConstants) -> Self {
+        CodegenContext { constants, ..self }
+    }
+
+    /// Constants of a module given its ID.
+    pub fn module_constants(&self, module_id: &str) -> &[IDLConstant] {
+        self.constants
+            .get(module_id)
+            .map_or(&[], |constants| constants.as_slice())
+    }
+
+    /// Sets the enums of the modules, keyed by module ID.
+    pub fn with_enums(self, enums: IDLEnums) -> Self {
+        CodegenContext { enums, ..self }
+    }
+
+    /// Enums of a module given its ID.
+    pub fn module_enums(&self, module_id: &str) -> &[IDLEnum] {
+        self.enums
+            .get(module_id)
+            .map_or(&[], |enums| enums.as_slice())
+    }
+
+    /// Finds the enum of a type name, if the type is an enum rather than a struct.
+    pub fn find_enum(&self, name: &StructTagData) -> Option<&IDLEnum> {
+        self.module_enums(&name.module_id().short_str_lossless())
+            .iter()
+            .find(|e| e.name == *name)
+    }
+
+    /// Sets the view functions of the modules, keyed by module ID.
+    pub fn with_views(self, views: IDLViews) -> Self {
+        CodegenContext { views, ..self }
+    }
+
+    /// View functions of a module given its ID.
+    pub fn module_views(&self, module_id: &str) -> &[IDLViewFunction] {
+        self.views
+            .get(module_id)
+            .map_or(&[], |views| views.as_slice())
+    }
+
+    /// Sets the output names of the generated modules, keyed by module ID.
+    ///
+    /// Types of these modules are referenced instead of being inlined.
+    pub fn with_module_output_names(self, module_output_names: BTreeMap<String, String>) -> Self {
+        CodegenContext {
+            module_output_names,
+            ..self
+        }
+    }
+
+    /// Name of the output directory of a module, which defaults to the module name.
+    pub fn module_output_name(&self, module: &IDLModule) -> String {
+        self.generated_module_output_name(&module.module_id.short_str_lossless())
+            .unwrap_or_else(|| module.module_id.name().to_string())
+    }
+
+    /// Output name of a generated module given its ID, or `None` if it is not generated.
+    pub fn generated_module_output_name(&self, module_id: &str) -> Option<String> {
+        self.module_output_names.get(module_id).cloned()
+    }
+
+    pub fn get_module_generator(&self, value: &'info IDLModule) -> IDLModuleGenerator<'info> {
+        IDLModuleGenerator::new(value, self)
+    }
+
+    pub fn generate<T: Codegen>(&self, value: &T) -> Result<T::Node> {
+        value.generate_typescript(self)
+    }
+
+    /// Renders a generated file for the target of this context.
+    pub fn print(&self, module: &Module) -> Result<CodeText> {
+        Ok(Printer::new(self.target)
+            .with_options(self.format)
+            .print_module(module)?
+            .into())
     }
 }
 
@@ -159,13 +328,3 @@ impl From<CodeText> for String {
         s.0
     }
 }
-
-impl CodeText {
-    pub fn indent(&self) -> CodeText {
-        indent(&self.0).into()
-    }
-
-    pub fn trim(&self) -> CodeText {
-        self.0.trim().into()
-    }
-}
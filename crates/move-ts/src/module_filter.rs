@@ -0,0 +1,192 @@
+//! Selection of the modules to generate.
+
+use anyhow::*;
+use move_idl::{IDLModule, IDLPackage};
+
+/// Include and exclude patterns selecting the modules to generate.
+///
+/// Patterns containing `::` are matched against the module ID, e.g. `0x1::coin` or `0x1::*`;
+/// other patterns are matched against the module name, e.g. `*_tests`. `*` matches any
+/// sequence of characters and `?` matches a single character. Named addresses such as
+/// `std::*` must be replaced by their values with [ModuleFilter::resolve] before matching.
+///
+/// Modules of the package are generated unless they match an exclude pattern. Dependencies
+/// are additionally required to match an include pattern, if any are given.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleFilter {
+    /// Patterns of the dependency modules to generate.
+    pub include: Vec<String>,
+    /// Patterns of the modules to skip.
+    pub exclude: Vec<String>,
+}
+
+impl ModuleFilter {
+    /// Replaces the named addresses of the patterns by the addresses `idl` assigns to them.
+    ///
+    /// Fails if a pattern names an address which the package does not define.
+    pub fn resolve(&self, idl: &IDLPackage) -> Result<ModuleFilter> {
+        let resolve_all = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| resolve_pattern(p, idl))
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(ModuleFilter {
+            include: resolve_all(&self.include)?,
+            exclude: resolve_all(&self.exclude)?,
+        })
+    }
+
+    /// Returns true if a module of the package should be generated.
+    pub fn includes_module(&self, module: &IDLModule) -> bool {
+        !self.exclude.iter().any(|p| pattern_matches(p, module))
+    }
+
+    /// Returns true if a dependency module should be generated.
+    pub fn includes_dependency(&self, module: &IDLModule) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| pattern_matches(p, module)))
+            && self.includes_module(module)
+    }
+}
+
+fn resolve_pattern(pattern: &str, idl: &IDLPackage) -> Result<String> {
+    let (address, name) = match pattern.rsplit_once("::") {
+        Some(parts) => parts,
+        None => return Ok(pattern.to_string()),
+    };
+    if address.starts_with("0x") || address.contains(['*', '?']) {
+        return Ok(pattern.to_string());
+    }
+    match idl.aliases.get(address) {
+        Some(value) => Ok(format!("{}::{}", value, name)),
+        None => bail!(
+            "unknown named address `{}` in module pattern `{}`",
+            address,
+            pattern
+        ),
+    }
+}
+
+fn pattern_matches(pattern: &str, module: &IDLModule) -> bool {
+    match pattern.rsplit_once("::") {
+        Some((address, name)) => {
+            glob_matches(
+                &normalize_address(address),
+                &module.module_id.address().to_hex_literal(),
+            ) && glob_matches(name, &module.module_id.name().to_string())
+        }
+        None => glob_matches(pattern, &module.module_id.name().to_string()),
+    }
+}
+
+/// Strips leading zeros from literal hex addresses so `0x0001` matches `0x1`.
+fn normalize_address(address: &str) -> String {
+    match address.strip_prefix("0x") {
+        Some(hex) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            let trimmed = hex.trim_start_matches('0');
+            format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+        }
+        _ => address.to_string(),
+    }
+}
+
+/// Matches `text` against a pattern where `*` matches any sequence and `?` any character.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn module(module_id: &str) -> IDLModule {
+        serde_json::from_value(json!({
+            "module_id": module_id,
+            "functions": [],
+            "structs": [],
+            "errors": {}
+        }))
+        .unwrap()
+    }
+
+    fn filter(include: &[&str], exclude: &[&str]) -> ModuleFilter {
+        ModuleFilter {
+            include: include.iter().map(|p| p.to_string()).collect(),
+            exclude: exclude.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("coin", "coin"));
+        assert!(!glob_matches("coin", "coins"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("*_tests", "coin_tests"));
+        assert!(!glob_matches("*_tests", "coin_test"));
+        assert!(glob_matches("c?in", "coin"));
+        assert!(!glob_matches("c?in", "cin"));
+        assert!(glob_matches("a*b*c", "axxbyybc"));
+        assert!(!glob_matches("a*b*c", "axxbyyb"));
+    }
+
+    #[test]
+    fn normalize_address_strips_leading_zeros() {
+        assert_eq!(normalize_address("0x0001"), "0x1");
+        assert_eq!(normalize_address("0x000"), "0x0");
+        assert_eq!(normalize_address("0xa"), "0xa");
+        assert_eq!(normalize_address("0x*"), "0x*");
+        assert_eq!(normalize_address("0x"), "0x");
+    }
+
+    #[test]
+    fn include_only_applies_to_dependencies() {
+        let filter = filter(&["0x1::coin"], &["*_tests"]);
+        assert!(filter.includes_module(&module("0xa::pool")));
+        assert!(!filter.includes_module(&module("0xa::pool_tests")));
+        assert!(filter.includes_dependency(&module("0x0001::coin")));
+        assert!(!filter.includes_dependency(&module("0x1::table")));
+        assert!(!filter.includes_dependency(&module("0x2::coin")));
+    }
+
+    #[test]
+    fn resolve_replaces_named_addresses() {
+        let idl: IDLPackage = serde_json::from_value(json!({
+            "name": "demo",
+            "modules": {},
+            "aliases": { "std": "0x1" },
+            "dependencies": {},
+            "errors": { "error_categories": {}, "module_error_maps": {} },
+            "structs": []
+        }))
+        .unwrap();
+        let resolved = filter(&["std::*", "0x2::coin", "*::table"], &["coin"])
+            .resolve(&idl)
+            .unwrap();
+        assert_eq!(
+            resolved,
+            filter(&["0x1::*", "0x2::coin", "*::table"], &["coin"])
+        );
+        assert!(resolved.includes_dependency(&module("0x1::table")));
+        assert!(filter(&["aptos_std::*"], &[]).resolve(&idl).is_err());
+    }
+}
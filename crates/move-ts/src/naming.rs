@@ -0,0 +1,128 @@
+//! Naming conventions of generated identifiers.
+
+use crate::ident::{sanitize_identifier, IdentScope};
+use heck::{ToLowerCamelCase, ToPascalCase, ToSnakeCase};
+use std::str::FromStr;
+
+/// Casing applied to a generated identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    /// Keep the Move name.
+    Preserve,
+    /// `camelCase`.
+    Camel,
+    /// `PascalCase`.
+    Pascal,
+    /// `snake_case`.
+    Snake,
+}
+
+impl Case {
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            Case::Preserve => name.to_string(),
+            Case::Camel => name.to_lower_camel_case(),
+            Case::Pascal => name.to_pascal_case(),
+            Case::Snake => name.to_snake_case(),
+        }
+    }
+}
+
+impl FromStr for Case {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Case::Preserve),
+            "camel" => Ok(Case::Camel),
+            "pascal" => Ok(Case::Pascal),
+            "snake" => Ok(Case::Snake),
+            _ => Err(format!(
+                "unknown case `{}`, expected one of preserve, camel, pascal or snake",
+                s
+            )),
+        }
+    }
+}
+
+/// Naming conventions of generated identifiers.
+///
+/// The defaults keep the names generated by earlier versions of move-ts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamingStrategy {
+    /// Casing of entry function builders and their names.
+    pub functions: Case,
+    /// Casing of entry function payload types.
+    pub payload_types: Case,
+    /// Casing of struct interfaces.
+    pub struct_types: Case,
+    /// Prefix of struct interfaces.
+    pub struct_type_prefix: String,
+    /// Casing of struct fields, entry function arguments and type arguments.
+    pub fields: Case,
+    /// Casing of the module exports of the package index.
+    pub modules: Case,
+}
+
+impl Default for NamingStrategy {
+    fn default() -> Self {
+        NamingStrategy {
+            functions: Case::Preserve,
+            payload_types: Case::Pascal,
+            struct_types: Case::Preserve,
+            struct_type_prefix: "I".to_string(),
+            fields: Case::Preserve,
+            modules: Case::Preserve,
+        }
+    }
+}
+
+impl NamingStrategy {
+    /// Name of the builder of an entry function.
+    pub fn function_name(&self, name: &str) -> String {
+        sanitize_identifier(&self.functions.apply(name))
+    }
+
+    /// Name of the payload type of an entry function.
+    pub fn payload_type_name(&self, name: &str) -> String {
+        sanitize_identifier(&self.payload_types.apply(name))
+    }
+
+    /// Name of the interface of a struct.
+    pub fn struct_type_name(&self, name: &str) -> String {
+        sanitize_identifier(&format!(
+            "{}{}",
+            self.struct_type_prefix,
+            self.struct_types.apply(name)
+        ))
+    }
+
+    /// Name of a struct field, entry function argument or type argument.
+    ///
+    /// Fields are only used as property names, so reserved words do not need to be escaped.
+    pub fn field_name(&self, name: &str) -> String {
+        self.fields.apply(name)
+    }
+
+    /// Names of the fields of a single object type, renaming fields whose names collide
+    /// once cased.
+    pub fn field_names<'a, I>(&self, names: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut scope = IdentScope::default();
+        names
+            .into_iter()
+            .map(|name| scope.declare_property(&self.field_name(name)))
+            .collect()
+    }
+
+    /// Name of the export of a module in the package index.
+    pub fn module_export_name(&self, package_prefix: &str, module: &str) -> String {
+        sanitize_identifier(
+            &self
+                .modules
+                .apply(&format!("{}_{}", package_prefix, module)),
+        )
+    }
+}
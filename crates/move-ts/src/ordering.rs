@@ -0,0 +1,77 @@
+//! Ordering of generated modules and declarations.
+//!
+//! Generated output only depends on the IDL and the [DeclarationOrder], so regenerating an
+//! unchanged package produces identical files.
+
+use move_idl::IDLModule;
+use std::{cmp::Ordering, str::FromStr};
+
+/// Order of the modules of a package and of the declarations of each module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeclarationOrder {
+    /// Modules sorted by module ID, and structs and functions in the order they are declared
+    /// in the IDL. Errors are sorted by code.
+    Declaration,
+    /// Modules, structs, functions and errors sorted by name.
+    Name,
+}
+
+impl Default for DeclarationOrder {
+    fn default() -> Self {
+        DeclarationOrder::Declaration
+    }
+}
+
+impl FromStr for DeclarationOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "declaration" => Ok(DeclarationOrder::Declaration),
+            "name" => Ok(DeclarationOrder::Name),
+            _ => Err(format!(
+                "unknown order `{}`, expected declaration or name",
+                s
+            )),
+        }
+    }
+}
+
+/// Compares module addresses numerically, given as hex literals without leading zeros.
+fn compare_addresses(a: &str, b: &str) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+impl DeclarationOrder {
+    /// Compares two modules.
+    pub fn compare_modules(&self, a: &IDLModule, b: &IDLModule) -> Ordering {
+        let by_address = || {
+            compare_addresses(
+                &a.module_id.address().to_hex_literal(),
+                &b.module_id.address().to_hex_literal(),
+            )
+        };
+        let by_name = || {
+            a.module_id
+                .name()
+                .to_string()
+                .cmp(&b.module_id.name().to_string())
+        };
+        match self {
+            DeclarationOrder::Declaration => by_address().then_with(by_name),
+            DeclarationOrder::Name => by_name().then_with(by_address),
+        }
+    }
+
+    /// Sorts declarations by the given name if ordered by name, and keeps their order otherwise.
+    ///
+    /// The sort is stable, so declarations sharing a name keep their order.
+    pub fn sort_by_name<T, F>(&self, items: &mut [T], name: F)
+    where
+        F: Fn(&T) -> &str,
+    {
+        if *self == DeclarationOrder::Name {
+            items.sort_by(|a, b| name(a).cmp(name(b)));
+        }
+    }
+}
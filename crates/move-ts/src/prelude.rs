@@ -0,0 +1,76 @@
+//! Configuration of the runtime prelude imported by generated code.
+
+use crate::{
+    emit::{EmitTarget, ModuleFormat},
+    imports::ImportSource,
+};
+
+/// Default npm package providing the prelude runtime.
+///
+/// The package provides the argument types, the JSON serializers and `MoveModuleDefinition`.
+/// The other helpers, e.g. the BCS encoders, are only part of the bundled prelude, so code
+/// using them is only generated with [PreludeSource::Bundled].
+pub const DEFAULT_PRELUDE_PACKAGE: &str = "@movingco/prelude";
+
+/// Default namespace the prelude is imported as.
+pub const DEFAULT_PRELUDE_NAMESPACE: &str = "p";
+
+/// Name of the bundled prelude module, relative to the output directory.
+pub const BUNDLED_PRELUDE_NAME: &str = "prelude";
+
+/// Source of the self-contained prelude emitted when using [PreludeSource::Bundled].
+pub fn bundled_prelude(target: EmitTarget) -> &'static str {
+    match target {
+        EmitTarget::TypeScript => include_str!("prelude/prelude.ts"),
+        EmitTarget::JavaScript(ModuleFormat::Esm) => include_str!("prelude/prelude.js"),
+        EmitTarget::JavaScript(ModuleFormat::CommonJs) => include_str!("prelude/prelude.cjs"),
+        EmitTarget::Declarations(_) => include_str!("prelude/prelude.d.ts"),
+    }
+}
+
+/// Where generated code imports the prelude from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreludeSource {
+    /// Import the prelude from a module specifier, e.g. an npm package.
+    Package(String),
+    /// Import the prelude from a `prelude.ts` emitted into the output directory.
+    Bundled,
+}
+
+/// Configuration of the prelude import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreludeConfig {
+    /// Where to import the prelude from.
+    pub source: PreludeSource,
+    /// Namespace the prelude is imported as.
+    pub namespace: String,
+}
+
+impl Default for PreludeConfig {
+    fn default() -> Self {
+        PreludeConfig {
+            source: PreludeSource::Package(DEFAULT_PRELUDE_PACKAGE.to_string()),
+            namespace: DEFAULT_PRELUDE_NAMESPACE.to_string(),
+        }
+    }
+}
+
+impl PreludeConfig {
+    /// Module the prelude is imported from.
+    pub fn import_source(&self) -> ImportSource {
+        match &self.source {
+            PreludeSource::Package(path) => ImportSource::Package(path.clone()),
+            PreludeSource::Bundled => ImportSource::Local(BUNDLED_PRELUDE_NAME.to_string()),
+        }
+    }
+
+    /// Returns true if the prelude is bundled, and thus provides all helpers of the runtime.
+    pub fn is_bundled(&self) -> bool {
+        self.source == PreludeSource::Bundled
+    }
+
+    /// References an item exported by the prelude.
+    pub fn ident(&self, name: &str) -> String {
+        format!("{}.{}", self.namespace, name)
+    }
+}
@@ -0,0 +1,263 @@
+/**
+ * Runtime helpers for code generated by move-ts.
+ *
+ * @module
+ */
+
+/** A value convertible to a `u64`. */
+export type U64 = string | number | bigint;
+
+/** A value convertible to a `u128`. */
+export type U128 = string | number | bigint;
+
+/** A hex string or raw bytes. */
+export type HexStringArg = string | Uint8Array;
+
+/** An account address. */
+export type RawAddress = HexStringArg;
+
+/** A signer, represented by its address. */
+export type RawSigner = HexStringArg;
+
+/** A `vector<u8>`. */
+export type ByteString = HexStringArg;
+
+/** A Move `Option<T>`, which is `null` or `undefined` if it is none. */
+export type Option<T> = T | null | undefined;
+
+/** Serializers for JSON script function arguments. */
+export declare const serializers: {
+  readonly u64: (value: U64) => string;
+  readonly u128: (value: U128) => string;
+  readonly hexString: (value: HexStringArg) => string;
+};
+
+/** Encodes a value as BCS. */
+export type BcsEncoder<T> = (value: T) => Uint8Array;
+
+/** An entry function call encoded by {@link bcs.entryFunctionPayload}. */
+export interface EntryFunctionCall {
+  /** Address of the module. */
+  readonly address: string;
+  /** Name of the module. */
+  readonly module: string;
+  /** Name of the function. */
+  readonly function: string;
+  /** Type arguments, e.g. `0x1::aptos_coin::AptosCoin`. */
+  readonly typeArgs: ReadonlyArray<string>;
+  /** BCS-encoded arguments. */
+  readonly args: ReadonlyArray<Uint8Array>;
+}
+
+/** An argument of a {@link MoveCall}: a BCS-encoded pure value, or the ID of an object. */
+export type MoveCallArg =
+  | { readonly pure: Uint8Array }
+  | { readonly object: string };
+
+/** A Move call of a Sui programmable transaction. */
+export interface MoveCall {
+  /** Function called, e.g. `0x2::pay::split`. */
+  readonly target: string;
+  /** Type arguments, e.g. `0x2::sui::SUI`. */
+  readonly typeArguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<MoveCallArg>;
+}
+
+/** BCS encoders for entry function arguments and transaction payloads. */
+export declare const bcs: {
+  readonly bool: (value: boolean) => Uint8Array;
+  readonly u8: (value: number) => Uint8Array;
+  readonly u64: (value: U64) => Uint8Array;
+  readonly u128: (value: U128) => Uint8Array;
+  /** Encodes an address of `length` bytes, padded with leading zeros. */
+  readonly address: (value: HexStringArg, length: number) => Uint8Array;
+  /** Encodes a `vector<u8>`. */
+  readonly bytes: (value: HexStringArg) => Uint8Array;
+  /** Encodes a UTF-8 string, e.g. a `0x1::string::String`. */
+  readonly string: (value: string) => Uint8Array;
+  readonly vector: <T>(
+    values: ReadonlyArray<T>,
+    encode: BcsEncoder<T>
+  ) => Uint8Array;
+  /** Encodes an `Option<T>` as a vector of zero or one elements. */
+  readonly option: <T>(value: Option<T>, encode: BcsEncoder<T>) => Uint8Array;
+  /** Encodes a struct from its encoded fields. */
+  readonly struct: (fields: ReadonlyArray<Uint8Array>) => Uint8Array;
+  /** Accepts a value which is already encoded, e.g. of a generic type. */
+  readonly encoded: (value: unknown) => Uint8Array;
+  /** Encodes a type tag, e.g. `vector<0x1::coin::Coin<0x1::aptos_coin::AptosCoin>>`. */
+  readonly typeTag: (tag: string, length: number) => Uint8Array;
+  /** Encodes a `TransactionPayload` calling an entry function. */
+  readonly entryFunctionPayload: (
+    call: EntryFunctionCall,
+    length: number
+  ) => Uint8Array;
+};
+
+/** Decodes a value from a {@link BcsReader}. */
+export type BcsDecoder<T> = (reader: BcsReader) => T;
+
+/** Reads BCS-encoded values from bytes, in order. */
+export declare class BcsReader {
+  private readonly input;
+  private offset;
+  constructor(input: Uint8Array);
+  /** True if all the bytes have been read. */
+  get done(): boolean;
+  private read;
+  private uint;
+  bool(): boolean;
+  u8(): number;
+  u64(): bigint;
+  u128(): bigint;
+  /** Reads the ULEB128-encoded length of a vector or index of an enum variant. */
+  uleb128(): number;
+  /** Reads an address of `length` bytes as a hex string. */
+  address(length: number): string;
+  /** Reads a `vector<u8>` as a hex string. */
+  bytes(): string;
+  /** Reads a UTF-8 string, e.g. a `0x1::string::String`. */
+  string(): string;
+  vector<T>(decode: BcsDecoder<T>): T[];
+  /** Reads the index of an enum variant, then the variant with the decoder at that index. */
+  variant<T>(variants: ReadonlyArray<BcsDecoder<T>>): T;
+}
+
+/** Decodes BCS-encoded bytes, failing if any bytes are left over. */
+export declare const decodeBcs: <T>(
+  bytes: HexStringArg,
+  decode: BcsDecoder<T>
+) => T;
+
+/** Definition of a Move module. */
+export interface MoveModuleDefinition<A extends string, M extends string> {
+  readonly ADDRESS: A;
+  readonly FULL_NAME: `${A}::${M}`;
+  readonly NAME: M;
+  readonly errorCodes: Readonly<Record<string, unknown>>;
+  readonly functions: Readonly<Record<string, unknown>>;
+  readonly resources: Readonly<Record<string, string>>;
+  readonly structs: Readonly<Record<string, string>>;
+}
+
+/** A JSON script function payload, as built by the generated `entry` builders. */
+export interface ScriptFunctionPayload {
+  readonly type: "script_function_payload";
+  readonly function: string;
+  readonly type_arguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<unknown>;
+}
+
+/** A JSON payload calling a view function, as built by generated clients. */
+export interface ViewFunctionPayload {
+  readonly function: string;
+  readonly type_arguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<unknown>;
+}
+
+/** Signs and submits the transactions of generated clients. */
+export interface MoveSigner {
+  /** Signs and submits a transaction calling an entry function, resolving to its hash. */
+  submit(payload: ScriptFunctionPayload): Promise<string>;
+}
+
+/** Reads the state of the chain for generated clients. */
+export interface MoveProvider {
+  /** Reads the resource of type `type` stored at `address`, resolving to `null` if there is none. */
+  getResource(address: string, type: string): Promise<unknown>;
+  /** Calls a view function, resolving to its JSON-encoded return values. */
+  view(payload: ViewFunctionPayload): Promise<ReadonlyArray<unknown>>;
+}
+
+/** Formats the type of a struct with its type arguments, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`. */
+export declare const structTag: (
+  name: string,
+  typeArgs: ReadonlyArray<string>
+) => string;
+
+/** An abort of a Move transaction. */
+export interface MoveAbort {
+  /** ID of the aborting module, e.g. `0x1::coin`. */
+  readonly module: string;
+  /** The abort code. */
+  readonly code: number;
+  /** Category of the code in the encoding of `std::error`, i.e. its third byte. */
+  readonly category: number;
+  /** Reason of the code in the encoding of `std::error`, i.e. its two lowest bytes. */
+  readonly reason: number;
+}
+
+/** Name and documentation of a Move error constant. */
+export interface MoveErrorInfo {
+  readonly name: string;
+  readonly doc: string | null;
+}
+
+/** An abort of a Move transaction decoded into the error constant which caused it. */
+export interface DecodedAbort extends MoveAbort, MoveErrorInfo {}
+
+/** Errors of a package, keyed by module ID and error code. */
+export type ErrorMap = Readonly<
+  Record<string, Readonly<Record<string, MoveErrorInfo>>>
+>;
+
+/** Extracts the aborting module and abort code of a VM status, or `null` if it is not an abort. */
+export declare const parseVmStatus: (vmStatus: string) => MoveAbort | null;
+
+/**
+ * Decodes the abort of a VM status into the error of `errmap` which caused it.
+ *
+ * Codes are looked up as is, then as compared by {@link matchesError}, so that an error matches
+ * whether or not either its code or the abort code carries a `std::error` category.
+ */
+export declare const decodeAbort: (
+  errmap: ErrorMap,
+  vmStatus: string
+) => DecodedAbort | null;
+
+/**
+ * Whether the abort code `code` is the error `error`.
+ *
+ * Codes are compared by their `std::error` reason, ignoring their category.
+ */
+export declare const matchesError: (
+  code: number,
+  error: { readonly code: number; readonly reason: number }
+) => boolean;
+
+/** Error of an aborted Move transaction, extended by the generated class of each error constant. */
+export declare abstract class MoveAbortError extends Error {
+  /** ID of the module declaring the error. */
+  abstract readonly module: string;
+  /** Code of the error constant. */
+  abstract readonly code: number;
+  /** Name of the error constant. */
+  abstract readonly errorName: string;
+  /** The abort, whose code may carry a `std::error` category the error constant does not. */
+  readonly abort: MoveAbort;
+  constructor(abort: MoveAbort);
+}
+
+/** Class of an error constant. */
+export type MoveAbortErrorClass = new (abort: MoveAbort) => MoveAbortError;
+
+/** Error classes of a package, keyed by module ID and error code. */
+export type ErrorClassMap = Readonly<
+  Record<string, Readonly<Record<string, MoveAbortErrorClass>>>
+>;
+
+/**
+ * Decodes the abort of a VM status into an instance of the class of `classes` which caused it.
+ *
+ * Codes are looked up as in {@link decodeAbort}.
+ */
+export declare const abortError: (
+  classes: ErrorClassMap,
+  vmStatus: string
+) => MoveAbortError | null;
+
+/** Whether `error` is an instance of one of the error classes of `classes`. */
+export declare const isErrorOf: (
+  classes: ErrorClassMap,
+  error: unknown
+) => error is MoveAbortError;
@@ -0,0 +1,422 @@
+/**
+ * Runtime helpers for code generated by move-ts.
+ *
+ * @module
+ */
+
+const toHex = (bytes) =>
+  `0x${Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("")}`;
+
+/** Serializers for JSON script function arguments. */
+export const serializers = {
+  u64: (value) => BigInt(value).toString(),
+  u128: (value) => BigInt(value).toString(),
+  hexString: (value) => (typeof value === "string" ? value : toHex(value)),
+};
+
+const fromHex = (value) => {
+  const hex = value.startsWith("0x") ? value.slice(2) : value;
+  if (!/^[0-9a-fA-F]*$/.test(hex)) {
+    throw new TypeError(`invalid hex string: ${value}`);
+  }
+  const padded = hex.length % 2 === 0 ? hex : `0${hex}`;
+  const bytes = new Uint8Array(padded.length / 2);
+  for (let i = 0; i < bytes.length; i++) {
+    bytes[i] = parseInt(padded.slice(i * 2, i * 2 + 2), 16);
+  }
+  return bytes;
+};
+
+const toBytes = (value) => typeof value === "string" ? fromHex(value) : value;
+
+const concatBytes = (parts) => {
+  const length = parts.reduce((sum, part) => sum + part.length, 0);
+  const bytes = new Uint8Array(length);
+  let offset = 0;
+  for (const part of parts) {
+    bytes.set(part, offset);
+    offset += part.length;
+  }
+  return bytes;
+};
+
+const uleb128 = (value) => {
+  const bytes = [];
+  let rest = value;
+  do {
+    const byte = rest % 0x80;
+    rest = Math.floor(rest / 0x80);
+    bytes.push(rest === 0 ? byte : byte | 0x80);
+  } while (rest !== 0);
+  return Uint8Array.from(bytes);
+};
+
+const uint = (value, size, name) => {
+  let rest = BigInt(value);
+  if (rest < BigInt(0) || rest >= BigInt(1) << BigInt(size * 8)) {
+    throw new RangeError(`${value.toString()} is out of range for ${name}`);
+  }
+  const bytes = new Uint8Array(size);
+  for (let i = 0; i < size; i++) {
+    bytes[i] = Number(rest & BigInt(0xff));
+    rest >>= BigInt(8);
+  }
+  return bytes;
+};
+
+const withLength = (bytes) => concatBytes([uleb128(bytes.length), bytes]);
+
+const encodeAddress = (value, length) => {
+  const bytes = toBytes(value);
+  if (bytes.length > length) {
+    throw new RangeError(`address is longer than ${length} bytes`);
+  }
+  const address = new Uint8Array(length);
+  address.set(bytes, length - bytes.length);
+  return address;
+};
+
+const encodeString = (value) => withLength(new TextEncoder().encode(value));
+
+const PRIMITIVE_TYPE_TAGS = {
+  bool: 0,
+  u8: 1,
+  u64: 2,
+  u128: 3,
+  address: 4,
+  signer: 5,
+  u16: 8,
+  u32: 9,
+  u256: 10,
+};
+
+const encodeTypeTag = (tag, length) => {
+  const tokens = tag.match(/::|[<>,]|[^\s:<>,]+/g) ?? [];
+  let position = 0;
+  const next = () => {
+    const token = tokens[position++];
+    if (token === undefined) {
+      throw new TypeError(`unexpected end of type tag ${tag}`);
+    }
+    return token;
+  };
+  const expect = (expected) => {
+    const token = next();
+    if (token !== expected) {
+      throw new TypeError(`expected \`${expected}\` in type tag ${tag}`);
+    }
+  };
+  const parse = () => {
+    const token = next();
+    const primitive = PRIMITIVE_TYPE_TAGS[token];
+    if (primitive !== undefined) {
+      return Uint8Array.of(primitive);
+    }
+    if (token === "vector") {
+      expect("<");
+      const inner = parse();
+      expect(">");
+      return concatBytes([Uint8Array.of(6), inner]);
+    }
+    expect("::");
+    const module = next();
+    expect("::");
+    const name = next();
+    const params = [];
+    if (tokens[position] === "<") {
+      position++;
+      params.push(parse());
+      while (tokens[position] === ",") {
+        position++;
+        params.push(parse());
+      }
+      expect(">");
+    }
+    return concatBytes([
+      Uint8Array.of(7),
+      encodeAddress(token, length),
+      encodeString(module),
+      encodeString(name),
+      uleb128(params.length),
+      ...params,
+    ]);
+  };
+  const encoded = parse();
+  if (position !== tokens.length) {
+    throw new TypeError(`unexpected \`${tokens[position]}\` in type tag ${tag}`);
+  }
+  return encoded;
+};
+
+/** BCS encoders for entry function arguments and transaction payloads. */
+export const bcs = {
+  bool: (value) => Uint8Array.of(value ? 1 : 0),
+  u8: (value) => uint(value, 1, "u8"),
+  u64: (value) => uint(value, 8, "u64"),
+  u128: (value) => uint(value, 16, "u128"),
+  /** Encodes an address of `length` bytes, padded with leading zeros. */
+  address: (value, length) => encodeAddress(value, length),
+  /** Encodes a `vector<u8>`. */
+  bytes: (value) => withLength(toBytes(value)),
+  /** Encodes a UTF-8 string, e.g. a `0x1::string::String`. */
+  string: (value) => encodeString(value),
+  vector: (values, encode) =>
+    concatBytes([uleb128(values.length), ...values.map((v) => encode(v))]),
+  /** Encodes an `Option<T>` as a vector of zero or one elements. */
+  option: (value, encode) =>
+    value === null || value === undefined
+      ? Uint8Array.of(0)
+      : concatBytes([Uint8Array.of(1), encode(value)]),
+  /** Encodes a struct from its encoded fields. */
+  struct: (fields) => concatBytes(fields),
+  /** Accepts a value which is already encoded, e.g. of a generic type. */
+  encoded: (value) => {
+    if (!(value instanceof Uint8Array)) {
+      throw new TypeError("expected a BCS-encoded Uint8Array");
+    }
+    return value;
+  },
+  /** Encodes a type tag, e.g. `vector<0x1::coin::Coin<0x1::aptos_coin::AptosCoin>>`. */
+  typeTag: (tag, length) => encodeTypeTag(tag, length),
+  /** Encodes a `TransactionPayload` calling an entry function. */
+  entryFunctionPayload: (call, length) =>
+    concatBytes([
+      Uint8Array.of(2),
+      encodeAddress(call.address, length),
+      encodeString(call.module),
+      encodeString(call.function),
+      uleb128(call.typeArgs.length),
+      ...call.typeArgs.map((tag) => encodeTypeTag(tag, length)),
+      uleb128(call.args.length),
+      ...call.args.map(withLength),
+    ]),
+};
+
+/** Reads BCS-encoded values from bytes, in order. */
+export class BcsReader {
+  constructor(input) {
+    this.input = input;
+    this.offset = 0;
+  }
+
+  /** True if all the bytes have been read. */
+  get done() {
+    return this.offset === this.input.length;
+  }
+
+  read(length) {
+    if (this.offset + length > this.input.length) {
+      throw new RangeError("unexpected end of BCS input");
+    }
+    const bytes = this.input.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return bytes;
+  }
+
+  uint(size) {
+    return this.read(size).reduceRight(
+      (value, byte) => (value << BigInt(8)) | BigInt(byte),
+      BigInt(0)
+    );
+  }
+
+  bool() {
+    const byte = this.u8();
+    if (byte > 1) {
+      throw new TypeError(`invalid BCS boolean ${byte}`);
+    }
+    return byte === 1;
+  }
+
+  u8() {
+    return this.read(1)[0];
+  }
+
+  u64() {
+    return this.uint(8);
+  }
+
+  u128() {
+    return this.uint(16);
+  }
+
+  /** Reads the ULEB128-encoded length of a vector or index of an enum variant. */
+  uleb128() {
+    let value = 0;
+    for (let shift = 1; ; shift *= 0x80) {
+      const byte = this.u8();
+      value += (byte & 0x7f) * shift;
+      if ((byte & 0x80) === 0) {
+        return value;
+      }
+    }
+  }
+
+  /** Reads an address of `length` bytes as a hex string. */
+  address(length) {
+    return toHex(this.read(length));
+  }
+
+  /** Reads a `vector<u8>` as a hex string. */
+  bytes() {
+    return toHex(this.read(this.uleb128()));
+  }
+
+  /** Reads a UTF-8 string, e.g. a `0x1::string::String`. */
+  string() {
+    return new TextDecoder("utf-8", { fatal: true }).decode(
+      this.read(this.uleb128())
+    );
+  }
+
+  vector(decode) {
+    const length = this.uleb128();
+    const values = [];
+    for (let i = 0; i < length; i++) {
+      values.push(decode(this));
+    }
+    return values;
+  }
+
+  /** Reads the index of an enum variant, then the variant with the decoder at that index. */
+  variant(variants) {
+    const index = this.uleb128();
+    const decode = variants[index];
+    if (decode === undefined) {
+      throw new RangeError(`unknown enum variant index ${index}`);
+    }
+    return decode(this);
+  }
+}
+
+/** Decodes BCS-encoded bytes, failing if any bytes are left over. */
+export const decodeBcs = (bytes, decode) => {
+  const reader = new BcsReader(toBytes(bytes));
+  const value = decode(reader);
+  if (!reader.done) {
+    throw new RangeError("unexpected trailing bytes in BCS input");
+  }
+  return value;
+};
+
+/** Formats the type of a struct with its type arguments, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`. */
+export const structTag = (name, typeArgs) =>
+  typeArgs.length === 0 ? name : `${name}<${typeArgs.join(", ")}>`;
+
+const normalizeAddress = (address) =>
+  `0x${address.replace(/^0x/i, "").replace(/^0+/, "").toLowerCase() || "0"}`;
+
+const ABORT_PATTERNS = [
+  // Aptos: `Move abort in 0x1::coin: EINSUFFICIENT_BALANCE(0x10006): ...`
+  {
+    pattern:
+      /Move abort in (0x[0-9a-f]+)::(\w+): (?:\w+\()?(0x[0-9a-f]+|\d+)/i,
+    address: 1,
+    name: 2,
+    code: 3,
+  },
+  // Aptos and Diem: `ABORTED { code: 65542, location: 0x1::coin }`
+  {
+    pattern: /code: (0x[0-9a-f]+|\d+), location: (0x[0-9a-f]+)::(\w+)/i,
+    address: 2,
+    name: 3,
+    code: 1,
+  },
+  // Sui: `MoveAbort(MoveLocation { module: ModuleId { address: 0000...0002, name: Identifier("coin") }, ... }, 65542)`
+  {
+    pattern:
+      /MoveAbort\(MoveLocation \{ module: ModuleId \{ address: (?:0x)?([0-9a-f]+), name: Identifier\("(\w+)"\) \}.*\}, (\d+)\)/i,
+    address: 1,
+    name: 2,
+    code: 3,
+  },
+];
+
+/** Extracts the aborting module and abort code of a VM status, or `null` if it is not an abort. */
+export const parseVmStatus = (vmStatus) => {
+  for (const { pattern, address, name, code } of ABORT_PATTERNS) {
+    const match = pattern.exec(vmStatus);
+    if (match) {
+      const value = Number(match[code]);
+      return {
+        module: `${normalizeAddress(match[address])}::${match[name]}`,
+        code: value,
+        category: Math.floor(value / 0x10000) & 0xff,
+        reason: value % 0x10000,
+      };
+    }
+  }
+  return null;
+};
+
+/**
+ * Decodes the abort of a VM status into the error of `errmap` which caused it.
+ *
+ * Codes are looked up as is, then as compared by {@link matchesError}, so that an error matches
+ * whether or not either its code or the abort code carries a `std::error` category.
+ */
+export const decodeAbort = (errmap, vmStatus) => {
+  const abort = parseVmStatus(vmStatus);
+  if (!abort) {
+    return null;
+  }
+  const error = lookupError(errmap[abort.module], abort.code);
+  return error ? { ...abort, name: error.name, doc: error.doc } : null;
+};
+
+/**
+ * Whether the abort code `code` is the error `error`.
+ *
+ * Codes are compared by their `std::error` reason, ignoring their category.
+ */
+export const matchesError = (code, error) =>
+  code === error.code || (code < 0x1000000 && code % 0x10000 === error.reason);
+
+/** Reason of an error code, or the code itself if it has no known `std::error` category. */
+const errorReason = (code) =>
+  Math.floor(code / 0x10000) <= 0xd ? code % 0x10000 : code;
+
+/**
+ * Looks up the entry of `errors`, keyed by error code, for the abort code `code`: by the code
+ * itself, or else by the first error code which {@link matchesError} it.
+ */
+const lookupError = (errors, code) => {
+  if (!errors) {
+    return undefined;
+  }
+  const key = Object.keys(errors).find((key) =>
+    matchesError(code, { code: Number(key), reason: errorReason(Number(key)) })
+  );
+  return errors[code] ?? (key === undefined ? undefined : errors[key]);
+};
+
+/** Error of an aborted Move transaction, extended by the generated class of each error constant. */
+export class MoveAbortError extends Error {
+  constructor(abort) {
+    super(`Move abort in ${abort.module} with code ${abort.code}`);
+    this.name = new.target.name;
+    /** The abort, whose code may carry a `std::error` category the error constant does not. */
+    this.abort = abort;
+  }
+}
+
+/**
+ * Decodes the abort of a VM status into an instance of the class of `classes` which caused it.
+ *
+ * Codes are looked up as in {@link decodeAbort}.
+ */
+export const abortError = (classes, vmStatus) => {
+  const abort = parseVmStatus(vmStatus);
+  if (!abort) {
+    return null;
+  }
+  const ErrorClass = lookupError(classes[abort.module], abort.code);
+  return ErrorClass ? new ErrorClass(abort) : null;
+};
+
+/** Whether `error` is an instance of one of the error classes of `classes`. */
+export const isErrorOf = (classes, error) =>
+  error instanceof MoveAbortError &&
+  Object.values(classes[error.module] ?? {}).some(
+    (ErrorClass) => error instanceof ErrorClass
+  );
@@ -0,0 +1,588 @@
+/**
+ * Runtime helpers for code generated by move-ts.
+ *
+ * @module
+ */
+
+/** A value convertible to a `u64`. */
+export type U64 = string | number | bigint;
+
+/** A value convertible to a `u128`. */
+export type U128 = string | number | bigint;
+
+/** A hex string or raw bytes. */
+export type HexStringArg = string | Uint8Array;
+
+/** An account address. */
+export type RawAddress = HexStringArg;
+
+/** A signer, represented by its address. */
+export type RawSigner = HexStringArg;
+
+/** A `vector<u8>`. */
+export type ByteString = HexStringArg;
+
+/** A Move `Option<T>`, which is `null` or `undefined` if it is none. */
+export type Option<T> = T | null | undefined;
+
+const toHex = (bytes: Uint8Array): string =>
+  `0x${Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("")}`;
+
+/** Serializers for JSON script function arguments. */
+export const serializers = {
+  u64: (value: U64): string => BigInt(value).toString(),
+  u128: (value: U128): string => BigInt(value).toString(),
+  hexString: (value: HexStringArg): string =>
+    typeof value === "string" ? value : toHex(value),
+} as const;
+
+/** Encodes a value as BCS. */
+export type BcsEncoder<T> = (value: T) => Uint8Array;
+
+/** An entry function call encoded by {@link bcs.entryFunctionPayload}. */
+export interface EntryFunctionCall {
+  /** Address of the module. */
+  readonly address: string;
+  /** Name of the module. */
+  readonly module: string;
+  /** Name of the function. */
+  readonly function: string;
+  /** Type arguments, e.g. `0x1::aptos_coin::AptosCoin`. */
+  readonly typeArgs: ReadonlyArray<string>;
+  /** BCS-encoded arguments. */
+  readonly args: ReadonlyArray<Uint8Array>;
+}
+
+/** An argument of a {@link MoveCall}: a BCS-encoded pure value, or the ID of an object. */
+export type MoveCallArg =
+  | { readonly pure: Uint8Array }
+  | { readonly object: string };
+
+/** A Move call of a Sui programmable transaction. */
+export interface MoveCall {
+  /** Function called, e.g. `0x2::pay::split`. */
+  readonly target: string;
+  /** Type arguments, e.g. `0x2::sui::SUI`. */
+  readonly typeArguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<MoveCallArg>;
+}
+
+const fromHex = (value: string): Uint8Array => {
+  const hex = value.startsWith("0x") ? value.slice(2) : value;
+  if (!/^[0-9a-fA-F]*$/.test(hex)) {
+    throw new TypeError(`invalid hex string: ${value}`);
+  }
+  const padded = hex.length % 2 === 0 ? hex : `0${hex}`;
+  const bytes = new Uint8Array(padded.length / 2);
+  for (let i = 0; i < bytes.length; i++) {
+    bytes[i] = parseInt(padded.slice(i * 2, i * 2 + 2), 16);
+  }
+  return bytes;
+};
+
+const toBytes = (value: HexStringArg): Uint8Array =>
+  typeof value === "string" ? fromHex(value) : value;
+
+const concatBytes = (parts: ReadonlyArray<Uint8Array>): Uint8Array => {
+  const length = parts.reduce((sum, part) => sum + part.length, 0);
+  const bytes = new Uint8Array(length);
+  let offset = 0;
+  for (const part of parts) {
+    bytes.set(part, offset);
+    offset += part.length;
+  }
+  return bytes;
+};
+
+const uleb128 = (value: number): Uint8Array => {
+  const bytes: number[] = [];
+  let rest = value;
+  do {
+    const byte = rest % 0x80;
+    rest = Math.floor(rest / 0x80);
+    bytes.push(rest === 0 ? byte : byte | 0x80);
+  } while (rest !== 0);
+  return Uint8Array.from(bytes);
+};
+
+const uint = (value: U128, size: number, name: string): Uint8Array => {
+  let rest = BigInt(value);
+  if (rest < BigInt(0) || rest >= BigInt(1) << BigInt(size * 8)) {
+    throw new RangeError(`${value.toString()} is out of range for ${name}`);
+  }
+  const bytes = new Uint8Array(size);
+  for (let i = 0; i < size; i++) {
+    bytes[i] = Number(rest & BigInt(0xff));
+    rest >>= BigInt(8);
+  }
+  return bytes;
+};
+
+const withLength = (bytes: Uint8Array): Uint8Array =>
+  concatBytes([uleb128(bytes.length), bytes]);
+
+const encodeAddress = (value: HexStringArg, length: number): Uint8Array => {
+  const bytes = toBytes(value);
+  if (bytes.length > length) {
+    throw new RangeError(`address is longer than ${length} bytes`);
+  }
+  const address = new Uint8Array(length);
+  address.set(bytes, length - bytes.length);
+  return address;
+};
+
+const encodeString = (value: string): Uint8Array =>
+  withLength(new TextEncoder().encode(value));
+
+const PRIMITIVE_TYPE_TAGS: Readonly<Record<string, number>> = {
+  bool: 0,
+  u8: 1,
+  u64: 2,
+  u128: 3,
+  address: 4,
+  signer: 5,
+  u16: 8,
+  u32: 9,
+  u256: 10,
+};
+
+const encodeTypeTag = (tag: string, length: number): Uint8Array => {
+  const tokens = tag.match(/::|[<>,]|[^\s:<>,]+/g) ?? [];
+  let position = 0;
+  const next = (): string => {
+    const token = tokens[position++];
+    if (token === undefined) {
+      throw new TypeError(`unexpected end of type tag ${tag}`);
+    }
+    return token;
+  };
+  const expect = (expected: string): void => {
+    const token = next();
+    if (token !== expected) {
+      throw new TypeError(`expected \`${expected}\` in type tag ${tag}`);
+    }
+  };
+  const parse = (): Uint8Array => {
+    const token = next();
+    const primitive = PRIMITIVE_TYPE_TAGS[token];
+    if (primitive !== undefined) {
+      return Uint8Array.of(primitive);
+    }
+    if (token === "vector") {
+      expect("<");
+      const inner = parse();
+      expect(">");
+      return concatBytes([Uint8Array.of(6), inner]);
+    }
+    expect("::");
+    const module = next();
+    expect("::");
+    const name = next();
+    const params: Uint8Array[] = [];
+    if (tokens[position] === "<") {
+      position++;
+      params.push(parse());
+      while (tokens[position] === ",") {
+        position++;
+        params.push(parse());
+      }
+      expect(">");
+    }
+    return concatBytes([
+      Uint8Array.of(7),
+      encodeAddress(token, length),
+      encodeString(module),
+      encodeString(name),
+      uleb128(params.length),
+      ...params,
+    ]);
+  };
+  const encoded = parse();
+  if (position !== tokens.length) {
+    throw new TypeError(`unexpected \`${tokens[position]}\` in type tag ${tag}`);
+  }
+  return encoded;
+};
+
+/** BCS encoders for entry function arguments and transaction payloads. */
+export const bcs = {
+  bool: (value: boolean): Uint8Array => Uint8Array.of(value ? 1 : 0),
+  u8: (value: number): Uint8Array => uint(value, 1, "u8"),
+  u64: (value: U64): Uint8Array => uint(value, 8, "u64"),
+  u128: (value: U128): Uint8Array => uint(value, 16, "u128"),
+  /** Encodes an address of `length` bytes, padded with leading zeros. */
+  address: (value: HexStringArg, length: number): Uint8Array =>
+    encodeAddress(value, length),
+  /** Encodes a `vector<u8>`. */
+  bytes: (value: HexStringArg): Uint8Array => withLength(toBytes(value)),
+  /** Encodes a UTF-8 string, e.g. a `0x1::string::String`. */
+  string: (value: string): Uint8Array => encodeString(value),
+  vector: <T>(values: ReadonlyArray<T>, encode: BcsEncoder<T>): Uint8Array =>
+    concatBytes([uleb128(values.length), ...values.map((v) => encode(v))]),
+  /** Encodes an `Option<T>` as a vector of zero or one elements. */
+  option: <T>(value: Option<T>, encode: BcsEncoder<T>): Uint8Array =>
+    value === null || value === undefined
+      ? Uint8Array.of(0)
+      : concatBytes([Uint8Array.of(1), encode(value)]),
+  /** Encodes a struct from its encoded fields. */
+  struct: (fields: ReadonlyArray<Uint8Array>): Uint8Array =>
+    concatBytes(fields),
+  /** Accepts a value which is already encoded, e.g. of a generic type. */
+  encoded: (value: unknown): Uint8Array => {
+    if (!(value instanceof Uint8Array)) {
+      throw new TypeError("expected a BCS-encoded Uint8Array");
+    }
+    return value;
+  },
+  /** Encodes a type tag, e.g. `vector<0x1::coin::Coin<0x1::aptos_coin::AptosCoin>>`. */
+  typeTag: (tag: string, length: number): Uint8Array =>
+    encodeTypeTag(tag, length),
+  /** Encodes a `TransactionPayload` calling an entry function. */
+  entryFunctionPayload: (call: EntryFunctionCall, length: number): Uint8Array =>
+    concatBytes([
+      Uint8Array.of(2),
+      encodeAddress(call.address, length),
+      encodeString(call.module),
+      encodeString(call.function),
+      uleb128(call.typeArgs.length),
+      ...call.typeArgs.map((tag) => encodeTypeTag(tag, length)),
+      uleb128(call.args.length),
+      ...call.args.map(withLength),
+    ]),
+} as const;
+
+/** Decodes a value from a {@link BcsReader}. */
+export type BcsDecoder<T> = (reader: BcsReader) => T;
+
+/** Reads BCS-encoded values from bytes, in order. */
+export class BcsReader {
+  private offset = 0;
+
+  constructor(private readonly input: Uint8Array) {}
+
+  /** True if all the bytes have been read. */
+  get done(): boolean {
+    return this.offset === this.input.length;
+  }
+
+  private read(length: number): Uint8Array {
+    if (this.offset + length > this.input.length) {
+      throw new RangeError("unexpected end of BCS input");
+    }
+    const bytes = this.input.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return bytes;
+  }
+
+  private uint(size: number): bigint {
+    return this.read(size).reduceRight(
+      (value, byte) => (value << BigInt(8)) | BigInt(byte),
+      BigInt(0)
+    );
+  }
+
+  bool(): boolean {
+    const byte = this.u8();
+    if (byte > 1) {
+      throw new TypeError(`invalid BCS boolean ${byte}`);
+    }
+    return byte === 1;
+  }
+
+  u8(): number {
+    return this.read(1)[0];
+  }
+
+  u64(): bigint {
+    return this.uint(8);
+  }
+
+  u128(): bigint {
+    return this.uint(16);
+  }
+
+  /** Reads the ULEB128-encoded length of a vector or index of an enum variant. */
+  uleb128(): number {
+    let value = 0;
+    for (let shift = 1; ; shift *= 0x80) {
+      const byte = this.u8();
+      value += (byte & 0x7f) * shift;
+      if ((byte & 0x80) === 0) {
+        return value;
+      }
+    }
+  }
+
+  /** Reads an address of `length` bytes as a hex string. */
+  address(length: number): string {
+    return toHex(this.read(length));
+  }
+
+  /** Reads a `vector<u8>` as a hex string. */
+  bytes(): string {
+    return toHex(this.read(this.uleb128()));
+  }
+
+  /** Reads a UTF-8 string, e.g. a `0x1::string::String`. */
+  string(): string {
+    return new TextDecoder("utf-8", { fatal: true }).decode(
+      this.read(this.uleb128())
+    );
+  }
+
+  vector<T>(decode: BcsDecoder<T>): T[] {
+    const length = this.uleb128();
+    const values: T[] = [];
+    for (let i = 0; i < length; i++) {
+      values.push(decode(this));
+    }
+    return values;
+  }
+
+  /** Reads the index of an enum variant, then the variant with the decoder at that index. */
+  variant<T>(variants: ReadonlyArray<BcsDecoder<T>>): T {
+    const index = this.uleb128();
+    const decode = variants[index];
+    if (decode === undefined) {
+      throw new RangeError(`unknown enum variant index ${index}`);
+    }
+    return decode(this);
+  }
+}
+
+/** Decodes BCS-encoded bytes, failing if any bytes are left over. */
+export const decodeBcs = <T>(bytes: HexStringArg, decode: BcsDecoder<T>): T => {
+  const reader = new BcsReader(toBytes(bytes));
+  const value = decode(reader);
+  if (!reader.done) {
+    throw new RangeError("unexpected trailing bytes in BCS input");
+  }
+  return value;
+};
+
+/** Definition of a Move module. */
+export interface MoveModuleDefinition<A extends string, M extends string> {
+  readonly ADDRESS: A;
+  readonly FULL_NAME: `${A}::${M}`;
+  readonly NAME: M;
+  readonly errorCodes: Readonly<Record<string, unknown>>;
+  readonly functions: Readonly<Record<string, unknown>>;
+  readonly resources: Readonly<Record<string, string>>;
+  readonly structs: Readonly<Record<string, string>>;
+}
+
+/** A JSON script function payload, as built by the generated `entry` builders. */
+export interface ScriptFunctionPayload {
+  readonly type: "script_function_payload";
+  readonly function: string;
+  readonly type_arguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<unknown>;
+}
+
+/** A JSON payload calling a view function, as built by generated clients. */
+export interface ViewFunctionPayload {
+  readonly function: string;
+  readonly type_arguments: ReadonlyArray<string>;
+  readonly arguments: ReadonlyArray<unknown>;
+}
+
+/** Signs and submits the transactions of generated clients. */
+export interface MoveSigner {
+  /** Signs and submits a transaction calling an entry function, resolving to its hash. */
+  submit(payload: ScriptFunctionPayload): Promise<string>;
+}
+
+/** Reads the state of the chain for generated clients. */
+export interface MoveProvider {
+  /** Reads the resource of type `type` stored at `address`, resolving to `null` if there is none. */
+  getResource(address: string, type: string): Promise<unknown>;
+  /** Calls a view function, resolving to its JSON-encoded return values. */
+  view(payload: ViewFunctionPayload): Promise<ReadonlyArray<unknown>>;
+}
+
+/** Formats the type of a struct with its type arguments, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`. */
+export const structTag = (
+  name: string,
+  typeArgs: ReadonlyArray<string>
+): string =>
+  typeArgs.length === 0 ? name : `${name}<${typeArgs.join(", ")}>`;
+
+/** An abort of a Move transaction. */
+export interface MoveAbort {
+  /** ID of the aborting module, e.g. `0x1::coin`. */
+  readonly module: string;
+  /** The abort code. */
+  readonly code: number;
+  /** Category of the code in the encoding of `std::error`, i.e. its third byte. */
+  readonly category: number;
+  /** Reason of the code in the encoding of `std::error`, i.e. its two lowest bytes. */
+  readonly reason: number;
+}
+
+/** Name and documentation of a Move error constant. */
+export interface MoveErrorInfo {
+  readonly name: string;
+  readonly doc: string | null;
+}
+
+/** An abort of a Move transaction decoded into the error constant which caused it. */
+export interface DecodedAbort extends MoveAbort, MoveErrorInfo {}
+
+/** Errors of a package, keyed by module ID and error code. */
+export type ErrorMap = Readonly<
+  Record<string, Readonly<Record<string, MoveErrorInfo>>>
+>;
+
+const normalizeAddress = (address: string): string =>
+  `0x${address.replace(/^0x/i, "").replace(/^0+/, "").toLowerCase() || "0"}`;
+
+const ABORT_PATTERNS: ReadonlyArray<{
+  pattern: RegExp;
+  address: number;
+  name: number;
+  code: number;
+}> = [
+  // Aptos: `Move abort in 0x1::coin: EINSUFFICIENT_BALANCE(0x10006): ...`
+  {
+    pattern:
+      /Move abort in (0x[0-9a-f]+)::(\w+): (?:\w+\()?(0x[0-9a-f]+|\d+)/i,
+    address: 1,
+    name: 2,
+    code: 3,
+  },
+  // Aptos and Diem: `ABORTED { code: 65542, location: 0x1::coin }`
+  {
+    pattern: /code: (0x[0-9a-f]+|\d+), location: (0x[0-9a-f]+)::(\w+)/i,
+    address: 2,
+    name: 3,
+    code: 1,
+  },
+  // Sui: `MoveAbort(MoveLocation { module: ModuleId { address: 0000...0002, name: Identifier("coin") }, ... }, 65542)`
+  {
+    pattern:
+      /MoveAbort\(MoveLocation \{ module: ModuleId \{ address: (?:0x)?([0-9a-f]+), name: Identifier\("(\w+)"\) \}.*\}, (\d+)\)/i,
+    address: 1,
+    name: 2,
+    code: 3,
+  },
+];
+
+/** Extracts the aborting module and abort code of a VM status, or `null` if it is not an abort. */
+export const parseVmStatus = (vmStatus: string): MoveAbort | null => {
+  for (const { pattern, address, name, code } of ABORT_PATTERNS) {
+    const match = pattern.exec(vmStatus);
+    if (match) {
+      const value = Number(match[code]);
+      return {
+        module: `${normalizeAddress(match[address])}::${match[name]}`,
+        code: value,
+        category: Math.floor(value / 0x10000) & 0xff,
+        reason: value % 0x10000,
+      };
+    }
+  }
+  return null;
+};
+
+/**
+ * Decodes the abort of a VM status into the error of `errmap` which caused it.
+ *
+ * Codes are looked up as is, then as compared by {@link matchesError}, so that an error matches
+ * whether or not either its code or the abort code carries a `std::error` category.
+ */
+export const decodeAbort = (
+  errmap: ErrorMap,
+  vmStatus: string
+): DecodedAbort | null => {
+  const abort = parseVmStatus(vmStatus);
+  if (!abort) {
+    return null;
+  }
+  const error = lookupError(errmap[abort.module], abort.code);
+  return error ? { ...abort, name: error.name, doc: error.doc } : null;
+};
+
+/**
+ * Whether the abort code `code` is the error `error`.
+ *
+ * Codes are compared by their `std::error` reason, ignoring their category.
+ */
+export const matchesError = (
+  code: number,
+  error: { readonly code: number; readonly reason: number }
+): boolean =>
+  code === error.code || (code < 0x1000000 && code % 0x10000 === error.reason);
+
+/** Reason of an error code, or the code itself if it has no known `std::error` category. */
+const errorReason = (code: number): number =>
+  Math.floor(code / 0x10000) <= 0xd ? code % 0x10000 : code;
+
+/**
+ * Looks up the entry of `errors`, keyed by error code, for the abort code `code`: by the code
+ * itself, or else by the first error code which {@link matchesError} it.
+ */
+const lookupError = <T>(
+  errors: Readonly<Record<string, T>> | undefined,
+  code: number
+): T | undefined => {
+  if (!errors) {
+    return undefined;
+  }
+  const key = Object.keys(errors).find((key) =>
+    matchesError(code, { code: Number(key), reason: errorReason(Number(key)) })
+  );
+  return errors[code] ?? (key === undefined ? undefined : errors[key]);
+};
+
+/** Error of an aborted Move transaction, extended by the generated class of each error constant. */
+export abstract class MoveAbortError extends Error {
+  /** ID of the module declaring the error. */
+  abstract readonly module: string;
+  /** Code of the error constant. */
+  abstract readonly code: number;
+  /** Name of the error constant. */
+  abstract readonly errorName: string;
+
+  constructor(
+    /** The abort, whose code may carry a `std::error` category the error constant does not. */
+    readonly abort: MoveAbort
+  ) {
+    super(`Move abort in ${abort.module} with code ${abort.code}`);
+    this.name = new.target.name;
+  }
+}
+
+/** Class of an error constant. */
+export type MoveAbortErrorClass = new (abort: MoveAbort) => MoveAbortError;
+
+/** Error classes of a package, keyed by module ID and error code. */
+export type ErrorClassMap = Readonly<
+  Record<string, Readonly<Record<string, MoveAbortErrorClass>>>
+>;
+
+/**
+ * Decodes the abort of a VM status into an instance of the class of `classes` which caused it.
+ *
+ * Codes are looked up as in {@link decodeAbort}.
+ */
+export const abortError = (
+  classes: ErrorClassMap,
+  vmStatus: string
+): MoveAbortError | null => {
+  const abort = parseVmStatus(vmStatus);
+  if (!abort) {
+    return null;
+  }
+  const ErrorClass = lookupError(classes[abort.module], abort.code);
+  return ErrorClass ? new ErrorClass(abort) : null;
+};
+
+/** Whether `error` is an instance of one of the error classes of `classes`. */
+export const isErrorOf = (
+  classes: ErrorClassMap,
+  error: unknown
+): error is MoveAbortError =>
+  error instanceof MoveAbortError &&
+  Object.values(classes[error.module] ?? {}).some(
+    (ErrorClass) => error instanceof ErrorClass
+  );
@@ -0,0 +1,952 @@
+//! Renders [crate::ast] nodes as source code for an [EmitTarget].
+//!
+//! Nodes are first converted into a [Doc] describing the possible line breaks, which is then
+//! laid out in the style of prettier: a group is printed on a single line if it fits, and
+//! broken at all of its lines otherwise.
+
+use crate::{
+    ast::{
+        Class, Const, Export, ExportKind, Expr, Function, Import, ImportKind, Interface, Module,
+        ObjectMember, Param, Property, Stmt, TupleElement, Type, TypeAlias, TypeParam,
+    },
+    emit::{EmitTarget, ModuleFormat},
+    format::{gen_doc_string, FormatOptions, TrailingCommas},
+};
+use anyhow::*;
+use serde_json::Value;
+
+/// A document laid out by [render].
+#[derive(Clone, Debug)]
+enum Doc {
+    Text(String),
+    /// A space, or a newline if the enclosing group is broken.
+    Line,
+    /// Nothing, or a newline if the enclosing group is broken.
+    SoftLine,
+    /// A newline, which breaks all enclosing groups.
+    HardLine,
+    Concat(Vec<Doc>),
+    /// Contents printed on a single line if they fit.
+    Group(Box<Doc>),
+    Indent(Box<Doc>),
+    /// The first document if the enclosing group is broken, the second otherwise.
+    IfBreak(Box<Doc>, Box<Doc>),
+}
+
+fn text(s: impl Into<String>) -> Doc {
+    Doc::Text(s.into())
+}
+
+fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+fn indent(doc: Doc) -> Doc {
+    Doc::Indent(Box::new(doc))
+}
+
+fn if_break(broken: Doc, flat: Doc) -> Doc {
+    Doc::IfBreak(Box::new(broken), Box::new(flat))
+}
+
+/// Joins documents with a separator.
+fn join(docs: Vec<Doc>, separator: Vec<Doc>) -> Doc {
+    let mut result = vec![];
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i > 0 {
+            result.extend(separator.iter().cloned());
+        }
+        result.push(doc);
+    }
+    concat(result)
+}
+
+/// Surrounds a list of items with delimiters, breaking after each item if they do not fit.
+///
+/// `padded` lists are separated from their delimiters by a space when printed on a single
+/// line, and `trailing` lists end with a separator when broken.
+fn delimited(
+    open: &str,
+    items: Vec<Doc>,
+    separator: &str,
+    close: &str,
+    padded: bool,
+    trailing: bool,
+) -> Doc {
+    if items.is_empty() {
+        return text(format!("{}{}", open, close));
+    }
+    let line = if padded { Doc::Line } else { Doc::SoftLine };
+    group(concat(vec![
+        text(open),
+        indent(concat(vec![
+            line.clone(),
+            join(items, vec![text(separator), Doc::Line]),
+            if trailing {
+                if_break(text(separator), text(""))
+            } else {
+                text("")
+            },
+        ])),
+        line,
+        text(close),
+    ]))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Returns true if the command fits into `width` columns, followed by the remaining commands
+/// up to their next line break.
+fn fits(next: (usize, Mode, &Doc), rest: &[(usize, Mode, &Doc)], width: usize) -> bool {
+    let mut remaining = width as isize;
+    let mut stack = vec![next];
+    let mut rest_idx = rest.len();
+    loop {
+        let (ind, mode, doc) = match stack.pop() {
+            Some(cmd) => cmd,
+            None if rest_idx == 0 => return true,
+            None => {
+                rest_idx -= 1;
+                rest[rest_idx]
+            }
+        };
+        match doc {
+            Doc::Text(s) => {
+                remaining -= s.chars().count() as isize;
+                if remaining < 0 {
+                    return false;
+                }
+            }
+            Doc::Line | Doc::SoftLine if mode == Mode::Break => return true,
+            Doc::Line => remaining -= 1,
+            Doc::SoftLine => {}
+            Doc::HardLine => return mode == Mode::Break,
+            Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|d| (ind, mode, d))),
+            Doc::Group(doc) | Doc::Indent(doc) => stack.push((ind, mode, doc)),
+            Doc::IfBreak(broken, flat) => {
+                stack.push((ind, mode, if mode == Mode::Break { broken } else { flat }))
+            }
+        }
+    }
+}
+
+/// Lays out a document.
+fn render(doc: &Doc, options: &FormatOptions) -> String {
+    let indent = " ".repeat(options.indent_width);
+    let mut out = String::new();
+    let mut column = 0;
+    let mut pending_indent: Option<usize> = None;
+    let mut cmds = vec![(0, Mode::Break, doc)];
+    while let Some((ind, mode, doc)) = cmds.pop() {
+        match doc {
+            Doc::Text(s) => {
+                if s.is_empty() {
+                    continue;
+                }
+                if let Some(level) = pending_indent.take() {
+                    out.push_str(&indent.repeat(level));
+                    column = indent.len() * level;
+                }
+                out.push_str(s);
+                column += s.chars().count();
+            }
+            Doc::Line if mode == Mode::Flat => {
+                out.push(' ');
+                column += 1;
+            }
+            Doc::SoftLine if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine => {
+                out.push('\n');
+                column = 0;
+                pending_indent = Some(ind);
+            }
+            Doc::Concat(docs) => cmds.extend(docs.iter().rev().map(|d| (ind, mode, d))),
+            Doc::Indent(doc) => cmds.push((ind + 1, mode, doc)),
+            Doc::Group(doc) => {
+                let start = column + pending_indent.map_or(0, |level| indent.len() * level);
+                let mode = if mode == Mode::Flat
+                    || fits(
+                        (ind, Mode::Flat, doc),
+                        &cmds,
+                        options.line_width.saturating_sub(start),
+                    ) {
+                    Mode::Flat
+                } else {
+                    Mode::Break
+                };
+                cmds.push((ind, mode, doc));
+            }
+            Doc::IfBreak(broken, flat) => {
+                cmds.push((ind, mode, if mode == Mode::Break { broken } else { flat }))
+            }
+        }
+    }
+    out
+}
+
+/// Returns true if the last argument of a call is a callback and all others are identifiers or
+/// member accesses, so the callback can start on the line of the call.
+fn is_hugged_callback(args: &[Expr]) -> bool {
+    fn is_simple(expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(_) => true,
+            Expr::Member(object, _) => is_simple(object),
+            _ => false,
+        }
+    }
+    match args.split_last() {
+        Some((Expr::Arrow { .. }, rest)) => rest.iter().all(is_simple),
+        _ => false,
+    }
+}
+
+/// Renders a doc comment.
+fn doc_comment(docs: &str) -> Doc {
+    concat(
+        gen_doc_string(docs)
+            .lines()
+            .map(|line| concat(vec![text(line), Doc::HardLine]))
+            .collect(),
+    )
+}
+
+fn with_docs(docs: &Option<String>, doc: Doc) -> Doc {
+    match docs {
+        Some(docs) => concat(vec![doc_comment(docs), doc]),
+        None => doc,
+    }
+}
+
+/// Returns true if `name` can be used as a property name without quotes.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Kinds of lists, which differ in where trailing commas are allowed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    /// Object and array literals.
+    Literal,
+    /// Parameters, arguments, type parameters, tuples and import specifiers.
+    Other,
+}
+
+/// Renders nodes for an [EmitTarget].
+pub struct Printer {
+    target: EmitTarget,
+    options: FormatOptions,
+}
+
+impl Printer {
+    pub fn new(target: EmitTarget) -> Self {
+        Printer {
+            target,
+            options: FormatOptions::default(),
+        }
+    }
+
+    /// Sets the formatting of the rendered code.
+    pub fn with_options(self, options: FormatOptions) -> Self {
+        Printer { options, ..self }
+    }
+
+    fn string_literal(&self, value: &str) -> String {
+        self.options.string_literal(value)
+    }
+
+    fn property_name(&self, name: &str) -> String {
+        if is_identifier_name(name) {
+            name.to_string()
+        } else {
+            self.string_literal(name)
+        }
+    }
+
+    /// Returns true if broken lists of this kind end with a comma.
+    fn trailing_comma(&self, kind: ListKind) -> bool {
+        match self.options.trailing_commas {
+            TrailingCommas::All => true,
+            TrailingCommas::Es5 => kind == ListKind::Literal,
+            TrailingCommas::None => false,
+        }
+    }
+
+    /// Returns true if types are printed in expressions.
+    fn typed(&self) -> bool {
+        self.target == EmitTarget::TypeScript
+    }
+
+    fn is_commonjs(&self) -> bool {
+        self.target == EmitTarget::JavaScript(ModuleFormat::CommonJs)
+    }
+
+    /// Renders a file.
+    pub fn print_module(&self, module: &Module) -> Result<String> {
+        let mut parts = vec![];
+        if let Some(docs) = &module.docs {
+            parts.push(doc_comment(&format!("{}\n\n@module", docs)));
+        }
+
+        let mut previous: Option<&Stmt> = None;
+        for item in module.items.iter() {
+            let doc = match self.stmt(item)? {
+                Some(doc) => doc,
+                None => continue,
+            };
+            let adjacent = matches!(
+                (previous, item),
+                (Some(Stmt::Import(_)), Stmt::Import(_)) | (Some(Stmt::Export(_)), Stmt::Export(_))
+            );
+            if previous.is_some() {
+                parts.push(Doc::HardLine);
+            }
+            if previous.is_some() && !adjacent || previous.is_none() && module.docs.is_some() {
+                parts.push(Doc::HardLine);
+            }
+            parts.push(doc);
+            previous = Some(item);
+        }
+        // Doc comments already end with a newline.
+        if previous.is_some() || module.docs.is_none() {
+            parts.push(Doc::HardLine);
+        }
+        Ok(render(&concat(parts), &self.options))
+    }
+
+    /// Renders a statement, or returns `None` if it is omitted from the target.
+    fn stmt(&self, stmt: &Stmt) -> Result<Option<Doc>> {
+        Ok(match stmt {
+            Stmt::Import(import) => self.import(import),
+            Stmt::Export(export) => Some(self.export(export)),
+            Stmt::Interface(_) | Stmt::TypeAlias(_) if !self.target.emits_types() => None,
+            Stmt::Interface(interface) => Some(self.interface(interface)),
+            Stmt::TypeAlias(alias) => Some(self.type_alias(alias)),
+            Stmt::Const(decl) => Some(self.const_decl(decl)?),
+            Stmt::Function(function) => Some(self.function(function)?),
+            Stmt::Class(class) => Some(self.class(class)?),
+            Stmt::Return(expr) => Some(concat(vec![text("return "), self.expr(expr), text(";")])),
+            Stmt::Expr(expr) => Some(concat(vec![self.expr(expr), text(";")])),
+        })
+    }
+
+    fn import(&self, import: &Import) -> Option<Doc> {
+        let path = self.string_literal(&import.path);
+        match (&import.kind, self.target) {
+            (_, EmitTarget::JavaScript(_)) if import.type_only => None,
+            (ImportKind::Namespace(name), EmitTarget::JavaScript(ModuleFormat::CommonJs)) => {
+                Some(text(format!("const {} = require({});", name, path)))
+            }
+            (ImportKind::Named(names), EmitTarget::JavaScript(ModuleFormat::CommonJs)) => {
+                Some(concat(vec![
+                    text("const "),
+                    delimited(
+                        "{",
+                        names
+                            .iter()
+                            .map(|(name, alias)| match alias {
+                                Some(alias) => text(format!("{}: {}", name, alias)),
+                                None => text(name),
+                            })
+                            .collect(),
+                        ",",
+                        "}",
+                        true,
+                        self.trailing_comma(ListKind::Other),
+                    ),
+                    text(format!(" = require({});", path)),
+                ]))
+            }
+            (ImportKind::Namespace(name), _) => {
+                Some(text(format!("import * as {} from {};", name, path)))
+            }
+            (ImportKind::Named(names), _) => Some(concat(vec![
+                text("import "),
+                delimited(
+                    "{",
+                    names
+                        .iter()
+                        .map(|(name, alias)| match alias {
+                            Some(alias) => text(format!("{} as {}", name, alias)),
+                            None => text(name),
+                        })
+                        .collect(),
+                    ",",
+                    "}",
+                    true,
+                    self.trailing_comma(ListKind::Other),
+                ),
+                text(format!(" from {};", path)),
+            ])),
+        }
+    }
+
+    fn export(&self, export: &Export) -> Doc {
+        let path = self.string_literal(&export.path);
+        text(match (&export.kind, self.is_commonjs()) {
+            (ExportKind::Namespace(name), true) => {
+                format!("exports.{} = require({});", name, path)
+            }
+            (ExportKind::Named(name, alias), true) => format!(
+                "exports.{} = require({}).{};",
+                alias.as_ref().unwrap_or(name),
+                path,
+                name
+            ),
+            (ExportKind::Namespace(name), false) => format!("export * as {} from {};", name, path),
+            (ExportKind::Named(name, Some(alias)), false) => {
+                format!("export {{ {} as {} }} from {};", name, alias, path)
+            }
+            (ExportKind::Named(name, None), false) => {
+                format!("export {{ {} }} from {};", name, path)
+            }
+        })
+    }
+
+    /// Prefix of an exported declaration. CommonJS exports are assigned after the declaration.
+    fn export_keyword(&self, export: bool) -> &'static str {
+        if export && !self.is_commonjs() {
+            "export "
+        } else {
+            ""
+        }
+    }
+
+    /// Assigns a declaration to the CommonJS exports.
+    fn commonjs_export(&self, export: bool, name: &str) -> Doc {
+        if export && self.is_commonjs() {
+            concat(vec![
+                Doc::HardLine,
+                text(format!("exports.{} = {};", name, name)),
+            ])
+        } else {
+            text("")
+        }
+    }
+
+    fn type_params(&self, params: &[TypeParam]) -> Doc {
+        if params.is_empty() {
+            return text("");
+        }
+        delimited(
+            "<",
+            params
+                .iter()
+                .map(|p| match &p.default {
+                    Some(default) => concat(vec![text(format!("{} = ", p.name)), self.ty(default)]),
+                    None => text(&p.name),
+                })
+                .collect(),
+            ",",
+            ">",
+            false,
+            self.trailing_comma(ListKind::Other),
+        )
+    }
+
+    fn interface(&self, interface: &Interface) -> Doc {
+        let body = if interface.members.is_empty() {
+            text("{}")
+        } else {
+            concat(vec![
+                text("{"),
+                indent(concat(
+                    interface
+                        .members
+                        .iter()
+                        .map(|m| concat(vec![Doc::HardLine, self.property(m), text(";")]))
+                        .collect(),
+                )),
+                Doc::HardLine,
+                text("}"),
+            ])
+        };
+        with_docs(
+            &interface.docs,
+            concat(vec![
+                text(format!(
+                    "{}interface {}",
+                    self.export_keyword(interface.export),
+                    interface.name
+                )),
+                self.type_params(&interface.type_params),
+                text(" "),
+                body,
+            ]),
+        )
+    }
+
+    fn type_alias(&self, alias: &TypeAlias) -> Doc {
+        with_docs(
+            &alias.docs,
+            concat(vec![
+                text(format!(
+                    "{}type {}",
+                    self.export_keyword(alias.export),
+                    alias.name
+                )),
+                self.type_params(&alias.type_params),
+                text(" = "),
+                self.ty(&alias.ty),
+                text(";"),
+            ]),
+        )
+    }
+
+    fn const_decl(&self, decl: &Const) -> Result<Doc> {
+        let doc = match self.target {
+            EmitTarget::Declarations(_) => {
+                let ty = match &decl.ty {
+                    Some(ty) => ty.clone(),
+                    None => decl
+                        .value
+                        .declared_type()
+                        .with_context(|| format!("declaring const {}", decl.name))?,
+                };
+                concat(vec![
+                    text(format!(
+                        "{}declare const {}: ",
+                        self.export_keyword(decl.export),
+                        decl.name
+                    )),
+                    self.ty(&ty),
+                    text(";"),
+                ])
+            }
+            _ => concat(vec![
+                text(format!(
+                    "{}const {}",
+                    self.export_keyword(decl.export),
+                    decl.name
+                )),
+                match &decl.ty {
+                    Some(ty) if self.typed() => concat(vec![text(": "), self.ty(ty)]),
+                    _ => text(""),
+                },
+                text(" = "),
+                self.expr(&decl.value),
+                text(";"),
+                self.commonjs_export(decl.export, &decl.name),
+            ]),
+        };
+        Ok(with_docs(&decl.docs, doc))
+    }
+
+    fn function(&self, function: &Function) -> Result<Doc> {
+        let signature = concat(vec![
+            self.params(&function.params),
+            match &function.return_type {
+                Some(ty) if self.target.emits_types() => concat(vec![text(": "), self.ty(ty)]),
+                _ => text(""),
+            },
+        ]);
+        let doc = match self.target {
+            EmitTarget::Declarations(_) => concat(vec![
+                text(format!(
+                    "{}declare function {}",
+                    self.export_keyword(function.export),
+                    function.name
+                )),
+                signature,
+                text(";"),
+            ]),
+            _ => {
+                let body = function
+                    .body
+                    .iter()
+                    .map(|s| self.stmt(s))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .map(|s| concat(vec![Doc::HardLine, s]))
+                    .collect::<Vec<_>>();
+                concat(vec![
+                    text(format!(
+                        "{}function {}",
+                        self.export_keyword(function.export),
+                        function.name
+                    )),
+                    signature,
+                    text(" {"),
+                    indent(concat(body)),
+                    Doc::HardLine,
+                    text("}"),
+                    self.commonjs_export(function.export, &function.name),
+                ])
+            }
+        };
+        Ok(with_docs(&function.docs, doc))
+    }
+
+    fn class(&self, class: &Class) -> Result<Doc> {
+        let declarations = matches!(self.target, EmitTarget::Declarations(_));
+        let mut members = vec![];
+        if class.fields.is_empty() {
+            // The constructor is inherited.
+        } else if declarations {
+            let mut lines = class
+                .fields
+                .iter()
+                .map(|field| {
+                    let ty = field.ty.clone().unwrap_or_else(|| Type::named("unknown"));
+                    concat(vec![
+                        text(format!("readonly {}: ", field.pattern)),
+                        self.ty(&ty),
+                        text(";"),
+                    ])
+                })
+                .collect::<Vec<_>>();
+            lines.push(concat(vec![
+                text("constructor"),
+                self.params(&class.fields),
+                text(";"),
+            ]));
+            members.push(join(lines, vec![Doc::HardLine]));
+        } else if self.typed() {
+            // Parameter properties declare and assign the fields.
+            let params = class
+                .fields
+                .iter()
+                .map(|field| Param {
+                    pattern: format!("readonly {}", field.pattern),
+                    ty: field.ty.clone(),
+                })
+                .collect::<Vec<_>>();
+            members.push(concat(vec![
+                text("constructor"),
+                self.params(&params),
+                text(" {}"),
+            ]));
+        } else {
+            members.push(concat(vec![
+                text("constructor"),
+                self.params(&class.fields),
+                text(" {"),
+                indent(concat(
+                    class
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            concat(vec![
+                                Doc::HardLine,
+                                text(format!("this.{} = {};", field.pattern, field.pattern)),
+                            ])
+                        })
+                        .collect(),
+                )),
+                Doc::HardLine,
+                text("}"),
+            ]));
+        }
+        for property in class.properties.iter() {
+            let name = self.property_name(&property.name);
+            let doc = if declarations {
+                let ty = property
+                    .value
+                    .declared_type()
+                    .with_context(|| format!("declaring property {}", property.name))?;
+                concat(vec![
+                    text(format!("readonly {}: ", name)),
+                    self.ty(&ty),
+                    text(";"),
+                ])
+            } else {
+                concat(vec![
+                    text(format!(
+                        "{}{} = ",
+                        if self.typed() { "readonly " } else { "" },
+                        name
+                    )),
+                    self.expr(&property.value),
+                    text(";"),
+                ])
+            };
+            members.push(with_docs(&property.docs, doc));
+        }
+
+        let extends = match &class.extends {
+            Some(base) => concat(vec![text(" extends "), self.expr(base)]),
+            None => text(""),
+        };
+        let body = if members.is_empty() {
+            text("}")
+        } else {
+            concat(vec![
+                indent(concat(vec![
+                    Doc::HardLine,
+                    join(members, vec![Doc::HardLine, Doc::HardLine]),
+                ])),
+                Doc::HardLine,
+                text("}"),
+            ])
+        };
+        let doc = concat(vec![
+            text(format!(
+                "{}{}class {}",
+                self.export_keyword(class.export),
+                if declarations { "declare " } else { "" },
+                class.name
+            )),
+            extends,
+            text(" {"),
+            body,
+            self.commonjs_export(class.export, &class.name),
+        ]);
+        Ok(with_docs(&class.docs, doc))
+    }
+
+    fn params(&self, params: &[Param]) -> Doc {
+        delimited(
+            "(",
+            params
+                .iter()
+                .map(|p| match &p.ty {
+                    Some(ty) if self.target.emits_types() => {
+                        concat(vec![text(format!("{}: ", p.pattern)), self.ty(ty)])
+                    }
+                    _ => text(&p.pattern),
+                })
+                .collect(),
+            ",",
+            ")",
+            false,
+            self.trailing_comma(ListKind::Other),
+        )
+    }
+
+    /// Renders a property of an object type, without its separator.
+    fn property(&self, property: &Property) -> Doc {
+        with_docs(
+            &property.docs,
+            concat(vec![
+                text(format!(
+                    "{}{}{}: ",
+                    if property.readonly { "readonly " } else { "" },
+                    self.property_name(&property.name),
+                    if property.optional { "?" } else { "" }
+                )),
+                self.ty(&property.ty),
+            ]),
+        )
+    }
+
+    fn ty(&self, ty: &Type) -> Doc {
+        match ty {
+            Type::Ref(name, args) if args.is_empty() => text(name),
+            Type::Ref(name, args) => concat(vec![
+                text(name),
+                delimited(
+                    "<",
+                    args.iter().map(|a| self.ty(a)).collect(),
+                    ",",
+                    ">",
+                    false,
+                    self.trailing_comma(ListKind::Other),
+                ),
+            ]),
+            Type::Object(props) => delimited(
+                "{",
+                props.iter().map(|p| self.property(p)).collect(),
+                ";",
+                "}",
+                true,
+                true,
+            ),
+            Type::Tuple { readonly, elements } => concat(vec![
+                text(if *readonly { "readonly " } else { "" }),
+                delimited(
+                    "[",
+                    elements.iter().map(|e| self.tuple_element(e)).collect(),
+                    ",",
+                    "]",
+                    false,
+                    self.trailing_comma(ListKind::Other),
+                ),
+            ]),
+            Type::Literal(value) => self.literal(value),
+            Type::Typeof(name) => text(format!("typeof {}", name)),
+            Type::Function(params, ret) => {
+                concat(vec![self.params(params), text(" => "), self.ty(ret)])
+            }
+            Type::Union(types) => join(
+                types.iter().map(|t| self.ty(t)).collect(),
+                vec![text(" | ")],
+            ),
+            Type::Predicate(param, ty) => concat(vec![text(format!("{} is ", param)), self.ty(ty)]),
+        }
+    }
+
+    fn tuple_element(&self, element: &TupleElement) -> Doc {
+        match &element.label {
+            Some(label) => concat(vec![text(format!("{}: ", label)), self.ty(&element.ty)]),
+            None => self.ty(&element.ty),
+        }
+    }
+
+    fn literal(&self, value: &Value) -> Doc {
+        match value {
+            Value::String(s) => text(self.string_literal(s)),
+            Value::Array(items) => delimited(
+                "[",
+                items.iter().map(|v| self.literal(v)).collect(),
+                ",",
+                "]",
+                false,
+                self.trailing_comma(ListKind::Literal),
+            ),
+            Value::Object(fields) => delimited(
+                "{",
+                fields
+                    .iter()
+                    .map(|(k, v)| {
+                        concat(vec![
+                            text(format!("{}: ", self.property_name(k))),
+                            self.literal(v),
+                        ])
+                    })
+                    .collect(),
+                ",",
+                "}",
+                true,
+                self.trailing_comma(ListKind::Literal),
+            ),
+            other => text(other.to_string()),
+        }
+    }
+
+    /// Renders an arrow function. The body of a `hugged` callback is followed by a line break
+    /// if it does not fit, so the closing parenthesis of the call starts a new line.
+    fn arrow(&self, arrow: &Expr, hugged: bool) -> Doc {
+        let (params, return_type, body) = match arrow {
+            Expr::Arrow {
+                params,
+                return_type,
+                body,
+            } => (params, return_type, body),
+            other => return self.expr(other),
+        };
+        concat(vec![
+            self.params(params),
+            match return_type {
+                Some(ty) if self.typed() => concat(vec![text(": "), self.ty(ty)]),
+                _ => text(""),
+            },
+            match body.as_ref() {
+                Expr::Object(_) => concat(vec![text(" => ("), self.expr(body), text(")")]),
+                body => concat(vec![
+                    text(" =>"),
+                    group(concat(vec![
+                        indent(concat(vec![Doc::Line, self.expr(body)])),
+                        if hugged { Doc::SoftLine } else { text("") },
+                    ])),
+                ]),
+            },
+        ])
+    }
+
+    fn expr(&self, expr: &Expr) -> Doc {
+        match expr {
+            Expr::Ident(name) => text(name),
+            Expr::Literal(value) => self.literal(value),
+            Expr::BigInt(digits) => text(format!("{}n", digits)),
+            Expr::Array(items) => delimited(
+                "[",
+                items.iter().map(|e| self.expr(e)).collect(),
+                ",",
+                "]",
+                false,
+                self.trailing_comma(ListKind::Literal),
+            ),
+            Expr::Object(members) => delimited(
+                "{",
+                members
+                    .iter()
+                    .map(|m| match m {
+                        ObjectMember::Property(key, value) => concat(vec![
+                            text(format!("{}: ", self.property_name(key))),
+                            self.expr(value),
+                        ]),
+                        ObjectMember::Shorthand(key) => text(key),
+                    })
+                    .collect(),
+                ",",
+                "}",
+                true,
+                self.trailing_comma(ListKind::Literal),
+            ),
+            Expr::Member(object, property) => {
+                concat(vec![self.expr(object), text(format!(".{}", property))])
+            }
+            // A trailing callback after simple arguments is kept on the line of the call, e.g.
+            // `xs.map((x) =>`.
+            Expr::Call(callee, args) if is_hugged_callback(args) => {
+                let (callback, rest) = args.split_last().unwrap();
+                let mut docs = vec![self.expr(callee), text("(")];
+                for arg in rest {
+                    docs.push(self.expr(arg));
+                    docs.push(text(", "));
+                }
+                docs.push(self.arrow(callback, true));
+                docs.push(text(")"));
+                concat(docs)
+            }
+            // A sole array or object literal argument breaks inside its own brackets, e.g.
+            // `reader.variant([`.
+            Expr::Call(callee, args)
+                if matches!(args.as_slice(), [Expr::Array(_) | Expr::Object(_)]) =>
+            {
+                concat(vec![
+                    self.expr(callee),
+                    text("("),
+                    self.expr(&args[0]),
+                    text(")"),
+                ])
+            }
+            Expr::Call(callee, args) => concat(vec![
+                self.expr(callee),
+                delimited(
+                    "(",
+                    args.iter().map(|a| self.expr(a)).collect(),
+                    ",",
+                    ")",
+                    false,
+                    self.trailing_comma(ListKind::Other),
+                ),
+            ]),
+            Expr::Arrow { .. } => self.arrow(expr, false),
+            Expr::As(inner, ty) if self.typed() => {
+                concat(vec![self.expr(inner), text(" as "), self.ty(ty)])
+            }
+            Expr::AsConst(inner) if self.typed() => {
+                concat(vec![self.expr(inner), text(" as const")])
+            }
+            Expr::As(inner, _) | Expr::AsConst(inner) => self.expr(inner),
+            Expr::Binary(left, operator, right) => concat(vec![
+                self.expr(left),
+                text(format!(" {} ", operator)),
+                self.expr(right),
+            ]),
+            // The branches move to their own lines if the expression does not fit.
+            Expr::Conditional(test, consequent, alternate) => group(concat(vec![
+                self.expr(test),
+                indent(concat(vec![
+                    Doc::Line,
+                    text("? "),
+                    self.expr(consequent),
+                    Doc::Line,
+                    text(": "),
+                    self.expr(alternate),
+                ])),
+            ])),
+        }
+    }
+}
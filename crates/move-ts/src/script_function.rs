@@ -1,138 +1,159 @@
 use anyhow::*;
-use heck::ToPascalCase;
 use move_idl::{IDLArgument, IDLModule, IDLScriptFunction};
 
 use crate::{
-    format::{gen_doc_string, indent},
-    idl_type::{generate_idl_type_with_type_args, serialize_arg},
+    ast::{Const, Expr, ObjectMember, Param, Property, Stmt, Type, TypeAlias},
+    chain::Chain,
+    idl_type::{
+        encode_arg, encode_move_call_arg, generate_arg_type, is_implicit_arg, serialize_arg,
+        ADDRESS_LENGTH_NAME,
+    },
+    naming::NamingStrategy,
 };
 
-use super::{CodeText, Codegen, CodegenContext};
+use super::{Codegen, CodegenContext};
 
 pub struct ScriptFunctionPayloadStruct<'info>(&'info ScriptFunctionType<'info>);
 
 impl<'info> ScriptFunctionPayloadStruct<'info> {
-    fn args_inline(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        Ok(ctx
-            .try_join_with_separator(&self.0.script.args, "\n")?
-            .indent())
+    fn args_inline(&self, ctx: &CodegenContext) -> Result<Type> {
+        Ok(Type::Object(
+            self.0
+                .args
+                .iter()
+                .zip(&self.0.arg_names)
+                .map(|(arg, name)| {
+                    generate_argument(arg, name, ctx).with_context(|| self.0.arg_context(arg))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        ))
     }
 
-    fn type_args_inline(&self) -> CodeText {
-        script_fn_type_args(&self.0.script.ty_args).indent()
+    fn type_args_inline(&self) -> Type {
+        Type::Object(
+            self.0
+                .ty_arg_names
+                .iter()
+                .map(|name| Property::new(name, Type::named("string")))
+                .collect(),
+        )
     }
 }
 
 impl<'info> Codegen for ScriptFunctionPayloadStruct<'info> {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
-        Ok(CodeText::new_fields_export(
-            &self.0.payload_args_type_name(),
-            &format!(
-                "{}{}",
-                if self.0.script.args.is_empty() {
-                    "".to_string()
-                } else {
-                    format!(
-                        "{}\n",
-                        indent(&format!("args: {{\n{}\n}};\n", self.args_inline(ctx)?))
-                    )
-                },
-                if self.0.script.ty_args.is_empty() {
-                    "".to_string()
-                } else {
-                    format!(
-                        "{}\n",
-                        indent(&format!("typeArgs: {{\n{}\n}};\n", self.type_args_inline()))
-                    )
-                },
-            ),
+    type Node = Stmt;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Stmt> {
+        let mut fields = vec![];
+        if !self.0.args.is_empty() {
+            fields.push(Property::new("args", self.args_inline(ctx)?));
+        }
+        if !self.0.script.ty_args.is_empty() {
+            fields.push(Property::new("typeArgs", self.type_args_inline()));
+        }
+        Ok(
+            TypeAlias::new(&self.0.payload_args_type_name(), Type::Object(fields))
+                .docs(&format!("Payload arguments for {}.", self.0.doc_link()))
+                .into(),
         )
-        .docs(&format!("Payload arguments for {}.", self.0.doc_link()))
-        .into())
     }
 }
 
 pub struct ScriptFunctionType<'info> {
+    fn_name: String,
     type_name: String,
+    args_type_name: String,
+    arg_names: Vec<String>,
+    ty_arg_names: Vec<String>,
+    /// Arguments passed by the caller.
+    args: Vec<&'info IDLArgument>,
     module: &'info IDLModule,
     script: &'info IDLScriptFunction,
 }
 
-fn script_fn_type_args(args: &[String]) -> CodeText {
-    args.iter()
-        .map(|arg| format!("{}: string;", arg))
-        .collect::<Vec<_>>()
-        .join("\n")
-        .into()
+fn generate_argument(arg: &IDLArgument, name: &str, ctx: &CodegenContext) -> Result<Property> {
+    Ok(Property::new(name, generate_arg_type(&arg.ty, ctx, true)?)
+        .docs(&format!("IDL type: `{:?}`", &arg.ty)))
 }
 
 impl Codegen for IDLArgument {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
-        let doc = gen_doc_string(&format!("IDL type: `{:?}`", &self.ty));
-        Ok(format!(
-            "{}{}: {};",
-            doc,
-            self.name,
-            &self.ty.generate_typescript(ctx)?
-        ))
+    type Node = Property;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Property> {
+        generate_argument(self, &ctx.naming().field_name(&self.name), ctx)
     }
 }
 
 impl<'info> ScriptFunctionType<'info> {
-    pub fn new(module: &'info IDLModule, script: &'info IDLScriptFunction) -> Self {
-        let type_name = script.name.to_pascal_case();
+    pub fn new(
+        module: &'info IDLModule,
+        script: &'info IDLScriptFunction,
+        naming: &NamingStrategy,
+        chain: Chain,
+    ) -> Self {
+        let type_name = naming.payload_type_name(&script.name);
+        let args: Vec<_> = script
+            .args
+            .iter()
+            .filter(|arg| !is_implicit_arg(&arg.ty, chain))
+            .collect();
         Self {
+            fn_name: naming.function_name(&script.name),
+            args_type_name: format!("{}Args", type_name),
             type_name,
+            arg_names: naming.field_names(args.iter().map(|a| a.name.as_str())),
+            ty_arg_names: naming.field_names(script.ty_args.iter().map(|a| a.as_str())),
+            args,
             module,
             script,
         }
     }
 
+    /// Overrides the generated names, e.g. to rename collisions with other declarations.
+    pub fn with_names(self, fn_name: String, type_name: String, args_type_name: String) -> Self {
+        Self {
+            fn_name,
+            type_name,
+            args_type_name,
+            ..self
+        }
+    }
+
     pub fn doc_link(&self) -> String {
-        format!("{{@link entry.{}}}", self.script.name)
+        format!("{{@link entry.{}}}", self.fn_name)
     }
 
     pub fn payload(&'info self) -> ScriptFunctionPayloadStruct<'info> {
         ScriptFunctionPayloadStruct(self)
     }
 
-    pub fn generate_entry_payload_struct(&self, ctx: &CodegenContext) -> Result<CodeText> {
-        let arguments = format!(
-            "[{}]",
-            self.script
-                .args
+    pub fn generate_entry_payload_struct(&self, ctx: &CodegenContext) -> Result<Stmt> {
+        let arguments = Type::labeled_tuple(
+            self.args
                 .iter()
-                .map(|a| {
-                    let ts_type = &generate_idl_type_with_type_args(&a.ty, ctx, &[], false)?;
-                    Ok(format!("{}: {}", a.name, &ts_type))
+                .zip(&self.arg_names)
+                .map(|(a, name)| {
+                    let ty = generate_arg_type(&a.ty, ctx, false)
+                        .with_context(|| self.arg_context(a))?;
+                    Ok((name.clone(), ty))
                 })
-                .collect::<Result<Vec<_>>>()?
-                .join(", ")
+                .collect::<Result<Vec<_>>>()?,
         );
-        let type_arguments = format!(
-            "[{}]",
-            self.script
-                .ty_args
+        let type_arguments = Type::labeled_tuple(
+            self.ty_arg_names
                 .iter()
-                .map(|a| format!("{}: string", a))
-                .collect::<Vec<_>>()
-                .join(", ")
+                .map(|name| (name.clone(), Type::named("string")))
+                .collect(),
         );
 
-        Ok(CodeText::new_fields_export(
+        Ok(TypeAlias::new(
             &self.type_name,
-            &CodeText::try_join_with_separator(
-                &[
-                    CodeText::new("readonly type: \"script_function_payload\";"),
-                    CodeText::new(&format!("readonly function: \"{}\";", self.full_name())),
-                    CodeText::new(&format!("readonly arguments: {};", &arguments)),
-                    CodeText::new(&format!("readonly type_arguments: {};", &type_arguments)),
-                ],
-                "\n",
-            )?
-            .indent()
-            .append_newline()
-            .to_string(),
+            Type::Object(vec![
+                Property::new("type", Type::string_literal("script_function_payload")).readonly(),
+                Property::new("function", Type::string_literal(&self.full_name())).readonly(),
+                Property::new("arguments", arguments).readonly(),
+                Property::new("type_arguments", type_arguments).readonly(),
+            ]),
         )
         .docs(&format!(
             "Script function payload for `{}`.{}",
@@ -142,14 +163,21 @@ impl<'info> ScriptFunctionType<'info> {
                 .as_ref()
                 .map(|s| format!("\n\n{}", s))
                 .unwrap_or_default()
-        )))
+        ))
+        .into())
     }
 
     pub fn doc(&self) -> Option<String> {
         self.script.doc.clone()
     }
 
+    /// Name of the generated entry function builder.
     pub fn name(&self) -> &str {
+        &self.fn_name
+    }
+
+    /// Name of the Move function.
+    pub fn move_name(&self) -> &str {
         &self.script.name
     }
 
@@ -157,85 +185,207 @@ impl<'info> ScriptFunctionType<'info> {
         format!("{}::{}", self.module.module_id, self.script.name)
     }
 
+    /// Describes an argument in errors.
+    fn arg_context(&self, arg: &IDLArgument) -> String {
+        format!("argument `{}` of `{}`", arg.name, self.full_name())
+    }
+
     pub fn payload_args_type_name(&'info self) -> String {
-        format!("{}Args", self.type_name)
+        self.args_type_name.clone()
     }
 
     pub fn should_render_payload_struct(&'info self) -> bool {
-        !(self.script.args.is_empty() && self.script.ty_args.is_empty())
+        !(self.args.is_empty() && self.script.ty_args.is_empty())
+    }
+
+    /// Parameters of the builders, which destructure the payload arguments type.
+    fn builder_params(&self, ctx: &CodegenContext) -> Vec<Param> {
+        if !self.should_render_payload_struct() {
+            return vec![];
+        }
+        let pattern = format!(
+            "{{ {} }}",
+            vec![
+                if self.args.is_empty() {
+                    None
+                } else {
+                    Some("args")
+                },
+                if self.script.ty_args.is_empty() {
+                    None
+                } else {
+                    Some("typeArgs")
+                },
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", "),
+        );
+        ctx.use_sibling("mod", "index", false);
+        vec![Param::new(
+            &pattern,
+            Type::named(&format!("mod.{}", self.payload_args_type_name())),
+        )]
+    }
+
+    /// Generates the method of the package client submitting a call of this function.
+    pub fn generate_client_method(&self, ctx: &CodegenContext) -> Result<ObjectMember> {
+        let module_id = self.module.module_id.short_str_lossless();
+        let builder = ctx
+            .module_value(&module_id, &format!("entry.{}", self.fn_name))
+            .ok_or_else(|| anyhow!("module {} is not generated", module_id))?;
+        let (params, payload) = if self.should_render_payload_struct() {
+            let args_type = ctx
+                .module_type(&module_id, &self.args_type_name, vec![])
+                .ok_or_else(|| anyhow!("module {} is not generated", module_id))?;
+            (
+                vec![Param::new("args", args_type)],
+                builder.call(vec![Expr::path("args")]),
+            )
+        } else {
+            (vec![], builder.call(vec![]))
+        };
+        Ok(ObjectMember::Property(
+            self.fn_name.clone(),
+            Expr::arrow(
+                params,
+                Some(Type::generic("Promise", vec![Type::named("string")])),
+                Expr::path("this.signer.submit").call(vec![payload]),
+            ),
+        ))
+    }
+
+    /// Type arguments of the builders.
+    fn type_arguments(&self) -> Expr {
+        Expr::Array(
+            self.ty_arg_names
+                .iter()
+                .map(|name| Expr::path("typeArgs").member(name))
+                .collect(),
+        )
+    }
+
+    /// Generates a builder of the call of this function: a BCS-encoded `TransactionPayload` on
+    /// Aptos, and a Move call with BCS-encoded pure arguments on Sui.
+    pub fn generate_bcs_encoder(&self, ctx: &CodegenContext) -> Result<Stmt> {
+        let encode = match ctx.chain() {
+            Chain::Aptos => encode_arg,
+            Chain::Sui => encode_move_call_arg,
+        };
+        let arguments = Expr::Array(
+            self.args
+                .iter()
+                .zip(&self.arg_names)
+                .map(|(a, name)| {
+                    encode(&format!("args.{}", name), &a.ty, ctx)
+                        .with_context(|| self.arg_context(a))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+        if ctx.chain() == Chain::Sui {
+            return self.generate_move_call(arguments, ctx);
+        }
+        let call = Expr::Object(vec![
+            ObjectMember::Property(
+                "address".to_string(),
+                Expr::string(&self.module.module_id.address().to_hex_literal()),
+            ),
+            ObjectMember::Property(
+                "module".to_string(),
+                Expr::string(&self.module.module_id.name().to_string()),
+            ),
+            ObjectMember::Property("function".to_string(), Expr::string(&self.script.name)),
+            ObjectMember::Property("typeArgs".to_string(), self.type_arguments()),
+            ObjectMember::Property("args".to_string(), arguments),
+        ]);
+        let body = ctx
+            .prelude_value("bcs.entryFunctionPayload")
+            .call(vec![call, Expr::path(ADDRESS_LENGTH_NAME)]);
+
+        Ok(Const::new(
+            &self.fn_name,
+            Expr::arrow(
+                self.builder_params(ctx),
+                Some(Type::named("Uint8Array")),
+                body,
+            ),
+        )
+        .docs(&format!(
+            "BCS-encoded `TransactionPayload` calling `{}`.{}",
+            self.full_name(),
+            self.script
+                .doc
+                .as_ref()
+                .map(|s| format!("\n\n{}", s))
+                .unwrap_or_default()
+        ))
+        .into())
+    }
+
+    /// Generates a builder of the Sui Move call of this function.
+    fn generate_move_call(&self, arguments: Expr, ctx: &CodegenContext) -> Result<Stmt> {
+        let body = Expr::Object(vec![
+            ObjectMember::Property("target".to_string(), Expr::string(&self.full_name())),
+            ObjectMember::Property("typeArguments".to_string(), self.type_arguments()),
+            ObjectMember::Property("arguments".to_string(), arguments),
+        ]);
+        Ok(Const::new(
+            &self.fn_name,
+            Expr::arrow(
+                self.builder_params(ctx),
+                Some(ctx.prelude_type("MoveCall")),
+                body,
+            ),
+        )
+        .docs(&format!(
+            "Move call of `{}`, with BCS-encoded pure arguments.{}",
+            self.full_name(),
+            self.script
+                .doc
+                .as_ref()
+                .map(|s| format!("\n\n{}", s))
+                .unwrap_or_default()
+        ))
+        .into())
     }
 }
 
 impl<'info> Codegen for ScriptFunctionType<'info> {
-    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<String> {
+    type Node = Stmt;
+
+    fn generate_typescript(&self, ctx: &CodegenContext) -> Result<Stmt> {
         let function = format!(
             "{}::{}",
             &self.module.module_id.short_str_lossless(),
             &self.script.name
         );
-        let type_arguments = format!(
-            "[{}]",
-            self.script
-                .ty_args
+        let type_arguments = self.type_arguments();
+        let arguments = Expr::Array(
+            self.args
                 .iter()
-                .map(|a| format!("typeArgs.{}", a))
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
-        let arguments = format!(
-            "[{}]",
-            self.script
-                .args
-                .iter()
-                .map(|a| {
-                    let inner = format!("args.{}", a.name);
-                    serialize_arg(&inner, &a.ty, ctx)
+                .zip(&self.arg_names)
+                .map(|(a, name)| {
+                    serialize_arg(&format!("args.{}", name), &a.ty, ctx)
+                        .with_context(|| self.arg_context(a))
                 })
-                .collect::<Result<Vec<_>>>()?
-                .join(", ")
+                .collect::<Result<Vec<_>>>()?,
         );
 
-        Ok(format!(
-            r#"{}export const {} = ({}): payloads.{} => ({{
-  type: "script_function_payload",
-  function: "{}",
-  type_arguments: {},
-  arguments: {},
-}});"#,
-            self.script
-                .doc
-                .as_ref()
-                .map(|doc| gen_doc_string(doc))
-                .unwrap_or_default(),
-            self.script.name,
-            if self.should_render_payload_struct() {
-                format!(
-                    "{{ {} }}: mod.{}",
-                    vec![
-                        if self.script.args.is_empty() {
-                            None
-                        } else {
-                            Some("args")
-                        },
-                        if self.script.ty_args.is_empty() {
-                            None
-                        } else {
-                            Some("typeArgs")
-                        },
-                    ]
-                    .into_iter()
-                    .flatten()
-                    .collect::<Vec<_>>()
-                    .join(", "),
-                    self.payload_args_type_name()
-                )
-            } else {
-                "".to_string()
-            },
-            &self.type_name,
-            &function,
-            &type_arguments,
-            &arguments
-        ))
+        let body = Expr::Object(vec![
+            ObjectMember::Property("type".to_string(), Expr::string("script_function_payload")),
+            ObjectMember::Property("function".to_string(), Expr::string(&function)),
+            ObjectMember::Property("type_arguments".to_string(), type_arguments),
+            ObjectMember::Property("arguments".to_string(), arguments),
+        ]);
+        let params = self.builder_params(ctx);
+        let return_type = Type::named(&format!("payloads.{}", &self.type_name));
+        ctx.use_sibling("payloads", "payloads", false);
+
+        Ok(
+            Const::new(&self.fn_name, Expr::arrow(params, Some(return_type), body))
+                .docs_opt(&self.script.doc)
+                .into(),
+        )
     }
 }
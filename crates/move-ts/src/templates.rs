@@ -0,0 +1,263 @@
+//! Templates which lay out the generated files.
+//!
+//! Every generated file is rendered by a [TinyTemplate](tinytemplate) template. Generators still
+//! build the declarations of each file, so templates receive them as code printed for the
+//! target, along with the IDL data of the module or package. Templates can reorder, omit or
+//! wrap these declarations, and add their own code.
+//!
+//! A template is looked up by the kind of the file, e.g. `module/index` for the index of each
+//! module or `errmap` for the errmap of the package, falling back to the `file` template. Runs
+//! of blank lines in the rendered output are collapsed, so empty values leave no gaps. Values are
+//! inserted as is, or as JSON with the `json` formatter, e.g. `{module.idl.errors | json}`.
+
+use crate::{
+    ast::{Module, Stmt},
+    emit::{EmitTarget, ModuleFormat},
+    CodeText, CodegenContext,
+};
+use anyhow::*;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+use tinytemplate::TinyTemplate;
+
+/// Name of the template used for files without a template of their own.
+pub const DEFAULT_TEMPLATE: &str = "file";
+
+/// Templates shipped with the generator, reproducing the default layout of each file.
+pub const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("file", include_str!("../templates/file.tpl")),
+    ("index", include_str!("../templates/index.tpl")),
+    (
+        "module/index",
+        include_str!("../templates/module/index.tpl"),
+    ),
+    ("readme", include_str!("../templates/readme.tpl")),
+];
+
+/// Extension of template files.
+const TEMPLATE_EXTENSION: &str = "tpl";
+
+/// A set of templates, keyed by name.
+#[derive(Clone, Debug)]
+pub struct Templates {
+    templates: BTreeMap<String, String>,
+}
+
+impl Default for Templates {
+    fn default() -> Self {
+        Templates {
+            templates: DEFAULT_TEMPLATES
+                .iter()
+                .map(|(name, text)| (name.to_string(), text.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl Templates {
+    /// Creates the default templates.
+    pub fn new() -> Self {
+        Templates::default()
+    }
+
+    /// Adds a template, replacing any template with the same name.
+    pub fn with_template(mut self, name: &str, text: &str) -> Result<Self> {
+        TinyTemplate::new()
+            .add_template(name, text)
+            .map_err(|err| anyhow!("invalid template `{}`: {}", name, err))?;
+        self.templates.insert(name.to_string(), text.to_string());
+        Ok(self)
+    }
+
+    /// Adds all `.tpl` files of a directory, named by their path relative to the directory
+    /// without extension, e.g. `module/index.tpl` overrides the `module/index` template.
+    pub fn with_dir(self, dir: &Path) -> Result<Self> {
+        let mut templates = self;
+        for (name, path) in template_files(dir, "")? {
+            let text = std::fs::read_to_string(&path)
+                .with_context(|| format!("reading template {}", path.display()))?;
+            templates = templates.with_template(&name, &text)?;
+        }
+        Ok(templates)
+    }
+
+    /// Names of all templates.
+    pub fn names(&self) -> Vec<&str> {
+        self.templates.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns the text of a template.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.templates.get(name).map(|text| text.as_str())
+    }
+
+    /// Renders the template `name`, or the [DEFAULT_TEMPLATE] if there is none.
+    pub fn render(&self, name: &str, context: &Value) -> Result<String> {
+        let name = if self.templates.contains_key(name) {
+            name
+        } else {
+            DEFAULT_TEMPLATE
+        };
+        let text = self
+            .get(name)
+            .ok_or_else(|| anyhow!("missing template `{}`", name))?;
+        let mut tt = TinyTemplate::new();
+        tt.set_default_formatter(&tinytemplate::format_unescaped);
+        tt.add_formatter("json", |value, output| {
+            output.push_str(&serde_json::to_string(value)?);
+            std::result::Result::Ok(())
+        });
+        tt.add_template(name, text)
+            .map_err(|err| anyhow!("invalid template `{}`: {}", name, err))?;
+        let rendered = tt
+            .render(name, context)
+            .map_err(|err| anyhow!("rendering template `{}`: {}", name, err))?;
+        Ok(collapse_blank_lines(&rendered))
+    }
+}
+
+/// Lists the template files of a directory recursively, keyed by template name.
+fn template_files(dir: &Path, prefix: &str) -> Result<BTreeMap<String, PathBuf>> {
+    let mut files = BTreeMap::new();
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("reading templates {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(stem) => format!("{}{}", prefix, stem),
+            None => continue,
+        };
+        if path.is_dir() {
+            files.extend(template_files(&path, &format!("{}/", stem))?);
+        } else if path
+            .extension()
+            .map_or(false, |ext| ext == TEMPLATE_EXTENSION)
+        {
+            files.insert(stem, path);
+        }
+    }
+    Ok(files)
+}
+
+/// Removes blank lines at the start and end of a file and collapses runs of blank lines.
+fn collapse_blank_lines(text: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    for line in text.lines() {
+        let blank = line.trim().is_empty();
+        if blank && lines.last().map_or(true, |last| last.is_empty()) {
+            continue;
+        }
+        lines.push(if blank { "" } else { line });
+    }
+    while lines.last().map_or(false, |last| last.is_empty()) {
+        lines.pop();
+    }
+    format!("{}\n", lines.join("\n"))
+}
+
+/// Flags of the target, which templates can branch on.
+fn target_context(target: EmitTarget) -> Value {
+    json!({
+        "extension": target.extension(),
+        "typescript": target == EmitTarget::TypeScript,
+        "javascript": matches!(target, EmitTarget::JavaScript(_)),
+        "declarations": matches!(target, EmitTarget::Declarations(_)),
+        "commonjs": target.module_format() == ModuleFormat::CommonJs,
+    })
+}
+
+impl<'info> CodegenContext<'info> {
+    /// Prints statements for the target of this context, without a trailing newline.
+    fn print_items(&self, items: &[Stmt]) -> Result<String> {
+        let code = self.print(&Module::new().items(items.iter().cloned()))?;
+        Ok(code.to_string().trim_end().to_string())
+    }
+
+    /// Renders a generated file with the template `name`.
+    ///
+    /// Besides the fields of `data`, the template receives:
+    ///
+    /// - `file`: the path of the file without extension, e.g. `coin/index`.
+    /// - `target`: the `extension` of the file and whether the target is `typescript`,
+    ///   `javascript`, `declarations` or `commonjs`.
+    /// - `header`: the documentation comment of the file.
+    /// - `imports`: the imports of the file.
+    /// - `body`: all other statements, in order.
+    /// - `items`: the `name` and `code` of each statement, with adjacent exports grouped.
+    /// - `declarations`: the code of each declaration, keyed by name.
+    /// - `sections`: the code of each named section of the file.
+    pub fn render(&self, name: &str, file: &str, module: &Module, data: Value) -> Result<CodeText> {
+        let header = match &module.docs {
+            Some(docs) => self
+                .print(&Module::new().docs(docs))?
+                .to_string()
+                .trim_end()
+                .to_string(),
+            None => String::new(),
+        };
+        let import_count = module
+            .items
+            .iter()
+            .take_while(|item| matches!(item, Stmt::Import(_)))
+            .count();
+        let (imports, body) = module.items.split_at(import_count);
+
+        let mut items = vec![];
+        let mut start = 0;
+        for end in 1..=body.len() {
+            let adjacent = end < body.len()
+                && matches!(
+                    (&body[end - 1], &body[end]),
+                    (Stmt::Export(_), Stmt::Export(_)) | (Stmt::Import(_), Stmt::Import(_))
+                );
+            if !adjacent {
+                let group = &body[start..end];
+                items.push(json!({
+                    "name": if group.len() == 1 { group[0].declared_name() } else { None },
+                    "code": self.print_items(group)?,
+                }));
+                start = end;
+            }
+        }
+
+        let mut declarations = Map::new();
+        for item in body.iter() {
+            if let Some(name) = item.declared_name() {
+                declarations.insert(
+                    name.to_string(),
+                    self.print_items(std::slice::from_ref(item))?.into(),
+                );
+            }
+        }
+        let mut sections = Map::new();
+        for (section, range) in module.sections.iter() {
+            sections.insert(
+                section.clone(),
+                self.print_items(&module.items[range.clone()])?.into(),
+            );
+        }
+
+        let mut context = match data {
+            Value::Object(map) => map,
+            Value::Null => Map::new(),
+            _ => bail!("template data of {} must be an object", file),
+        };
+        context.insert("file".to_string(), file.into());
+        context.insert("target".to_string(), target_context(self.target()));
+        context.insert("header".to_string(), header.into());
+        context.insert("imports".to_string(), self.print_items(imports)?.into());
+        context.insert("body".to_string(), self.print_items(body)?.into());
+        context.insert("items".to_string(), items.into());
+        context.insert("declarations".to_string(), declarations.into());
+        context.insert("sections".to_string(), sections.into());
+
+        Ok(self
+            .templates()
+            .render(name, &Value::Object(context))
+            .with_context(|| format!("rendering {}", file))?
+            .into())
+    }
+}
@@ -0,0 +1,5 @@
+{header}
+
+{imports}
+
+{body}
@@ -0,0 +1,8 @@
+{header}
+
+{imports}
+
+{sections.modules}
+{sections.errmap}
+
+{sections.client}
@@ -0,0 +1,19 @@
+{header}
+
+{imports}
+
+{sections.types}
+
+{sections.exports}
+
+{sections.id}
+
+{sections.errors}
+
+{sections.constants}
+
+{sections.metadata}
+
+{sections.definition}
+
+{sections.extensions}
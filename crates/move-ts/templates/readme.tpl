@@ -0,0 +1,8 @@
+# {package.npmName}
+
+Generated TypeScript bindings for the Move package `{package.name}`.
+
+## Modules
+
+{{ for module in package.modules }}- [`{module.outputName}`](./{module.outputName}/index.ts) (`{module.id}`){{ if module.summary }}: {module.summary}{{ endif }}
+{{ endfor }}
@@ -0,0 +1,82 @@
+//! Runs the generated BCS encoders with Node.js and checks the encoded payloads byte by byte.
+//!
+//! The tests are skipped if Node.js is not available, see [common::run_node].
+
+mod common;
+
+use anyhow::*;
+use common::run_node;
+use serde_json::json;
+
+/// Prints the value of `script` with the builders of the module `module` of a fixture in scope
+/// as `bcs`. Bytes are printed in hex.
+fn run_encoder(fixture: &str, module: &str, script: &str) -> Result<Option<String>> {
+    run_node(fixture, &[("bcs", &format!("{}/bcs", module))], script)
+}
+
+#[test]
+fn signers_are_not_encoded() -> Result<()> {
+    // `create<X, Y>(account: &signer, amounts, labels, data, limit, enabled, fee_bps)`
+    let encoded = match run_encoder(
+        "generic_pools",
+        "pool",
+        "bcs.create({ args: { amounts: [[1]], labels: [\"hi\"], data: [\"0x0102\"], limit: 2, enabled: true, fee_bps: 3 }, typeArgs: { X: \"u8\", Y: \"bool\" } })",
+    )? {
+        Some(encoded) => encoded,
+        None => return Ok(()),
+    };
+    let expected = serde_json::Value::String(
+        [
+            // `TransactionPayload::EntryFunction`
+            "02",
+            // module `0x2::pool`
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "04706f6f6c",
+            // function `create`
+            "06637265617465",
+            // type arguments `u8` and `bool`
+            "02",
+            "01",
+            "00",
+            // six arguments, without the signer
+            "06",
+            "0a01010100000000000000",
+            "0401026869",
+            "0401020102",
+            "1002000000000000000000000000000000",
+            "0101",
+            "0103",
+        ]
+        .concat(),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&encoded)?,
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn sui_move_calls_pass_objects_by_id() -> Result<()> {
+    // `claim(ticket: &mut Ticket, receipt: ID, ctx: &mut TxContext)`
+    let encoded = match run_encoder(
+        "sui_market",
+        "market",
+        "bcs.claim({ args: { ticket: \"0xab\", receipt: \"0x2\" } })",
+    )? {
+        Some(encoded) => encoded,
+        None => return Ok(()),
+    };
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&encoded)?,
+        json!({
+            "target": "0x3::market::claim",
+            "typeArguments": [],
+            "arguments": [
+                { "object": "0xab" },
+                { "pure": "0000000000000000000000000000000000000000000000000000000000000002" },
+            ],
+        })
+    );
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Tests of the generation of entry function calls for each chain.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, load_fixture};
+use move_ts::{chain::Chain, emit::EmitTarget, idl_package::IDLPackageGenerator};
+use std::path::Path;
+
+fn generate_file(fixture: &str, chain: Chain, path: &str) -> Result<String> {
+    let idl = load_fixture(fixture)?.idl;
+    let files = IDLPackageGenerator::new(&idl, true)
+        .with_prelude(bundled())
+        .with_chain(chain)
+        .generate_all()?;
+    let path = format!("{}.{}", path, EmitTarget::TypeScript.extension());
+    Ok(files
+        .get(Path::new(&path))
+        .with_context(|| format!("missing {}", path))?
+        .to_string())
+}
+
+#[test]
+fn chains_are_parsed() {
+    assert_eq!("aptos".parse::<Chain>().unwrap(), Chain::Aptos);
+    assert_eq!("sui".parse::<Chain>().unwrap(), Chain::Sui);
+    assert!("move".parse::<Chain>().is_err());
+    assert_eq!(Chain::default(), Chain::Aptos);
+}
+
+#[test]
+fn aptos_calls_are_transaction_payloads() -> Result<()> {
+    let bcs = generate_file("object_market", Chain::Aptos, "market/bcs")?;
+    assert!(bcs.contains("export const list = ({ args }: mod.ListArgs): Uint8Array =>"));
+    assert!(bcs.contains("p.bcs.entryFunctionPayload("));
+
+    let index = generate_file("object_market", Chain::Aptos, "market/index")?;
+    assert!(!index.contains("seller: "));
+    Ok(())
+}
+
+#[test]
+fn sui_calls_are_move_calls() -> Result<()> {
+    let bcs = generate_file("sui_market", Chain::Sui, "market/bcs")?;
+    assert!(bcs.contains("export const claim = ({ args }: mod.ClaimArgs): p.MoveCall => ({"));
+    assert!(bcs.contains("{ object: p.serializers.hexString(args.ticket) },"));
+    assert!(bcs.contains("{ pure: p.bcs.address(args.receipt, ADDRESS_LENGTH) },"));
+    assert!(bcs.contains("const ADDRESS_LENGTH = 32 as const;"));
+
+    // The `TxContext` is passed by the runtime.
+    let index = generate_file("sui_market", Chain::Sui, "market/index")?;
+    assert!(index.contains("ticket: p.RawAddress;"));
+    assert!(!index.contains("ctx: "));
+    Ok(())
+}
+
+#[test]
+fn objects_of_other_chains_are_rejected() {
+    let err = generate_file("sui_market", Chain::Aptos, "market/bcs").unwrap_err();
+    assert!(format!("{:?}", err).contains("unsupported struct argument type"));
+    let err = generate_file("object_market", Chain::Sui, "market/bcs").unwrap_err();
+    assert!(format!("{:?}", err).contains("0x1::object::Object"));
+}
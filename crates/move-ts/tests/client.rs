@@ -0,0 +1,96 @@
+//! Tests of the client class exported by the package index.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, load_fixture};
+use move_ts::{
+    emit::{EmitTarget, ModuleFormat},
+    idl_package::IDLPackageGenerator,
+};
+use std::path::Path;
+
+fn generate_index(client: bool, target: EmitTarget) -> Result<String> {
+    let fixture = load_fixture("coin_demo")?;
+    let files = IDLPackageGenerator::new(&fixture.idl, true)
+        .with_prelude(bundled())
+        .with_target(target)
+        .with_client(client)
+        .with_views(fixture.views)
+        .generate_all()?;
+    let path = format!("index.{}", target.extension());
+    Ok(files
+        .get(Path::new(&path))
+        .context("missing index")?
+        .to_string())
+}
+
+#[test]
+fn client_is_optional() -> Result<()> {
+    let index = generate_index(false, EmitTarget::TypeScript)?;
+    assert!(!index.contains("class"));
+    assert!(!index.contains("import"));
+    Ok(())
+}
+
+#[test]
+fn client_needs_the_bundled_prelude() -> Result<()> {
+    let fixture = load_fixture("coin_demo")?;
+    let err = IDLPackageGenerator::new(&fixture.idl, true)
+        .with_client(true)
+        .generate_all()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "the client needs the bundled prelude");
+    Ok(())
+}
+
+#[test]
+fn client_groups_modules() -> Result<()> {
+    let index = generate_index(true, EmitTarget::TypeScript)?;
+    assert!(index.contains("import * as mod_vault from \"./vault/index.js\";"));
+    assert!(index.contains("export class CoinDemoClient {"));
+    assert!(index.contains(
+        "    readonly provider: p.MoveProvider,\n    readonly signer: p.MoveSigner\n  ) {}"
+    ));
+    assert!(index.contains("  readonly _0xa_coin = {"));
+    assert!(index.contains(
+        "      deposit: (args: mod_vault.DepositArgs): Promise<string> =>\n        this.signer.submit(mod_vault.entry.deposit(args)),"
+    ));
+    assert!(index.contains("      init: (): Promise<string> =>"));
+    assert!(index.contains("p.structTag(\"0xa::coin::Holder\", [typeArgs.T, typeArgs.Item])"));
+    // Modules without entry functions or resources are omitted.
+    assert!(!index.contains("_0x1_coin ="));
+    Ok(())
+}
+
+#[test]
+fn client_calls_view_functions() -> Result<()> {
+    let index = generate_index(true, EmitTarget::TypeScript)?;
+    assert!(index.contains(
+        "    views: {\n      balance: (\n        { args, typeArgs }: {\n          args: { owner: p.RawAddress };\n          typeArgs: { T: string };\n        }\n      ): Promise<readonly [string]> =>"
+    ));
+    assert!(index.contains(
+        "          function: \"0xa::coin::balance\",\n          type_arguments: [typeArgs.T],\n          arguments: [p.serializers.hexString(args.owner)],\n"
+    ));
+    assert!(index.contains("      fee: (): Promise<readonly [string, string]> =>"));
+    Ok(())
+}
+
+#[test]
+fn client_is_emitted_for_each_target() -> Result<()> {
+    let js = generate_index(true, EmitTarget::JavaScript(ModuleFormat::Esm))?;
+    assert!(js.contains("  constructor(provider, signer) {\n    this.provider = provider;"));
+    assert!(js.contains("  vault = {"));
+
+    let cjs = generate_index(true, EmitTarget::JavaScript(ModuleFormat::CommonJs))?;
+    assert!(cjs.contains("\nclass CoinDemoClient {"));
+    assert!(cjs.contains("exports.CoinDemoClient = CoinDemoClient;"));
+
+    let dts = generate_index(true, EmitTarget::Declarations(ModuleFormat::Esm))?;
+    assert!(dts.contains("export declare class CoinDemoClient {"));
+    assert!(dts.contains("  constructor(provider: p.MoveProvider, signer: p.MoveSigner);"));
+    assert!(
+        dts.contains("    entry: { deposit: (args: mod_vault.DepositArgs) => Promise<string> };")
+    );
+    Ok(())
+}
@@ -0,0 +1,189 @@
+//! Helpers shared by the integration tests.
+
+// Each test crate only uses some of the helpers.
+#![allow(dead_code)]
+
+use anyhow::*;
+use move_idl::IDLPackage;
+use move_ts::{
+    chain::Chain,
+    emit::{EmitTarget, ModuleFormat},
+    idl_constant::{read_constants, IDLConstants},
+    idl_enum::{read_enums, IDLEnums},
+    idl_package::IDLPackageGenerator,
+    idl_view::{read_views, IDLViews},
+    ordering::DeclarationOrder,
+    prelude::{bundled_prelude, PreludeConfig, PreludeSource, BUNDLED_PRELUDE_NAME},
+    CodeText,
+};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Path of a file or directory in `tests/`.
+pub fn test_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(path)
+}
+
+/// Names of the IDL fixtures in `tests/fixtures`.
+pub fn fixture_names() -> Result<Vec<String>> {
+    let mut names = std::fs::read_dir(test_path("fixtures"))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|path| path.extension().map_or(false, |ext| ext == "json"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
+
+/// An IDL fixture, along with its input which is not part of the IDL of `move-idl`.
+#[derive(Clone)]
+pub struct Fixture {
+    pub idl: IDLPackage,
+    /// Chain given by the `chain` property.
+    pub chain: Chain,
+    pub constants: IDLConstants,
+    pub enums: IDLEnums,
+    pub views: IDLViews,
+}
+
+/// Reads and parses a fixture of `tests/fixtures`.
+pub fn load_fixture(name: &str) -> Result<Fixture> {
+    let path = test_path("fixtures").join(name).with_extension("json");
+    let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)
+        .with_context(|| format!("parsing {}", path.display()))?;
+    Ok(Fixture {
+        idl: serde_json::from_value(json.clone())
+            .with_context(|| format!("parsing {}", path.display()))?,
+        chain: match json.get("chain").and_then(|chain| chain.as_str()) {
+            Some(chain) => chain.parse().map_err(Error::msg)?,
+            None => Chain::default(),
+        },
+        constants: read_constants(&json)?,
+        enums: read_enums(&json)?,
+        views: read_views(&json)?,
+    })
+}
+
+/// Generates all files of a package for a target with the bundled prelude, keyed by path.
+///
+/// The prelude itself is a copy of `src/prelude` and is left out.
+pub fn generate(
+    fixture: &Fixture,
+    target: EmitTarget,
+    order: DeclarationOrder,
+) -> Result<BTreeMap<String, CodeText>> {
+    generate_with_prelude(fixture, target, order, bundled())
+}
+
+/// Generates all files of a package for a target with a prelude, keyed by path.
+pub fn generate_with_prelude(
+    fixture: &Fixture,
+    target: EmitTarget,
+    order: DeclarationOrder,
+    prelude: PreludeConfig,
+) -> Result<BTreeMap<String, CodeText>> {
+    Ok(IDLPackageGenerator::new(&fixture.idl, true)
+        .with_prelude(prelude)
+        .with_target(target)
+        .with_order(order)
+        .with_chain(fixture.chain)
+        .with_constants(fixture.constants.clone())
+        .with_enums(fixture.enums.clone())
+        .with_views(fixture.views.clone())
+        .generate_all()?
+        .into_iter()
+        .map(|(path, code)| (path.to_string_lossy().replace('\\', "/"), code))
+        .filter(|(path, _)| !path.starts_with(&format!("{}.", BUNDLED_PRELUDE_NAME)))
+        .collect())
+}
+
+/// Configuration importing the bundled prelude.
+pub fn bundled() -> PreludeConfig {
+    PreludeConfig {
+        source: PreludeSource::Bundled,
+        ..Default::default()
+    }
+}
+
+/// Returns the command of Node.js, if it is available.
+pub fn find_node() -> Option<String> {
+    let node = std::env::var("NODE").unwrap_or_else(|_| "node".to_string());
+    let output = Command::new(&node).arg("--version").output().ok()?;
+    if output.status.success() {
+        Some(node)
+    } else {
+        None
+    }
+}
+
+/// Generates the CommonJS modules of a fixture and prints the JSON of the value of `script`, with
+/// the generated files `modules` in scope, given by name and path without extension. Bytes are
+/// printed in hex.
+///
+/// Node.js is taken from the `NODE` environment variable, or `node` on the `PATH`. Returns `None`
+/// if it is not available, unless the `CI` environment variable is set.
+pub fn run_node(fixture: &str, modules: &[(&str, &str)], script: &str) -> Result<Option<String>> {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let node = match find_node() {
+        Some(node) => node,
+        None if std::env::var_os("CI").is_some() => {
+            bail!("node not found, set NODE to the Node.js executable")
+        }
+        None => {
+            eprintln!("skipping: node not found, set NODE to the Node.js executable");
+            return Ok(None);
+        }
+    };
+
+    let target = EmitTarget::JavaScript(ModuleFormat::CommonJs);
+    let dir = std::env::temp_dir().join(format!(
+        "move-ts-node-{}-{}-{}",
+        std::process::id(),
+        fixture,
+        RUNS.fetch_add(1, Ordering::SeqCst)
+    ));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    let fixture = load_fixture(fixture)?;
+    for (path, code) in generate(&fixture, target, DeclarationOrder::default())? {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, code)?;
+    }
+    std::fs::write(
+        dir.join(format!("{}.{}", BUNDLED_PRELUDE_NAME, target.extension())),
+        bundled_prelude(target),
+    )?;
+
+    let requires = modules
+        .iter()
+        .map(|(name, path)| format!("const {} = require(\"./{}.cjs\");\n", name, path))
+        .collect::<String>();
+    let script = format!(
+        "{}const hex = (_, v) => (v instanceof Uint8Array ? Buffer.from(v).toString(\"hex\") : v);\nprocess.stdout.write(JSON.stringify({}, hex));\n",
+        requires, script
+    );
+    std::fs::write(dir.join("main.cjs"), script)?;
+    let output = Command::new(&node)
+        .arg(dir.join("main.cjs"))
+        .output()
+        .with_context(|| format!("running {}", node))?;
+    ensure!(
+        output.status.success(),
+        "script failed in {}:\n{}",
+        dir.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    std::fs::remove_dir_all(&dir)?;
+    Ok(Some(String::from_utf8(output.stdout)?))
+}
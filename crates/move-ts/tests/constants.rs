@@ -0,0 +1,91 @@
+//! Tests of the generation of module constants.
+
+mod common;
+
+use anyhow::*;
+use common::load_fixture;
+use move_ts::{
+    ast::Module,
+    idl_constant::{generate_constant, IDLConstant},
+    CodegenContext,
+};
+use serde_json::json;
+
+/// Prints the const generated for a constant of type `ty` with value `value`.
+fn print_constant(ty: serde_json::Value, value: serde_json::Value) -> Result<String> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let ctx = CodegenContext::new(&idl);
+    let constant: IDLConstant = serde_json::from_value(json!({
+        "name": "VALUE",
+        "doc": null,
+        "ty": ty,
+        "value": value,
+    }))?;
+    let code = ctx
+        .print(&Module::new().item(generate_constant("VALUE", &constant)?))?
+        .to_string();
+    Ok(code
+        .trim_start_matches("export const VALUE = ")
+        .trim_end_matches(";\n")
+        .to_string())
+}
+
+#[test]
+fn constants_are_read_from_the_idl() -> Result<()> {
+    let constants = load_fixture("coin_demo")?.constants;
+    assert_eq!(constants.keys().collect::<Vec<_>>(), ["0xa::coin"]);
+    assert_eq!(constants["0xa::coin"][0].name, "FEE_BPS");
+    Ok(())
+}
+
+#[test]
+fn integers() -> Result<()> {
+    assert_eq!(print_constant(json!("u8"), json!(255))?, "255");
+    assert_eq!(print_constant(json!("u64"), json!(30))?, "30n");
+    assert_eq!(print_constant(json!("u64"), json!("007"))?, "7n");
+    assert_eq!(
+        print_constant(json!("u128"), json!("18446744073709551616"))?,
+        "18446744073709551616n"
+    );
+    Ok(())
+}
+
+#[test]
+fn addresses_and_bytes() -> Result<()> {
+    assert_eq!(print_constant(json!("address"), json!("0xAB"))?, "\"0xab\"");
+    assert_eq!(
+        print_constant(json!({ "vector": "u8" }), json!([1, 255]))?,
+        "\"0x01ff\""
+    );
+    assert_eq!(
+        print_constant(json!({ "vector": "u8" }), json!("0x01FF"))?,
+        "\"0x01ff\""
+    );
+    assert_eq!(
+        print_constant(json!({ "vector": "address" }), json!(["0x1"]))?,
+        "[\"0x1\"] as const"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_constants_are_rejected() -> Result<()> {
+    for (ty, value) in [
+        (json!("u8"), json!(256)),
+        (json!("u64"), json!(-1)),
+        (json!("u64"), json!("0x10")),
+        (json!("bool"), json!(1)),
+        (json!("address"), json!("1")),
+        (json!({ "vector": "u8" }), json!([256])),
+        (json!({ "vector": "u64" }), json!("0x01")),
+        (json!("signer"), json!("0x1")),
+    ] {
+        assert!(
+            print_constant(ty.clone(), value.clone()).is_err(),
+            "{} {}",
+            ty,
+            value
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! Tests of the generation of Move 2 enums.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, load_fixture};
+use move_idl::StructTagData;
+use move_ts::{
+    emit::EmitTarget,
+    idl_enum::{IDLEnum, IDLEnums},
+    idl_package::IDLPackageGenerator,
+};
+use serde_json::json;
+use std::path::Path;
+
+fn generate_file(enums: IDLEnums, path: &str) -> Result<String> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let files = IDLPackageGenerator::new(&idl, true)
+        .with_prelude(bundled())
+        .with_enums(enums)
+        .generate_all()?;
+    let path = format!("{}.{}", path, EmitTarget::TypeScript.extension());
+    Ok(files
+        .get(Path::new(&path))
+        .with_context(|| format!("missing {}", path))?
+        .to_string())
+}
+
+#[test]
+fn enums_are_read_from_the_idl() -> Result<()> {
+    let enums = load_fixture("coin_demo")?.enums;
+    assert_eq!(enums.keys().collect::<Vec<_>>(), ["0xa::coin"]);
+    assert_eq!(enums["0xa::coin"][0].name.to_string(), "0xa::coin::Status");
+    assert_eq!(enums["0xa::coin"][0].variants.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn enum_names_are_struct_tags() -> Result<()> {
+    let enum_def: IDLEnum = serde_json::from_value(json!({
+        "name": "0x1::option::Choice",
+        "doc": null,
+        "type_params": [{ "name": "T" }],
+        "variants": [
+            { "name": "None", "doc": null, "fields": [] },
+            { "name": "Some", "fields": [{ "name": "value", "ty": { "type_param": 0 } }] },
+        ],
+    }))?;
+    assert_eq!(
+        enum_def.name,
+        "0x1::option::Choice".parse::<StructTagData>()?
+    );
+    assert_eq!(
+        enum_def.name.module_id().short_str_lossless(),
+        "0x1::option"
+    );
+    assert_eq!(enum_def.name.name.as_str(), "Choice");
+    assert!(!enum_def.type_params[0].is_phantom);
+    assert_eq!(enum_def.variants[1].fields[0].doc, None);
+    Ok(())
+}
+
+#[test]
+fn enums_are_unions_of_variants() -> Result<()> {
+    let index = generate_file(load_fixture("coin_demo")?.enums, "0xa_coin/index")?;
+    assert!(index.contains(
+        "export interface IStatusFrozen {\n  __variant: \"Frozen\";\n  reason: string;\n"
+    ));
+    assert!(index.contains("export type IStatus = IStatusActive | IStatusFrozen | IStatusLimited;"));
+    assert!(index.contains("export type ISlot<_T = unknown> = ISlotEmpty<_T> | ISlotFull<_T>;"));
+    assert!(index.contains("  status: IStatus;\n  slots: ReadonlyArray<ISlot<p.U64>>;"));
+    Ok(())
+}
+
+#[test]
+fn decoders_read_the_variant_index() -> Result<()> {
+    let decoders = generate_file(load_fixture("coin_demo")?.enums, "0xa_coin/decoders")?;
+    assert!(decoders.contains(
+        "export const Status = (reader: p.BcsReader): mod.IStatus =>\n  reader.variant(["
+    ));
+    assert!(decoders.contains("    (): mod.IStatus => ({ __variant: \"Active\" }),"));
+    assert!(
+        decoders.contains("allowed: reader.vector((reader) => reader.address(ADDRESS_LENGTH)),")
+    );
+    // Generic enums have no decoder.
+    assert!(!decoders.contains("Slot"));
+
+    let index = generate_file(load_fixture("coin_demo")?.enums, "0xa_coin/index")?;
+    assert!(index.contains("export * as decoders from \"./decoders.js\";"));
+    Ok(())
+}
+
+#[test]
+fn enums_are_unknown_without_their_definitions() -> Result<()> {
+    let index = generate_file(IDLEnums::new(), "0xa_coin/index")?;
+    assert!(index.contains("  status: unknown;"));
+    assert!(!index.contains("IStatus"));
+    assert!(generate_file(IDLEnums::new(), "0xa_coin/decoders").is_err());
+    Ok(())
+}
+
+#[test]
+fn phantom_type_params_keep_the_indices_of_the_others() -> Result<()> {
+    let tagged: IDLEnum = serde_json::from_value(json!({
+        "name": "0xa::coin::Tagged",
+        "type_params": [{ "name": "Tag", "is_phantom": true }, { "name": "T" }],
+        "variants": [
+            { "name": "Empty", "fields": [] },
+            { "name": "Full", "fields": [{ "name": "value", "ty": { "type_param": 1 } }] },
+        ],
+    }))?;
+    let mut enums = load_fixture("coin_demo")?.enums;
+    enums
+        .get_mut("0xa::coin")
+        .context("missing 0xa::coin")?
+        .push(tagged);
+    let index = generate_file(enums, "0xa_coin/index")?;
+    assert!(index.contains(
+        "export interface ITaggedFull<_T = unknown> {\n  __variant: \"Full\";\n  value: _T;\n"
+    ));
+    assert!(
+        index.contains("export type ITagged<_T = unknown> = ITaggedEmpty<_T> | ITaggedFull<_T>;")
+    );
+    Ok(())
+}
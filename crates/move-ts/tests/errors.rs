@@ -0,0 +1,132 @@
+//! Tests of the generated error metadata and error classes.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, load_fixture, run_node};
+use move_ts::{
+    emit::EmitTarget,
+    idl_module::{decompose_error_code, error_class_name},
+    idl_package::IDLPackageGenerator,
+};
+use std::path::Path;
+
+fn generate_file(error_classes: bool, target: EmitTarget, path: &str) -> Result<String> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let files = IDLPackageGenerator::new(&idl, true)
+        .with_prelude(bundled())
+        .with_target(target)
+        .with_error_classes(error_classes)
+        .generate_all()?;
+    let path = format!("{}.{}", path, target.extension());
+    Ok(files
+        .get(Path::new(&path))
+        .with_context(|| format!("missing {}", path))?
+        .to_string())
+}
+
+#[test]
+fn error_codes_are_decomposed() {
+    assert_eq!(decompose_error_code(1), (None, 1));
+    assert_eq!(decompose_error_code(0x10002), (Some("INVALID_ARGUMENT"), 2));
+    assert_eq!(
+        decompose_error_code(0x5_0003),
+        (Some("PERMISSION_DENIED"), 3)
+    );
+    assert_eq!(
+        decompose_error_code(0xD_FFFF),
+        (Some("UNAVAILABLE"), 0xFFFF)
+    );
+}
+
+#[test]
+fn unknown_categories_are_kept_in_the_reason() {
+    assert_eq!(decompose_error_code(0xE_0001), (None, 0xE_0001));
+    assert_eq!(decompose_error_code(0x101_0001), (None, 0x101_0001));
+    assert_eq!(decompose_error_code(u64::MAX), (None, u64::MAX));
+}
+
+#[test]
+fn error_class_names() {
+    assert_eq!(error_class_name("E_NOT_OWNER"), "ENotOwner");
+    assert_eq!(error_class_name("ENotOwner"), "ENotOwner");
+    assert_eq!(error_class_name("not_owner"), "ENotOwner");
+    // Screaming case names starting with an `E` keep it, as it may be part of a word.
+    assert_eq!(error_class_name("EMPTY_POOL"), "EEmptyPool");
+    assert_eq!(error_class_name("EXPIRED"), "EExpired");
+    assert_eq!(
+        error_class_name("EINSUFFICIENT_BALANCE"),
+        "EEinsufficientBalance"
+    );
+    assert_eq!(error_class_name("Empty"), "EEmpty");
+}
+
+#[test]
+fn error_classes_are_optional() -> Result<()> {
+    let errors = generate_file(false, EmitTarget::TypeScript, "0xa_coin/errors")?;
+    assert!(!errors.contains("class"));
+    let errmap = generate_file(false, EmitTarget::TypeScript, "errmap")?;
+    assert!(!errmap.contains("errorClasses"));
+    Ok(())
+}
+
+#[test]
+fn error_classes_need_the_bundled_prelude() -> Result<()> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let err = IDLPackageGenerator::new(&idl, true)
+        .with_error_classes(true)
+        .generate_all()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "error classes need the bundled prelude");
+    Ok(())
+}
+
+#[test]
+fn error_classes_extend_move_abort_error() -> Result<()> {
+    let errors = generate_file(true, EmitTarget::TypeScript, "0xa_coin/errors")?;
+    assert!(errors.contains(
+        "export class EEnotOwner extends p.MoveAbortError {\n  readonly module = \"0xa::coin\";\n\n  readonly code = 65538;\n\n  readonly errorName = \"ENOT_OWNER\";\n}"
+    ));
+
+    let errmap = generate_file(true, EmitTarget::TypeScript, "errmap")?;
+    assert!(errmap.contains("import * as mod_0xa_coin from \"./0xa_coin/index.js\";"));
+    assert!(errmap.contains("    \"65538\": mod_0xa_coin.errors.EEnotOwner,"));
+    assert!(errmap.contains(
+        "): error is mod_0xa_coin.errors.EEinsufficientBalance | mod_0xa_coin.errors.EEnotOwner =>"
+    ));
+
+    let index = generate_file(true, EmitTarget::TypeScript, "index")?;
+    assert!(index.contains("export { isPackageError as coin_demo_is_error } from \"./errmap.js\";"));
+    Ok(())
+}
+
+#[test]
+fn error_classes_are_declared() -> Result<()> {
+    let errors = generate_file(
+        true,
+        EmitTarget::Declarations(move_ts::emit::ModuleFormat::Esm),
+        "0xa_coin/errors",
+    )?;
+    assert!(errors.contains(
+        "export declare class EEinsufficientBalance extends p.MoveAbortError {\n  readonly module: \"0xa::coin\";\n\n  readonly code: 1;"
+    ));
+    Ok(())
+}
+
+#[test]
+fn aborts_are_decoded_like_is_error() -> Result<()> {
+    // `ENOT_OWNER` is declared as `0x10002` and `EINSUFFICIENT_BALANCE` as `1`.
+    let decoded = match run_node(
+        "coin_demo",
+        &[("errmap", "errmap")],
+        "[\"ABORTED { code: 2, location: 0xa::coin }\", \"Move abort in 0xa::coin: 0x10001\", \"ABORTED { code: 3, location: 0xa::coin }\"].map((status) => errmap.parseAbort(status)?.name ?? null)",
+    )? {
+        Some(decoded) => decoded,
+        None => return Ok(()),
+    };
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&decoded)?,
+        serde_json::json!(["ENOT_OWNER", "EINSUFFICIENT_BALANCE", null])
+    );
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Tests of generators contributing additional output to each module.
+
+mod common;
+
+use anyhow::*;
+use common::load_fixture;
+use move_ts::{
+    ast::{Const, Export, Expr, Module, Stmt},
+    emit::EmitTarget,
+    generator::{Generator, GeneratorRegistry},
+    idl_module::IDLModuleGenerator,
+    idl_package::IDLPackageGenerator,
+    CodegenContext,
+};
+use std::{path::Path, sync::Arc};
+
+/// Adds a `hooks` file exporting the names of the entry functions of each module.
+struct HooksGenerator;
+
+impl Generator for HooksGenerator {
+    fn module_files(&self, gen: &IDLModuleGenerator, _ctx: &CodegenContext) -> Vec<String> {
+        if gen.has_entrypoints() {
+            vec!["hooks".to_string()]
+        } else {
+            vec![]
+        }
+    }
+
+    fn generate_module_file(
+        &self,
+        _name: &str,
+        gen: &IDLModuleGenerator,
+        ctx: &CodegenContext,
+    ) -> Result<Module> {
+        ctx.use_sibling("entryNames", "entryNames", true);
+        Ok(Module::new()
+            .docs("Hooks.")
+            .items(gen.script_fns.iter().map(|f| {
+                Const::new(
+                    &format!("use_{}", f.name()),
+                    Expr::path(&format!("entryNames.{}", f.name())),
+                )
+            })))
+    }
+
+    fn generate_index_items(
+        &self,
+        gen: &IDLModuleGenerator,
+        ctx: &CodegenContext,
+    ) -> Result<Vec<Stmt>> {
+        if !gen.has_entrypoints() {
+            return Ok(vec![]);
+        }
+        Ok(vec![Export::namespace(
+            "hooks",
+            &ctx.import_path("./hooks"),
+        )
+        .into()])
+    }
+}
+
+/// Declares a file which is already generated for each module.
+struct CollidingGenerator;
+
+impl Generator for CollidingGenerator {
+    fn module_files(&self, _gen: &IDLModuleGenerator, _ctx: &CodegenContext) -> Vec<String> {
+        vec!["payloads".to_string()]
+    }
+}
+
+#[test]
+fn generator_adds_module_files_and_index_items() -> Result<()> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let files = IDLPackageGenerator::new(&idl, true)
+        .with_target(EmitTarget::TypeScript)
+        .with_generator(Arc::new(HooksGenerator))
+        .generate_all()?;
+
+    let hooks = files
+        .get(Path::new("vault/hooks.ts"))
+        .context("missing hooks")?
+        .to_string();
+    assert!(hooks.contains("import * as entryNames from \"./entryNames.js\";"));
+    assert!(hooks.contains("export const use_deposit = entryNames.deposit;"));
+    assert!(!files.contains_key(Path::new("0x1_coin/hooks.ts")));
+
+    let index = files
+        .get(Path::new("vault/index.ts"))
+        .context("missing index")?
+        .to_string();
+    assert!(index.contains("export * as hooks from \"./hooks.js\";"));
+    Ok(())
+}
+
+#[test]
+fn generator_files_must_not_collide() -> Result<()> {
+    let idl = load_fixture("coin_demo")?.idl;
+    let result = IDLPackageGenerator::new(&idl, true)
+        .with_generator(Arc::new(CollidingGenerator))
+        .generate_all();
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn registry_resolves_generators_by_name() {
+    let mut registry = GeneratorRegistry::default();
+    registry.register("hooks", HooksGenerator);
+    assert_eq!(registry.names(), vec!["hooks"]);
+    assert!(registry.get("hooks").is_ok());
+    assert!(registry.get("analytics").is_err());
+}
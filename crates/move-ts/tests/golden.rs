@@ -0,0 +1,87 @@
+//! Checks that generated output is byte-identical across runs and matches the golden files in
+//! `tests/golden`.
+//!
+//! To update the golden files after an intended change to the generated code, run:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test -p move-ts --test golden
+//! ```
+
+mod common;
+
+use anyhow::*;
+use common::{load_fixture, test_path, Fixture};
+use move_ts::{emit::EmitTarget, ordering::DeclarationOrder, CodeText};
+use std::collections::BTreeMap;
+
+fn generate(fixture: &Fixture, order: DeclarationOrder) -> Result<BTreeMap<String, CodeText>> {
+    common::generate(fixture, EmitTarget::TypeScript, order)
+}
+
+/// Concatenates generated files into a single golden file.
+fn render(files: &BTreeMap<String, CodeText>) -> String {
+    files
+        .iter()
+        .map(|(path, code)| format!("=== {}\n{}", path, code))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn output_is_identical_across_runs() -> Result<()> {
+    let first = render(&generate(
+        &load_fixture("coin_demo")?,
+        DeclarationOrder::Declaration,
+    )?);
+    let second = render(&generate(
+        &load_fixture("coin_demo")?,
+        DeclarationOrder::Declaration,
+    )?);
+    assert_eq!(first, second);
+    Ok(())
+}
+
+#[test]
+fn output_matches_golden_file() -> Result<()> {
+    let output = render(&generate(
+        &load_fixture("coin_demo")?,
+        DeclarationOrder::Declaration,
+    )?);
+    let path = test_path("golden/coin_demo.txt");
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, &output)?;
+    }
+    let golden = std::fs::read_to_string(&path)?;
+    assert!(
+        output == golden,
+        "generated output differs from tests/golden/coin_demo.txt, run `UPDATE_SNAPSHOTS=1 cargo test -p move-ts --test golden` to update it"
+    );
+    Ok(())
+}
+
+#[test]
+fn name_order_ignores_idl_order() -> Result<()> {
+    let fixture = load_fixture("coin_demo")?;
+    let mut reversed = fixture.clone();
+    for module in reversed
+        .idl
+        .modules
+        .values_mut()
+        .chain(reversed.idl.dependencies.values_mut())
+    {
+        module.structs.reverse();
+        module.functions.reverse();
+    }
+    // `idl.ts` embeds the IDL as is.
+    let without_idl = |files: BTreeMap<String, CodeText>| {
+        files
+            .into_iter()
+            .filter(|(path, _)| !path.ends_with("/idl.ts"))
+            .collect()
+    };
+    assert_eq!(
+        render(&without_idl(generate(&fixture, DeclarationOrder::Name)?)),
+        render(&without_idl(generate(&reversed, DeclarationOrder::Name)?))
+    );
+    Ok(())
+}
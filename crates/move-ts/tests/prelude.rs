@@ -0,0 +1,73 @@
+//! Tests of the output which depends on the helpers of the bundled prelude.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, load_fixture};
+use move_ts::{
+    emit::EmitTarget, idl_package::IDLPackageGenerator, prelude::PreludeConfig, CodeText,
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+fn generate(prelude: PreludeConfig) -> Result<BTreeMap<PathBuf, CodeText>> {
+    let fixture = load_fixture("coin_demo")?;
+    IDLPackageGenerator::new(&fixture.idl, true)
+        .with_prelude(prelude)
+        .with_enums(fixture.enums)
+        .with_target(EmitTarget::TypeScript)
+        .generate_all()
+}
+
+fn file(files: &BTreeMap<PathBuf, CodeText>, path: &str) -> Result<String> {
+    Ok(files
+        .get(&PathBuf::from(path))
+        .with_context(|| format!("missing {}", path))?
+        .to_string())
+}
+
+#[test]
+fn bcs_builders_need_the_bundled_prelude() -> Result<()> {
+    let files = generate(PreludeConfig::default())?;
+    assert!(!files.contains_key(&PathBuf::from("vault/bcs.ts")));
+    assert!(!file(&files, "vault/index.ts")?.contains("bcs"));
+
+    let files = generate(bundled())?;
+    assert!(files.contains_key(&PathBuf::from("prelude.ts")));
+    assert!(file(&files, "vault/bcs.ts")?.contains("from \"../prelude.js\";"));
+    assert!(file(&files, "vault/index.ts")?.contains("export * as bcs from \"./bcs.js\";"));
+    Ok(())
+}
+
+#[test]
+fn abort_parsing_needs_the_bundled_prelude() -> Result<()> {
+    let files = generate(PreludeConfig::default())?;
+    assert!(!file(&files, "errmap.ts")?.contains("parseAbort"));
+    assert!(!file(&files, "index.ts")?.contains("parseAbort"));
+
+    let files = generate(bundled())?;
+    assert!(file(&files, "errmap.ts")?.contains("export const parseAbort = ("));
+    assert!(file(&files, "index.ts")?
+        .contains("export { parseAbort as coin_demo_parse_abort } from \"./errmap.js\";"));
+    Ok(())
+}
+
+#[test]
+fn is_error_needs_the_bundled_prelude() -> Result<()> {
+    let files = generate(PreludeConfig::default())?;
+    assert!(!file(&files, "0xa_coin/errors.ts")?.contains("isError"));
+
+    let files = generate(bundled())?;
+    assert!(file(&files, "0xa_coin/errors.ts")?.contains("export const isError = ("));
+    Ok(())
+}
+
+#[test]
+fn enum_decoders_need_the_bundled_prelude() -> Result<()> {
+    let files = generate(PreludeConfig::default())?;
+    assert!(!files.contains_key(&PathBuf::from("0xa_coin/decoders.ts")));
+    assert!(!file(&files, "0xa_coin/index.ts")?.contains("decoders"));
+
+    let files = generate(bundled())?;
+    assert!(files.contains_key(&PathBuf::from("0xa_coin/decoders.ts")));
+    Ok(())
+}
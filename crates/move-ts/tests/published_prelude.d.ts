@@ -0,0 +1,41 @@
+/**
+ * Declarations of the helpers provided by the published `@movingco/prelude` package.
+ *
+ * Code generated without the bundled prelude may only use these.
+ */
+
+/** A value convertible to a `u64`. */
+export type U64 = string | number | bigint;
+
+/** A value convertible to a `u128`. */
+export type U128 = string | number | bigint;
+
+/** A hex string or raw bytes. */
+export type HexStringArg = string | Uint8Array;
+
+/** An account address. */
+export type RawAddress = HexStringArg;
+
+/** A signer, represented by its address. */
+export type RawSigner = HexStringArg;
+
+/** A `vector<u8>`. */
+export type ByteString = HexStringArg;
+
+/** Serializers for JSON script function arguments. */
+export declare const serializers: {
+  readonly u64: (value: U64) => string;
+  readonly u128: (value: U128) => string;
+  readonly hexString: (value: HexStringArg) => string;
+};
+
+/** Definition of a Move module. */
+export interface MoveModuleDefinition<A extends string, M extends string> {
+  readonly ADDRESS: A;
+  readonly FULL_NAME: `${A}::${M}`;
+  readonly NAME: M;
+  readonly errorCodes: Readonly<Record<string, unknown>>;
+  readonly functions: Readonly<Record<string, unknown>>;
+  readonly resources: Readonly<Record<string, string>>;
+  readonly structs: Readonly<Record<string, string>>;
+}
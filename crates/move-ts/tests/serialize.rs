@@ -0,0 +1,205 @@
+//! Tests of the serialization and encoding of entry function arguments.
+
+mod common;
+
+use anyhow::*;
+use common::load_fixture;
+use move_idl::IDLType;
+use move_ts::{
+    ast::{Const, Expr, Module},
+    chain::Chain,
+    idl_type::{encode_arg, encode_move_call_arg, serialize_arg},
+    CodegenContext,
+};
+use serde_json::json;
+
+/// Prints the expression generated for `args.value` of the type described by `ty`.
+fn print_arg(
+    fixture: &str,
+    chain: Chain,
+    ty: serde_json::Value,
+    generate: fn(&str, &IDLType, &CodegenContext) -> Result<Expr>,
+) -> Result<String> {
+    let idl = load_fixture(fixture)?.idl;
+    let ctx = CodegenContext::new(&idl).with_chain(chain);
+    let ty: IDLType = serde_json::from_value(ty)?;
+    let expr = generate("args.value", &ty, &ctx)?;
+    let code = ctx
+        .print(&Module::new().item(Const::new("value", expr)))?
+        .to_string();
+    Ok(code
+        .trim_start_matches("export const value = ")
+        .trim_end_matches(";\n")
+        .to_string())
+}
+
+/// Prints the expression serializing `args.value` into a JSON payload.
+fn serialize(ty: serde_json::Value) -> Result<String> {
+    print_arg("object_market", Chain::Aptos, ty, serialize_arg)
+}
+
+/// Prints the expression BCS-encoding `args.value`.
+fn encode(ty: serde_json::Value) -> Result<String> {
+    print_arg("object_market", Chain::Aptos, ty, encode_arg)
+}
+
+/// Prints the expression serializing `args.value` into a JSON payload on Sui.
+fn serialize_sui(ty: serde_json::Value) -> Result<String> {
+    print_arg("sui_market", Chain::Sui, ty, serialize_arg)
+}
+
+/// Prints the argument of a Sui Move call passing `args.value`.
+fn encode_sui(ty: serde_json::Value) -> Result<String> {
+    print_arg("sui_market", Chain::Sui, ty, encode_move_call_arg)
+}
+
+#[test]
+fn primitives() -> Result<()> {
+    assert_eq!(serialize(json!("bool"))?, "args.value");
+    assert_eq!(serialize(json!("u8"))?, "args.value");
+    assert_eq!(serialize(json!("u64"))?, "p.serializers.u64(args.value)");
+    assert_eq!(serialize(json!("u128"))?, "p.serializers.u128(args.value)");
+    assert_eq!(
+        serialize(json!("address"))?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(
+        serialize(json!("signer"))?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(serialize(json!({ "type_param": 0 }))?, "args.value");
+    Ok(())
+}
+
+#[test]
+fn vectors() -> Result<()> {
+    assert_eq!(
+        serialize(json!({ "vector": "u8" }))?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(serialize(json!({ "vector": "bool" }))?, "args.value");
+    assert_eq!(
+        serialize(json!({ "vector": { "vector": "bool" } }))?,
+        "args.value"
+    );
+    assert_eq!(
+        serialize(json!({ "vector": "address" }))?,
+        "args.value.map((inner_args__value) =>\n  p.serializers.hexString(inner_args__value)\n)"
+    );
+    assert_eq!(
+        serialize(json!({ "vector": { "vector": "u8" } }))?,
+        "args.value.map((inner_args__value) =>\n  p.serializers.hexString(inner_args__value)\n)"
+    );
+    assert_eq!(
+        serialize(json!({ "vector": { "vector": "u64" } }))?,
+        "args.value.map((inner_args__value) =>\n  inner_args__value.map((inner_inner_args__value) =>\n    p.serializers.u64(inner_inner_args__value)\n  )\n)"
+    );
+    Ok(())
+}
+
+#[test]
+fn strings() -> Result<()> {
+    let string = json!({ "struct": { "name": "0x1::string::String", "ty_args": [] } });
+    assert_eq!(serialize(string.clone())?, "args.value");
+    assert_eq!(encode(string)?, "p.bcs.string(args.value)");
+    assert_eq!(
+        serialize(json!({ "struct": { "name": "0x1::ASCII::String", "ty_args": [] } }))?,
+        "args.value"
+    );
+    Ok(())
+}
+
+#[test]
+fn aptos_objects() -> Result<()> {
+    let object = json!({
+        "struct": {
+            "name": "0x1::object::Object",
+            "ty_args": [{ "struct": { "name": "0x3::market::Listing", "ty_args": [] } }]
+        }
+    });
+    assert_eq!(
+        serialize(object.clone())?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(encode(object)?, "p.bcs.address(args.value, ADDRESS_LENGTH)");
+    // Sui objects are not objects on Aptos.
+    let sui_id = json!({ "struct": { "name": "0x2::object::ID", "ty_args": [] } });
+    assert!(serialize(sui_id.clone()).is_err());
+    assert!(encode(sui_id).is_err());
+    Ok(())
+}
+
+#[test]
+fn sui_objects() -> Result<()> {
+    let id = json!({ "struct": { "name": "0x2::object::ID", "ty_args": [] } });
+    assert_eq!(
+        serialize_sui(id.clone())?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(
+        encode_sui(id)?,
+        "{ pure: p.bcs.address(args.value, ADDRESS_LENGTH) }"
+    );
+
+    // Objects are passed by reference to their ID, so they cannot be part of a pure value.
+    let object = json!({ "struct": { "name": "0x3::market::Ticket", "ty_args": [] } });
+    assert_eq!(
+        serialize_sui(object.clone())?,
+        "p.serializers.hexString(args.value)"
+    );
+    assert_eq!(
+        encode_sui(object.clone())?,
+        "{ object: p.serializers.hexString(args.value) }"
+    );
+    assert!(encode_sui(json!({ "vector": object })).is_err());
+
+    // Aptos objects are not objects on Sui.
+    let aptos_object = json!({
+        "struct": {
+            "name": "0x1::object::Object",
+            "ty_args": [{ "struct": { "name": "0x3::market::Ticket", "ty_args": [] } }]
+        }
+    });
+    assert!(serialize_sui(aptos_object.clone()).is_err());
+    assert!(encode_sui(aptos_object).is_err());
+    Ok(())
+}
+
+#[test]
+fn sui_pure_values() -> Result<()> {
+    assert_eq!(encode_sui(json!("u64"))?, "{ pure: p.bcs.u64(args.value) }");
+    assert_eq!(
+        encode_sui(json!({ "vector": "u8" }))?,
+        "{ pure: p.bcs.bytes(args.value) }"
+    );
+    Ok(())
+}
+
+#[test]
+fn options() -> Result<()> {
+    let option = json!({ "struct": { "name": "0x1::option::Option", "ty_args": ["u64"] } });
+    assert_eq!(
+        serialize(option.clone())?,
+        "args.value == null ? [] : [p.serializers.u64(args.value)]"
+    );
+    assert_eq!(
+        encode(option)?,
+        "p.bcs.option(args.value, (inner_args__value) =>\n  p.bcs.u64(inner_args__value)\n)"
+    );
+    Ok(())
+}
+
+#[test]
+fn unsupported_types_are_rejected() -> Result<()> {
+    for ty in [
+        json!({ "tuple": ["u64"] }),
+        json!({ "struct": { "name": "0x3::market::Listing", "ty_args": [] } }),
+        json!({ "struct": { "name": "0x3::market::Missing", "ty_args": [] } }),
+        json!({ "vector": { "struct": { "name": "0x2::tx_context::TxContext", "ty_args": [] } } }),
+        json!({ "struct": { "name": "0x1::option::Option", "ty_args": [] } }),
+    ] {
+        assert!(serialize(ty.clone()).is_err(), "{}", ty);
+        assert!(encode(ty.clone()).is_err(), "{}", ty);
+    }
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Compares the output of every generator against the snapshots in `tests/snapshots`.
+//!
+//! Each fixture in `tests/fixtures` has a snapshot directory containing the files generated for
+//! all emit targets and the package files. After an intended change of the output, update the
+//! snapshots with:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test -p move-ts --test snapshots
+//! ```
+
+mod common;
+
+use anyhow::*;
+use common::{fixture_names, generate, load_fixture, test_path};
+use move_ts::{
+    emit::{EmitTarget, ModuleFormat, OutputFormat},
+    idl_package::IDLPackageGenerator,
+    ordering::DeclarationOrder,
+};
+use std::{collections::BTreeMap, path::Path};
+
+const TARGETS: &[EmitTarget] = &[
+    EmitTarget::TypeScript,
+    EmitTarget::JavaScript(ModuleFormat::Esm),
+    EmitTarget::JavaScript(ModuleFormat::CommonJs),
+    EmitTarget::Declarations(ModuleFormat::Esm),
+    EmitTarget::Declarations(ModuleFormat::CommonJs),
+];
+
+/// Generates all snapshot files of a fixture, keyed by path.
+fn generate_snapshot(name: &str) -> Result<BTreeMap<String, String>> {
+    let fixture = load_fixture(name)?;
+    let mut files = BTreeMap::new();
+    for target in TARGETS {
+        for (path, code) in generate(&fixture, *target, DeclarationOrder::default())? {
+            files.insert(path, code.to_string());
+        }
+    }
+
+    for (path, code) in IDLPackageGenerator::new(&fixture.idl, true)
+        .generate_package_files("0.1.0", OutputFormat::TypeScript)?
+    {
+        files.insert(path.to_string_lossy().replace('\\', "/"), code.to_string());
+    }
+    Ok(files)
+}
+
+/// Reads all files in a directory, keyed by path relative to `root`.
+fn read_dir_files(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir_files(root, &path, files)?;
+        } else {
+            let relative = path
+                .strip_prefix(root)?
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.insert(relative, std::fs::read_to_string(&path)?);
+        }
+    }
+    Ok(())
+}
+
+/// Describes the first difference between an expected and an actual file.
+fn describe_difference(expected: &str, actual: &str) -> String {
+    let line = expected
+        .lines()
+        .zip(actual.lines())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+    format!(
+        "first difference at line {}:\n  snapshot: {:?}\n  actual:   {:?}",
+        line + 1,
+        expected.lines().nth(line).unwrap_or("<end of file>"),
+        actual.lines().nth(line).unwrap_or("<end of file>"),
+    )
+}
+
+fn write_snapshot(dir: &Path, files: &BTreeMap<String, String>) -> Result<()> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)?;
+    }
+    for (path, code) in files {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, code)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn snapshots() -> Result<()> {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = vec![];
+    for name in fixture_names()? {
+        let dir = test_path("snapshots").join(&name);
+        let actual = generate_snapshot(&name)?;
+        if update {
+            write_snapshot(&dir, &actual)?;
+            continue;
+        }
+
+        let mut expected = BTreeMap::new();
+        read_dir_files(&dir, &dir, &mut expected)?;
+        for (path, code) in actual.iter() {
+            match expected.get(path) {
+                None => failures.push(format!("{}/{}: missing snapshot", name, path)),
+                Some(snapshot) if snapshot != code => failures.push(format!(
+                    "{}/{}: {}",
+                    name,
+                    path,
+                    describe_difference(snapshot, code)
+                )),
+                Some(_) => {}
+            }
+        }
+        for path in expected.keys().filter(|path| !actual.contains_key(*path)) {
+            failures.push(format!("{}/{}: file is no longer generated", name, path));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) differ, run `UPDATE_SNAPSHOTS=1 cargo test -p move-ts --test snapshots` to update them:\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+    Ok(())
+}
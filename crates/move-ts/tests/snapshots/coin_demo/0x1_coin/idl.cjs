@@ -0,0 +1,9 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = { errors: {}, functions: [], module_id: "0x1::coin", structs: [] };
+exports.idl = idl;
@@ -0,0 +1,13 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x1::coin";
+  readonly structs: readonly [];
+};
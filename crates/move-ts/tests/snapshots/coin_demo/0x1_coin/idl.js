@@ -0,0 +1,13 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x1::coin",
+  structs: [],
+};
@@ -0,0 +1,23 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0xa::coin::transfer`.
+ *
+ * Transfers coins.
+ */
+export declare const transfer: (
+  { args, typeArgs }: mod.TransferArgs
+) => Uint8Array;
+
+/** BCS-encoded `TransactionPayload` calling `0xa::coin::init`. */
+export declare const init: () => Uint8Array;
@@ -0,0 +1,51 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0xa::coin::transfer`.
+ *
+ * Transfers coins.
+ */
+export const transfer = ({ args, typeArgs }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0xa",
+      module: "coin",
+      function: "transfer",
+      typeArgs: [typeArgs.CoinType],
+      args: [
+        p.bcs.address(args.to, ADDRESS_LENGTH),
+        p.bcs.u64(args.amount),
+        p.bcs.bytes(args.memo),
+        p.bcs.vector(args.tags, (inner_args__tags) =>
+          p.bcs.bytes(inner_args__tags)
+        ),
+        p.bcs.vector(args.recipients, (inner_args__recipients) =>
+          p.bcs.address(inner_args__recipients, ADDRESS_LENGTH)
+        ),
+        p.bcs.string(args.label),
+      ],
+    },
+    ADDRESS_LENGTH
+  );
+
+/** BCS-encoded `TransactionPayload` calling `0xa::coin::init`. */
+export const init = () =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0xa",
+      module: "coin",
+      function: "init",
+      typeArgs: [],
+      args: [],
+    },
+    ADDRESS_LENGTH
+  );
@@ -0,0 +1,28 @@
+/**
+ * Module constants.
+ *
+ * @module
+ */
+
+/** Transfer fee, in basis points. */
+const FEE_BPS = 30n;
+exports.FEE_BPS = FEE_BPS;
+
+const MAX_SUPPLY = 340282366920938463463374607431768211455n;
+exports.MAX_SUPPLY = MAX_SUPPLY;
+
+const DECIMALS = 8;
+exports.DECIMALS = DECIMALS;
+
+/** Account collecting the fees. */
+const TREASURY = "0xa";
+exports.TREASURY = TREASURY;
+
+const SEED = "0x636f696e";
+exports.SEED = SEED;
+
+const TIERS = [1n, 1000n];
+exports.TIERS = TIERS;
+
+const PAUSED = false;
+exports.PAUSED = PAUSED;
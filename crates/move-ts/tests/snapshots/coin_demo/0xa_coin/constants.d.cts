@@ -0,0 +1,21 @@
+/**
+ * Module constants.
+ *
+ * @module
+ */
+
+/** Transfer fee, in basis points. */
+export declare const FEE_BPS: bigint;
+
+export declare const MAX_SUPPLY: bigint;
+
+export declare const DECIMALS: 8;
+
+/** Account collecting the fees. */
+export declare const TREASURY: "0xa";
+
+export declare const SEED: "0x636f696e";
+
+export declare const TIERS: readonly [bigint, bigint];
+
+export declare const PAUSED: false;
@@ -0,0 +1,21 @@
+/**
+ * Module constants.
+ *
+ * @module
+ */
+
+/** Transfer fee, in basis points. */
+export const FEE_BPS = 30n;
+
+export const MAX_SUPPLY = 340282366920938463463374607431768211455n;
+
+export const DECIMALS = 8;
+
+/** Account collecting the fees. */
+export const TREASURY = "0xa";
+
+export const SEED = "0x636f696e";
+
+export const TIERS = [1n, 1000n] as const;
+
+export const PAUSED = false;
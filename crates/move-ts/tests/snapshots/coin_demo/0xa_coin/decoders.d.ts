@@ -0,0 +1,14 @@
+/**
+ * BCS decoders of the enums of the module.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/** Decodes a BCS-encoded `0xa::coin::Status`. */
+export declare const Status: (reader: p.BcsReader) => mod.IStatus;
@@ -0,0 +1,25 @@
+/**
+ * BCS decoders of the enums of the module.
+ *
+ * @module
+ */
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/** Decodes a BCS-encoded `0xa::coin::Status`. */
+export const Status = (reader) =>
+  reader.variant([
+    () => ({ __variant: "Active" }),
+    (reader) => ({
+      __variant: "Frozen",
+      reason: reader.string(),
+      until: reader.u64(),
+    }),
+    (reader) => ({
+      __variant: "Limited",
+      max_amount: reader.u64(),
+      allowed: reader.vector((reader) => reader.address(ADDRESS_LENGTH)),
+      memo: reader.bytes(),
+    }),
+  ]);
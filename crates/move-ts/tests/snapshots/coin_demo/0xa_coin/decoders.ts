@@ -0,0 +1,28 @@
+/**
+ * BCS decoders of the enums of the module.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32 as const;
+
+/** Decodes a BCS-encoded `0xa::coin::Status`. */
+export const Status = (reader: p.BcsReader): mod.IStatus =>
+  reader.variant([
+    (): mod.IStatus => ({ __variant: "Active" }),
+    (reader): mod.IStatus => ({
+      __variant: "Frozen",
+      reason: reader.string(),
+      until: reader.u64(),
+    }),
+    (reader): mod.IStatus => ({
+      __variant: "Limited",
+      max_amount: reader.u64(),
+      allowed: reader.vector((reader) => reader.address(ADDRESS_LENGTH)),
+      memo: reader.bytes(),
+    }),
+  ]);
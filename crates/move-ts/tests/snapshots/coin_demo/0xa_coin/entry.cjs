@@ -0,0 +1,35 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Transfers coins. */
+const transfer = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0xa::coin::transfer",
+  type_arguments: [typeArgs.CoinType],
+  arguments: [
+    p.serializers.hexString(args.to),
+    p.serializers.u64(args.amount),
+    p.serializers.hexString(args.memo),
+    args.tags.map((inner_args__tags) =>
+      p.serializers.hexString(inner_args__tags)
+    ),
+    args.recipients.map((inner_args__recipients) =>
+      p.serializers.hexString(inner_args__recipients)
+    ),
+    args.label,
+  ],
+});
+exports.transfer = transfer;
+
+const init = () => ({
+  type: "script_function_payload",
+  function: "0xa::coin::init",
+  type_arguments: [],
+  arguments: [],
+});
+exports.init = init;
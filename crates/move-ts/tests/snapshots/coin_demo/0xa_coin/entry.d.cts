@@ -0,0 +1,16 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+/** Transfers coins. */
+export declare const transfer: (
+  { args, typeArgs }: mod.TransferArgs
+) => payloads.Transfer;
+
+export declare const init: () => payloads.Init;
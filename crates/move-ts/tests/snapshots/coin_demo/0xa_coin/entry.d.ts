@@ -0,0 +1,16 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Transfers coins. */
+export declare const transfer: (
+  { args, typeArgs }: mod.TransferArgs
+) => payloads.Transfer;
+
+export declare const init: () => payloads.Init;
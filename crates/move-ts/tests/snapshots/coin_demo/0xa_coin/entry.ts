@@ -0,0 +1,37 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Transfers coins. */
+export const transfer = (
+  { args, typeArgs }: mod.TransferArgs
+): payloads.Transfer => ({
+  type: "script_function_payload",
+  function: "0xa::coin::transfer",
+  type_arguments: [typeArgs.CoinType],
+  arguments: [
+    p.serializers.hexString(args.to),
+    p.serializers.u64(args.amount),
+    p.serializers.hexString(args.memo),
+    args.tags.map((inner_args__tags) =>
+      p.serializers.hexString(inner_args__tags)
+    ),
+    args.recipients.map((inner_args__recipients) =>
+      p.serializers.hexString(inner_args__recipients)
+    ),
+    args.label,
+  ],
+});
+
+export const init = (): payloads.Init => ({
+  type: "script_function_payload",
+  function: "0xa::coin::init",
+  type_arguments: [],
+  arguments: [],
+});
@@ -0,0 +1,13 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0xa::coin::transfer`. */
+const transfer = "0xa::coin::transfer";
+exports.transfer = transfer;
+
+/** Script function type for `0xa::coin::init`. */
+const init = "0xa::coin::init";
+exports.init = init;
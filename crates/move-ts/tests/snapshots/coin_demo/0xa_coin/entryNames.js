@@ -0,0 +1,11 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0xa::coin::transfer`. */
+export const transfer = "0xa::coin::transfer";
+
+/** Script function type for `0xa::coin::init`. */
+export const init = "0xa::coin::init";
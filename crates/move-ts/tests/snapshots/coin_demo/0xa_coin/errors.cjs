@@ -0,0 +1,33 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Not enough coins. */
+const EINSUFFICIENT_BALANCE = {
+  category: null,
+  code: 1,
+  doc: "Not enough coins.",
+  name: "EINSUFFICIENT_BALANCE",
+  reason: 1,
+};
+exports.EINSUFFICIENT_BALANCE = EINSUFFICIENT_BALANCE;
+
+const ENOT_OWNER = {
+  category: "INVALID_ARGUMENT",
+  code: 65538,
+  name: "ENOT_OWNER",
+  reason: 2,
+};
+exports.ENOT_OWNER = ENOT_OWNER;
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+const isError = (code, error) => p.matchesError(code, error);
+exports.isError = isError;
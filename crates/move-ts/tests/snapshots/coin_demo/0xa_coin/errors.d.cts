@@ -0,0 +1,33 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+
+/** Not enough coins. */
+export declare const EINSUFFICIENT_BALANCE: {
+  readonly category: null;
+  readonly code: 1;
+  readonly doc: "Not enough coins.";
+  readonly name: "EINSUFFICIENT_BALANCE";
+  readonly reason: 1;
+};
+
+export declare const ENOT_OWNER: {
+  readonly category: "INVALID_ARGUMENT";
+  readonly code: 65538;
+  readonly name: "ENOT_OWNER";
+  readonly reason: 2;
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export declare const isError: (
+  code: number,
+  error: typeof EINSUFFICIENT_BALANCE | typeof ENOT_OWNER
+) => boolean;
@@ -0,0 +1,30 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Not enough coins. */
+export const EINSUFFICIENT_BALANCE = {
+  category: null,
+  code: 1,
+  doc: "Not enough coins.",
+  name: "EINSUFFICIENT_BALANCE",
+  reason: 1,
+};
+
+export const ENOT_OWNER = {
+  category: "INVALID_ARGUMENT",
+  code: 65538,
+  name: "ENOT_OWNER",
+  reason: 2,
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export const isError = (code, error) => p.matchesError(code, error);
@@ -0,0 +1,74 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  doc: "A basic coin.",
+  errors: {
+    "1": { doc: "Not enough coins.", name: "EINSUFFICIENT_BALANCE" },
+    "65538": { name: "ENOT_OWNER" },
+  },
+  functions: [
+    {
+      args: [
+        { name: "to", ty: "address" },
+        { name: "amount", ty: "u64" },
+        { name: "memo", ty: { vector: "u8" } },
+        { name: "tags", ty: { vector: { vector: "u8" } } },
+        { name: "recipients", ty: { vector: "address" } },
+        { name: "label", ty: { struct: { name: "0x1::string::String" } } },
+      ],
+      doc: "Transfers coins.",
+      name: "transfer",
+      ty_args: ["CoinType"],
+    },
+    { args: [], name: "init", ty_args: [] },
+  ],
+  module_id: "0xa::coin",
+  structs: [
+    {
+      abilities: ["store"],
+      doc: "A coin.",
+      fields: [{ doc: "Amount.", name: "value", ty: "u64" }],
+      name: "0xa::coin::Coin",
+      type_params: [{ is_phantom: true, name: "CoinType" }],
+    },
+    {
+      abilities: ["key"],
+      fields: [
+        {
+          name: "coin",
+          ty: {
+            struct: { name: "0xa::coin::Coin", ty_args: [{ type_param: 0 }] },
+          },
+        },
+        { name: "items", ty: { vector: { type_param: 1 } } },
+      ],
+      name: "0xa::coin::Holder",
+      type_params: [{ is_phantom: true, name: "T" }, { name: "Item" }],
+    },
+    {
+      abilities: ["drop"],
+      fields: [{ name: "dummy_field", ty: "bool" }],
+      name: "0xa::coin::Marker",
+    },
+    {
+      abilities: ["key"],
+      doc: "Status of an account.",
+      fields: [
+        { name: "status", ty: { struct: { name: "0xa::coin::Status" } } },
+        {
+          name: "slots",
+          ty: {
+            vector: { struct: { name: "0xa::coin::Slot", ty_args: ["u64"] } },
+          },
+        },
+      ],
+      name: "0xa::coin::Account",
+    },
+  ],
+};
+exports.idl = idl;
@@ -0,0 +1,116 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly doc: "A basic coin.";
+  readonly errors: {
+    readonly "1": {
+      readonly doc: "Not enough coins.";
+      readonly name: "EINSUFFICIENT_BALANCE";
+    };
+    readonly "65538": { readonly name: "ENOT_OWNER" };
+  };
+  readonly functions: readonly [
+    {
+      readonly args: readonly [
+        { readonly name: "to"; readonly ty: "address" },
+        { readonly name: "amount"; readonly ty: "u64" },
+        { readonly name: "memo"; readonly ty: { readonly vector: "u8" } },
+        {
+          readonly name: "tags";
+          readonly ty: { readonly vector: { readonly vector: "u8" } };
+        },
+        {
+          readonly name: "recipients";
+          readonly ty: { readonly vector: "address" };
+        },
+        {
+          readonly name: "label";
+          readonly ty: {
+            readonly struct: { readonly name: "0x1::string::String" };
+          };
+        }
+      ];
+      readonly doc: "Transfers coins.";
+      readonly name: "transfer";
+      readonly ty_args: readonly ["CoinType"];
+    },
+    {
+      readonly args: readonly [];
+      readonly name: "init";
+      readonly ty_args: readonly [];
+    }
+  ];
+  readonly module_id: "0xa::coin";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["store"];
+      readonly doc: "A coin.";
+      readonly fields: readonly [
+        { readonly doc: "Amount."; readonly name: "value"; readonly ty: "u64" }
+      ];
+      readonly name: "0xa::coin::Coin";
+      readonly type_params: readonly [
+        { readonly is_phantom: true; readonly name: "CoinType" }
+      ];
+    },
+    {
+      readonly abilities: readonly ["key"];
+      readonly fields: readonly [
+        {
+          readonly name: "coin";
+          readonly ty: {
+            readonly struct: {
+              readonly name: "0xa::coin::Coin";
+              readonly ty_args: readonly [{ readonly type_param: 0 }];
+            };
+          };
+        },
+        {
+          readonly name: "items";
+          readonly ty: { readonly vector: { readonly type_param: 1 } };
+        }
+      ];
+      readonly name: "0xa::coin::Holder";
+      readonly type_params: readonly [
+        { readonly is_phantom: true; readonly name: "T" },
+        { readonly name: "Item" }
+      ];
+    },
+    {
+      readonly abilities: readonly ["drop"];
+      readonly fields: readonly [
+        { readonly name: "dummy_field"; readonly ty: "bool" }
+      ];
+      readonly name: "0xa::coin::Marker";
+    },
+    {
+      readonly abilities: readonly ["key"];
+      readonly doc: "Status of an account.";
+      readonly fields: readonly [
+        {
+          readonly name: "status";
+          readonly ty: {
+            readonly struct: { readonly name: "0xa::coin::Status" };
+          };
+        },
+        {
+          readonly name: "slots";
+          readonly ty: {
+            readonly vector: {
+              readonly struct: {
+                readonly name: "0xa::coin::Slot";
+                readonly ty_args: readonly ["u64"];
+              };
+            };
+          };
+        }
+      ];
+      readonly name: "0xa::coin::Account";
+    }
+  ];
+};
@@ -0,0 +1,98 @@
+/**
+ * A basic coin.
+ *
+ * **Module ID:** `0xa::coin`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+exports.decoders = require("./decoders.cjs");
+
+/** The address of the module. */
+const ADDRESS = "0xa";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0xa::coin";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "coin";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+exports.errors = require("./errors.cjs");
+
+exports.constants = require("./constants.cjs");
+
+/** Module error codes. */
+const errorCodes = {
+  "1": { doc: "Not enough coins.", name: "EINSUFFICIENT_BALANCE" },
+  "65538": { name: "ENOT_OWNER" },
+};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  init: { args: [], name: "init", ty_args: [] },
+  transfer: {
+    args: [
+      { name: "to", ty: "address" },
+      { name: "amount", ty: "u64" },
+      { name: "memo", ty: { vector: "u8" } },
+      { name: "tags", ty: { vector: { vector: "u8" } } },
+      { name: "recipients", ty: { vector: "address" } },
+      { name: "label", ty: { struct: { name: "0x1::string::String" } } },
+    ],
+    doc: "Transfers coins.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = {
+  Account: "0xa::coin::Account",
+  Holder: "0xa::coin::Holder",
+};
+exports.resources = resources;
+
+/** All struct types. */
+const structs = {
+  Account: "0xa::coin::Account",
+  Coin: "0xa::coin::Coin",
+  Holder: "0xa::coin::Holder",
+  Marker: "0xa::coin::Marker",
+};
+exports.structs = structs;
+
+/** Variant names of the enum types, in the order of their indices. */
+const enums = {
+  Slot: ["Empty", "Full"],
+  Status: ["Active", "Frozen", "Limited"],
+};
+exports.enums = enums;
+
+/** Payload generators for module `0xa::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A basic coin. */
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
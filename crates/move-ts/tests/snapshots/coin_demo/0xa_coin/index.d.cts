@@ -0,0 +1,203 @@
+/**
+ * A basic coin.
+ *
+ * **Module ID:** `0xa::coin`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+
+/**
+ * A coin.
+ *
+ * Type name: `0xa::coin::Coin`
+ */
+export interface ICoin {
+  /** Amount. */
+  value: p.U64;
+}
+
+/** Type name: `0xa::coin::Holder` */
+export interface IHolder<_Item = unknown> {
+  coin: ICoin;
+  items: ReadonlyArray<_Item>;
+}
+
+/**
+ * Status of an account.
+ *
+ * Type name: `0xa::coin::Account`
+ */
+export interface IAccount {
+  status: IStatus;
+  slots: ReadonlyArray<ISlot<p.U64>>;
+}
+
+/** Variant `Active` of `0xa::coin::Status`. */
+export interface IStatusActive {
+  __variant: "Active";
+}
+
+/**
+ * Frozen by the treasury.
+ *
+ * Variant `Frozen` of `0xa::coin::Status`.
+ */
+export interface IStatusFrozen {
+  __variant: "Frozen";
+  reason: string;
+  /** Unfreezing time, in seconds. */
+  until: p.U64;
+}
+
+/** Variant `Limited` of `0xa::coin::Status`. */
+export interface IStatusLimited {
+  __variant: "Limited";
+  max_amount: p.U64;
+  allowed: ReadonlyArray<p.RawAddress>;
+  memo: p.ByteString;
+}
+
+/**
+ * Whether an account may transfer coins.
+ *
+ * Type name: `0xa::coin::Status`
+ */
+export type IStatus = IStatusActive | IStatusFrozen | IStatusLimited;
+
+/** Variant `Empty` of `0xa::coin::Slot`. */
+export interface ISlotEmpty<_T = unknown> {
+  __variant: "Empty";
+}
+
+/** Variant `Full` of `0xa::coin::Slot`. */
+export interface ISlotFull<_T = unknown> {
+  __variant: "Full";
+  item: _T;
+}
+
+/** Type name: `0xa::coin::Slot` */
+export type ISlot<_T = unknown> = ISlotEmpty<_T> | ISlotFull<_T>;
+
+/** Payload arguments for {@link entry.transfer}. */
+export type TransferArgs = {
+  args: {
+    /** IDL type: `Address` */
+    to: p.RawAddress;
+    /** IDL type: `U64` */
+    amount: p.U64;
+    /** IDL type: `Vector(U8)` */
+    memo: p.ByteString;
+    /** IDL type: `Vector(Vector(U8))` */
+    tags: ReadonlyArray<p.ByteString>;
+    /** IDL type: `Vector(Address)` */
+    recipients: ReadonlyArray<p.RawAddress>;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] })` */
+    label: string;
+  };
+  typeArgs: { CoinType: string };
+};
+
+export * as entry from "./entry.cjs";
+export * as payloads from "./payloads.cjs";
+export * as entryNames from "./entryNames.cjs";
+export * as bcs from "./bcs.cjs";
+export { idl } from "./idl.cjs";
+export * as decoders from "./decoders.cjs";
+
+/** The address of the module. */
+export declare const ADDRESS: "0xa";
+
+/** The full module name. */
+export declare const FULL_NAME: "0xa::coin";
+
+/** The name of the module. */
+export declare const NAME: "coin";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+export * as errors from "./errors.cjs";
+
+export * as constants from "./constants.cjs";
+
+/** Module error codes. */
+export declare const errorCodes: {
+  readonly "1": {
+    readonly doc: "Not enough coins.";
+    readonly name: "EINSUFFICIENT_BALANCE";
+  };
+  readonly "65538": { readonly name: "ENOT_OWNER" };
+};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly init: {
+    readonly args: readonly [];
+    readonly name: "init";
+    readonly ty_args: readonly [];
+  };
+  readonly transfer: {
+    readonly args: readonly [
+      { readonly name: "to"; readonly ty: "address" },
+      { readonly name: "amount"; readonly ty: "u64" },
+      { readonly name: "memo"; readonly ty: { readonly vector: "u8" } },
+      {
+        readonly name: "tags";
+        readonly ty: { readonly vector: { readonly vector: "u8" } };
+      },
+      {
+        readonly name: "recipients";
+        readonly ty: { readonly vector: "address" };
+      },
+      {
+        readonly name: "label";
+        readonly ty: {
+          readonly struct: { readonly name: "0x1::string::String" };
+        };
+      }
+    ];
+    readonly doc: "Transfers coins.";
+    readonly name: "transfer";
+    readonly ty_args: readonly ["CoinType"];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: {
+  readonly Account: "0xa::coin::Account";
+  readonly Holder: "0xa::coin::Holder";
+};
+
+/** All struct types. */
+export declare const structs: {
+  readonly Account: "0xa::coin::Account";
+  readonly Coin: "0xa::coin::Coin";
+  readonly Holder: "0xa::coin::Holder";
+  readonly Marker: "0xa::coin::Marker";
+};
+
+/** Variant names of the enum types, in the order of their indices. */
+export declare const enums: {
+  readonly Slot: readonly ["Empty", "Full"];
+  readonly Status: readonly ["Active", "Frozen", "Limited"];
+};
+
+/** Payload generators for module `0xa::coin`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+/** A basic coin. */
+export declare const moduleDefinition: typeof moduleImpl;
@@ -0,0 +1,88 @@
+/**
+ * A basic coin.
+ *
+ * **Module ID:** `0xa::coin`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+export * as decoders from "./decoders.js";
+
+/** The address of the module. */
+export const ADDRESS = "0xa";
+
+/** The full module name. */
+export const FULL_NAME = "0xa::coin";
+
+/** The name of the module. */
+export const NAME = "coin";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+export * as errors from "./errors.js";
+
+export * as constants from "./constants.js";
+
+/** Module error codes. */
+export const errorCodes = {
+  "1": { doc: "Not enough coins.", name: "EINSUFFICIENT_BALANCE" },
+  "65538": { name: "ENOT_OWNER" },
+};
+
+/** All module function IDLs. */
+export const functions = {
+  init: { args: [], name: "init", ty_args: [] },
+  transfer: {
+    args: [
+      { name: "to", ty: "address" },
+      { name: "amount", ty: "u64" },
+      { name: "memo", ty: { vector: "u8" } },
+      { name: "tags", ty: { vector: { vector: "u8" } } },
+      { name: "recipients", ty: { vector: "address" } },
+      { name: "label", ty: { struct: { name: "0x1::string::String" } } },
+    ],
+    doc: "Transfers coins.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+};
+
+/** All struct types with ability `key`. */
+export const resources = {
+  Account: "0xa::coin::Account",
+  Holder: "0xa::coin::Holder",
+};
+
+/** All struct types. */
+export const structs = {
+  Account: "0xa::coin::Account",
+  Coin: "0xa::coin::Coin",
+  Holder: "0xa::coin::Holder",
+  Marker: "0xa::coin::Marker",
+};
+
+/** Variant names of the enum types, in the order of their indices. */
+export const enums = {
+  Slot: ["Empty", "Full"],
+  Status: ["Active", "Frozen", "Limited"],
+};
+
+/** Payload generators for module `0xa::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A basic coin. */
+export const moduleDefinition = moduleImpl;
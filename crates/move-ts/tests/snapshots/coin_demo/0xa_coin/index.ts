@@ -0,0 +1,184 @@
+/**
+ * A basic coin.
+ *
+ * **Module ID:** `0xa::coin`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/**
+ * A coin.
+ *
+ * Type name: `0xa::coin::Coin`
+ */
+export interface ICoin {
+  /** Amount. */
+  value: p.U64;
+}
+
+/** Type name: `0xa::coin::Holder` */
+export interface IHolder<_Item = unknown> {
+  coin: ICoin;
+  items: ReadonlyArray<_Item>;
+}
+
+/**
+ * Status of an account.
+ *
+ * Type name: `0xa::coin::Account`
+ */
+export interface IAccount {
+  status: IStatus;
+  slots: ReadonlyArray<ISlot<p.U64>>;
+}
+
+/** Variant `Active` of `0xa::coin::Status`. */
+export interface IStatusActive {
+  __variant: "Active";
+}
+
+/**
+ * Frozen by the treasury.
+ *
+ * Variant `Frozen` of `0xa::coin::Status`.
+ */
+export interface IStatusFrozen {
+  __variant: "Frozen";
+  reason: string;
+  /** Unfreezing time, in seconds. */
+  until: p.U64;
+}
+
+/** Variant `Limited` of `0xa::coin::Status`. */
+export interface IStatusLimited {
+  __variant: "Limited";
+  max_amount: p.U64;
+  allowed: ReadonlyArray<p.RawAddress>;
+  memo: p.ByteString;
+}
+
+/**
+ * Whether an account may transfer coins.
+ *
+ * Type name: `0xa::coin::Status`
+ */
+export type IStatus = IStatusActive | IStatusFrozen | IStatusLimited;
+
+/** Variant `Empty` of `0xa::coin::Slot`. */
+export interface ISlotEmpty<_T = unknown> {
+  __variant: "Empty";
+}
+
+/** Variant `Full` of `0xa::coin::Slot`. */
+export interface ISlotFull<_T = unknown> {
+  __variant: "Full";
+  item: _T;
+}
+
+/** Type name: `0xa::coin::Slot` */
+export type ISlot<_T = unknown> = ISlotEmpty<_T> | ISlotFull<_T>;
+
+/** Payload arguments for {@link entry.transfer}. */
+export type TransferArgs = {
+  args: {
+    /** IDL type: `Address` */
+    to: p.RawAddress;
+    /** IDL type: `U64` */
+    amount: p.U64;
+    /** IDL type: `Vector(U8)` */
+    memo: p.ByteString;
+    /** IDL type: `Vector(Vector(U8))` */
+    tags: ReadonlyArray<p.ByteString>;
+    /** IDL type: `Vector(Address)` */
+    recipients: ReadonlyArray<p.RawAddress>;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] })` */
+    label: string;
+  };
+  typeArgs: { CoinType: string };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+export * as decoders from "./decoders.js";
+
+/** The address of the module. */
+export const ADDRESS = "0xa" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0xa::coin" as const;
+
+/** The name of the module. */
+export const NAME = "coin" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+export * as errors from "./errors.js";
+
+export * as constants from "./constants.js";
+
+/** Module error codes. */
+export const errorCodes = {
+  "1": { doc: "Not enough coins.", name: "EINSUFFICIENT_BALANCE" },
+  "65538": { name: "ENOT_OWNER" },
+} as const;
+
+/** All module function IDLs. */
+export const functions = {
+  init: { args: [], name: "init", ty_args: [] },
+  transfer: {
+    args: [
+      { name: "to", ty: "address" },
+      { name: "amount", ty: "u64" },
+      { name: "memo", ty: { vector: "u8" } },
+      { name: "tags", ty: { vector: { vector: "u8" } } },
+      { name: "recipients", ty: { vector: "address" } },
+      { name: "label", ty: { struct: { name: "0x1::string::String" } } },
+    ],
+    doc: "Transfers coins.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = {
+  Account: "0xa::coin::Account",
+  Holder: "0xa::coin::Holder",
+} as const;
+
+/** All struct types. */
+export const structs = {
+  Account: "0xa::coin::Account",
+  Coin: "0xa::coin::Coin",
+  Holder: "0xa::coin::Holder",
+  Marker: "0xa::coin::Marker",
+} as const;
+
+/** Variant names of the enum types, in the order of their indices. */
+export const enums = {
+  Slot: ["Empty", "Full"],
+  Status: ["Active", "Frozen", "Limited"],
+} as const;
+
+/** Payload generators for module `0xa::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+/** A basic coin. */
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0xa",
+  "coin"
+> as typeof moduleImpl;
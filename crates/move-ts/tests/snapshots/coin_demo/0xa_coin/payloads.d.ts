@@ -0,0 +1,32 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
+
+/**
+ * Script function payload for `0xa::coin::transfer`.
+ *
+ * Transfers coins.
+ */
+export type Transfer = {
+  readonly type: "script_function_payload";
+  readonly function: "0xa::coin::transfer";
+  readonly arguments: [
+    to: string,
+    amount: string,
+    memo: string,
+    tags: ReadonlyArray<string>,
+    recipients: ReadonlyArray<string>,
+    label: string
+  ];
+  readonly type_arguments: [CoinType: string];
+};
+
+/** Script function payload for `0xa::coin::init`. */
+export type Init = {
+  readonly type: "script_function_payload";
+  readonly function: "0xa::coin::init";
+  readonly arguments: [];
+  readonly type_arguments: [];
+};
@@ -0,0 +1,25 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+import * as p from "./prelude.cjs";
+
+/** All errors in this package, keyed by module ID and error code. */
+export declare const errmap: {
+  readonly "0xa::coin": {
+    readonly "1": {
+      readonly doc: "Not enough coins.";
+      readonly name: "EINSUFFICIENT_BALANCE";
+    };
+    readonly "65538": { readonly name: "ENOT_OWNER" };
+  };
+};
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+export declare const parseAbort: (vmStatus: string) => p.DecodedAbort | null;
@@ -0,0 +1,22 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+import * as p from "./prelude.js";
+
+/** All errors in this package, keyed by module ID and error code. */
+export const errmap = {
+  "0xa::coin": {
+    "1": { doc: "Not enough coins.", name: "EINSUFFICIENT_BALANCE" },
+    "65538": { name: "ENOT_OWNER" },
+  },
+};
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+export const parseAbort = (vmStatus) => p.decodeAbort(errmap, vmStatus);
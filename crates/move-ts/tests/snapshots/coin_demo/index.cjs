@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `CoinDemo`.
+ *
+ * @module
+ */
+
+exports.coin_demo_0xa_coin = require("./0xa_coin/index.cjs");
+exports.coin_demo_vault = require("./vault/index.cjs");
+exports.coin_demo_0x1_coin = require("./0x1_coin/index.cjs");
+exports.coin_demo_errmap = require("./errmap.cjs").errmap;
+exports.coin_demo_parse_abort = require("./errmap.cjs").parseAbort;
@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `CoinDemo`.
+ *
+ * @module
+ */
+
+export * as coin_demo_0xa_coin from "./0xa_coin/index.cjs";
+export * as coin_demo_vault from "./vault/index.cjs";
+export * as coin_demo_0x1_coin from "./0x1_coin/index.cjs";
+export { errmap as coin_demo_errmap } from "./errmap.cjs";
+export { parseAbort as coin_demo_parse_abort } from "./errmap.cjs";
@@ -0,0 +1,24 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/** BCS-encoded `TransactionPayload` calling `0xa::vault::deposit`. */
+const deposit = ({ args, typeArgs }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0xa",
+      module: "vault",
+      function: "deposit",
+      typeArgs: [typeArgs.T],
+      args: [p.bcs.u64(args.amount)],
+    },
+    ADDRESS_LENGTH
+  );
+exports.deposit = deposit;
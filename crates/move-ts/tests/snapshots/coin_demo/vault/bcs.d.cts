@@ -0,0 +1,16 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/** BCS-encoded `TransactionPayload` calling `0xa::vault::deposit`. */
+export declare const deposit: (
+  { args, typeArgs }: mod.DepositArgs
+) => Uint8Array;
@@ -0,0 +1,23 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/** BCS-encoded `TransactionPayload` calling `0xa::vault::deposit`. */
+export const deposit = ({ args, typeArgs }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0xa",
+      module: "vault",
+      function: "deposit",
+      typeArgs: [typeArgs.T],
+      args: [p.bcs.u64(args.amount)],
+    },
+    ADDRESS_LENGTH
+  );
@@ -0,0 +1,24 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32 as const;
+
+/** BCS-encoded `TransactionPayload` calling `0xa::vault::deposit`. */
+export const deposit = ({ args, typeArgs }: mod.DepositArgs): Uint8Array =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0xa",
+      module: "vault",
+      function: "deposit",
+      typeArgs: [typeArgs.T],
+      args: [p.bcs.u64(args.amount)],
+    },
+    ADDRESS_LENGTH
+  );
@@ -0,0 +1,15 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+const deposit = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0xa::vault::deposit",
+  type_arguments: [typeArgs.T],
+  arguments: [p.serializers.u64(args.amount)],
+});
+exports.deposit = deposit;
@@ -0,0 +1,13 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+export declare const deposit: (
+  { args, typeArgs }: mod.DepositArgs
+) => payloads.Deposit;
@@ -0,0 +1,13 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+export declare const deposit: (
+  { args, typeArgs }: mod.DepositArgs
+) => payloads.Deposit;
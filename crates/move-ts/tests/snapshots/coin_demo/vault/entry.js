@@ -0,0 +1,14 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+export const deposit = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0xa::vault::deposit",
+  type_arguments: [typeArgs.T],
+  arguments: [p.serializers.u64(args.amount)],
+});
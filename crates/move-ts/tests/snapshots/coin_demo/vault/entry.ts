@@ -0,0 +1,18 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+export const deposit = (
+  { args, typeArgs }: mod.DepositArgs
+): payloads.Deposit => ({
+  type: "script_function_payload",
+  function: "0xa::vault::deposit",
+  type_arguments: [typeArgs.T],
+  arguments: [p.serializers.u64(args.amount)],
+});
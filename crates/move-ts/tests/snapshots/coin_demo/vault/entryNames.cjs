@@ -0,0 +1,9 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0xa::vault::deposit`. */
+const deposit = "0xa::vault::deposit";
+exports.deposit = deposit;
@@ -0,0 +1,8 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0xa::vault::deposit`. */
+export declare const deposit: "0xa::vault::deposit";
@@ -0,0 +1,39 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [
+    {
+      readonly args: readonly [{ readonly name: "amount"; readonly ty: "u64" }];
+      readonly name: "deposit";
+      readonly ty_args: readonly ["T"];
+    }
+  ];
+  readonly module_id: "0xa::vault";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["key"];
+      readonly doc: "A vault.";
+      readonly fields: readonly [
+        {
+          readonly name: "coins";
+          readonly ty: {
+            readonly vector: {
+              readonly struct: {
+                readonly name: "0xa::coin::Coin";
+                readonly ty_args: readonly [{ readonly type_param: 0 }];
+              };
+            };
+          };
+        }
+      ];
+      readonly name: "0xa::vault::Vault";
+      readonly type_params: readonly [{ readonly name: "T" }];
+    }
+  ];
+};
@@ -0,0 +1,32 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export const idl = {
+  errors: {},
+  functions: [
+    { args: [{ name: "amount", ty: "u64" }], name: "deposit", ty_args: ["T"] },
+  ],
+  module_id: "0xa::vault",
+  structs: [
+    {
+      abilities: ["key"],
+      doc: "A vault.",
+      fields: [
+        {
+          name: "coins",
+          ty: {
+            vector: {
+              struct: { name: "0xa::coin::Coin", ty_args: [{ type_param: 0 }] },
+            },
+          },
+        },
+      ],
+      name: "0xa::vault::Vault",
+      type_params: [{ name: "T" }],
+    },
+  ],
+};
@@ -0,0 +1,63 @@
+/**
+ * **Module ID:** `0xa::vault`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0xa";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0xa::vault";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "vault";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+/** Module error codes. */
+const errorCodes = {};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  deposit: {
+    args: [{ name: "amount", ty: "u64" }],
+    name: "deposit",
+    ty_args: ["T"],
+  },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = { Vault: "0xa::vault::Vault" };
+exports.resources = resources;
+
+/** All struct types. */
+const structs = { Vault: "0xa::vault::Vault" };
+exports.structs = structs;
+
+/** Payload generators for module `0xa::vault`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
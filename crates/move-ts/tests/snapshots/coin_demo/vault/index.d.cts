@@ -0,0 +1,79 @@
+/**
+ * **Module ID:** `0xa::vault`
+ *
+ * @module
+ */
+
+import * as mod_0xa_coin from "../0xa_coin/index.cjs";
+import * as p from "../prelude.cjs";
+
+/**
+ * A vault.
+ *
+ * Type name: `0xa::vault::Vault`
+ */
+export interface IVault<_T = unknown> {
+  coins: ReadonlyArray<mod_0xa_coin.ICoin>;
+}
+
+/** Payload arguments for {@link entry.deposit}. */
+export type DepositArgs = {
+  args: {
+    /** IDL type: `U64` */
+    amount: p.U64;
+  };
+  typeArgs: { T: string };
+};
+
+export * as entry from "./entry.cjs";
+export * as payloads from "./payloads.cjs";
+export * as entryNames from "./entryNames.cjs";
+export * as bcs from "./bcs.cjs";
+export { idl } from "./idl.cjs";
+
+/** The address of the module. */
+export declare const ADDRESS: "0xa";
+
+/** The full module name. */
+export declare const FULL_NAME: "0xa::vault";
+
+/** The name of the module. */
+export declare const NAME: "vault";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+/** Module error codes. */
+export declare const errorCodes: {};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly deposit: {
+    readonly args: readonly [{ readonly name: "amount"; readonly ty: "u64" }];
+    readonly name: "deposit";
+    readonly ty_args: readonly ["T"];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: { readonly Vault: "0xa::vault::Vault" };
+
+/** All struct types. */
+export declare const structs: { readonly Vault: "0xa::vault::Vault" };
+
+/** Payload generators for module `0xa::vault`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+export declare const moduleDefinition: typeof moduleImpl;
@@ -0,0 +1,54 @@
+/**
+ * **Module ID:** `0xa::vault`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0xa";
+
+/** The full module name. */
+export const FULL_NAME = "0xa::vault";
+
+/** The name of the module. */
+export const NAME = "vault";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+/** Module error codes. */
+export const errorCodes = {};
+
+/** All module function IDLs. */
+export const functions = {
+  deposit: {
+    args: [{ name: "amount", ty: "u64" }],
+    name: "deposit",
+    ty_args: ["T"],
+  },
+};
+
+/** All struct types with ability `key`. */
+export const resources = { Vault: "0xa::vault::Vault" };
+
+/** All struct types. */
+export const structs = { Vault: "0xa::vault::Vault" };
+
+/** Payload generators for module `0xa::vault`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+export const moduleDefinition = moduleImpl;
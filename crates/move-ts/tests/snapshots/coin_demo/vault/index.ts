@@ -0,0 +1,78 @@
+/**
+ * **Module ID:** `0xa::vault`
+ *
+ * @module
+ */
+
+import * as mod_0xa_coin from "../0xa_coin/index.js";
+import * as p from "../prelude.js";
+
+/**
+ * A vault.
+ *
+ * Type name: `0xa::vault::Vault`
+ */
+export interface IVault<_T = unknown> {
+  coins: ReadonlyArray<mod_0xa_coin.ICoin>;
+}
+
+/** Payload arguments for {@link entry.deposit}. */
+export type DepositArgs = {
+  args: {
+    /** IDL type: `U64` */
+    amount: p.U64;
+  };
+  typeArgs: { T: string };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0xa" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0xa::vault" as const;
+
+/** The name of the module. */
+export const NAME = "vault" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+/** Module error codes. */
+export const errorCodes = {} as const;
+
+/** All module function IDLs. */
+export const functions = {
+  deposit: {
+    args: [{ name: "amount", ty: "u64" }],
+    name: "deposit",
+    ty_args: ["T"],
+  },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = { Vault: "0xa::vault::Vault" } as const;
+
+/** All struct types. */
+export const structs = { Vault: "0xa::vault::Vault" } as const;
+
+/** Payload generators for module `0xa::vault`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0xa",
+  "vault"
+> as typeof moduleImpl;
@@ -0,0 +1,13 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
+
+/** Script function payload for `0xa::vault::deposit`. */
+export type Deposit = {
+  readonly type: "script_function_payload";
+  readonly function: "0xa::vault::deposit";
+  readonly arguments: [amount: string];
+  readonly type_arguments: [T: string];
+};
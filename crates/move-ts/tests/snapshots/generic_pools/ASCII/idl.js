@@ -0,0 +1,19 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x1::ASCII",
+  structs: [
+    {
+      abilities: ["copy", "drop", "store"],
+      fields: [{ name: "bytes", ty: { vector: "u8" } }],
+      name: "0x1::ASCII::String",
+    },
+  ],
+};
@@ -0,0 +1,44 @@
+/**
+ * **Module ID:** `0x1::ASCII`
+ *
+ * @module
+ */
+
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x1";
+
+/** The full module name. */
+export const FULL_NAME = "0x1::ASCII";
+
+/** The name of the module. */
+export const NAME = "ASCII";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+/** Module error codes. */
+export const errorCodes = {};
+
+/** All module function IDLs. */
+export const functions = {};
+
+/** All struct types with ability `key`. */
+export const resources = {};
+
+/** All struct types. */
+export const structs = { String: "0x1::ASCII::String" };
+
+/** Payload generators for module `0x1::ASCII`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+export const moduleDefinition = moduleImpl;
@@ -0,0 +1,28 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x1::coin::transfer`.
+ *
+ * Transfers `amount` coins to `to`.
+ */
+const transfer = ({ args, typeArgs }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0x1",
+      module: "coin",
+      function: "transfer",
+      typeArgs: [typeArgs.CoinType],
+      args: [p.bcs.address(args.to, ADDRESS_LENGTH), p.bcs.u64(args.amount)],
+    },
+    ADDRESS_LENGTH
+  );
+exports.transfer = transfer;
@@ -0,0 +1,28 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32 as const;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x1::coin::transfer`.
+ *
+ * Transfers `amount` coins to `to`.
+ */
+export const transfer = ({ args, typeArgs }: mod.TransferArgs): Uint8Array =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0x1",
+      module: "coin",
+      function: "transfer",
+      typeArgs: [typeArgs.CoinType],
+      args: [p.bcs.address(args.to, ADDRESS_LENGTH), p.bcs.u64(args.amount)],
+    },
+    ADDRESS_LENGTH
+  );
@@ -0,0 +1,16 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Transfers `amount` coins to `to`. */
+const transfer = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0x1::coin::transfer",
+  type_arguments: [typeArgs.CoinType],
+  arguments: [p.serializers.hexString(args.to), p.serializers.u64(args.amount)],
+});
+exports.transfer = transfer;
@@ -0,0 +1,14 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+/** Transfers `amount` coins to `to`. */
+export declare const transfer: (
+  { args, typeArgs }: mod.TransferArgs
+) => payloads.Transfer;
@@ -0,0 +1,14 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Transfers `amount` coins to `to`. */
+export declare const transfer: (
+  { args, typeArgs }: mod.TransferArgs
+) => payloads.Transfer;
@@ -0,0 +1,19 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Transfers `amount` coins to `to`. */
+export const transfer = (
+  { args, typeArgs }: mod.TransferArgs
+): payloads.Transfer => ({
+  type: "script_function_payload",
+  function: "0x1::coin::transfer",
+  type_arguments: [typeArgs.CoinType],
+  arguments: [p.serializers.hexString(args.to), p.serializers.u64(args.amount)],
+});
@@ -0,0 +1,9 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x1::coin::transfer`. */
+const transfer = "0x1::coin::transfer";
+exports.transfer = transfer;
@@ -0,0 +1,23 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+const EINSUFFICIENT_BALANCE = {
+  category: null,
+  code: 7,
+  name: "EINSUFFICIENT_BALANCE",
+  reason: 7,
+};
+exports.EINSUFFICIENT_BALANCE = EINSUFFICIENT_BALANCE;
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+const isError = (code, error) => p.matchesError(code, error);
+exports.isError = isError;
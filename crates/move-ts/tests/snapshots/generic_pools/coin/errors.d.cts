@@ -0,0 +1,24 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+
+export declare const EINSUFFICIENT_BALANCE: {
+  readonly category: null;
+  readonly code: 7;
+  readonly name: "EINSUFFICIENT_BALANCE";
+  readonly reason: 7;
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export declare const isError: (
+  code: number,
+  error: typeof EINSUFFICIENT_BALANCE
+) => boolean;
@@ -0,0 +1,21 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+export const EINSUFFICIENT_BALANCE = {
+  category: null,
+  code: 7,
+  name: "EINSUFFICIENT_BALANCE",
+  reason: 7,
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export const isError = (code, error) => p.matchesError(code, error);
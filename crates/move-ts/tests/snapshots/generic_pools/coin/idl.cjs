@@ -0,0 +1,30 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  doc: "Coins.",
+  errors: { "7": { name: "EINSUFFICIENT_BALANCE" } },
+  functions: [
+    {
+      args: [{ name: "to", ty: "address" }, { name: "amount", ty: "u64" }],
+      doc: "Transfers `amount` coins to `to`.",
+      name: "transfer",
+      ty_args: ["CoinType"],
+    },
+  ],
+  module_id: "0x1::coin",
+  structs: [
+    {
+      abilities: ["store"],
+      doc: "A coin of type `T`.",
+      fields: [{ name: "value", ty: "u64" }],
+      name: "0x1::coin::Coin",
+      type_params: [{ is_phantom: true, name: "T" }],
+    },
+  ],
+};
+exports.idl = idl;
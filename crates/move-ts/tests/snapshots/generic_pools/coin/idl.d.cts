@@ -0,0 +1,36 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly doc: "Coins.";
+  readonly errors: { readonly "7": { readonly name: "EINSUFFICIENT_BALANCE" } };
+  readonly functions: readonly [
+    {
+      readonly args: readonly [
+        { readonly name: "to"; readonly ty: "address" },
+        { readonly name: "amount"; readonly ty: "u64" }
+      ];
+      readonly doc: "Transfers `amount` coins to `to`.";
+      readonly name: "transfer";
+      readonly ty_args: readonly ["CoinType"];
+    }
+  ];
+  readonly module_id: "0x1::coin";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["store"];
+      readonly doc: "A coin of type `T`.";
+      readonly fields: readonly [
+        { readonly name: "value"; readonly ty: "u64" }
+      ];
+      readonly name: "0x1::coin::Coin";
+      readonly type_params: readonly [
+        { readonly is_phantom: true; readonly name: "T" }
+      ];
+    }
+  ];
+};
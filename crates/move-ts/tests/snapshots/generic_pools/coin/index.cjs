@@ -0,0 +1,69 @@
+/**
+ * Coins.
+ *
+ * **Module ID:** `0x1::coin`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0x1";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0x1::coin";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "coin";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+exports.errors = require("./errors.cjs");
+
+/** Module error codes. */
+const errorCodes = { "7": { name: "EINSUFFICIENT_BALANCE" } };
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  transfer: {
+    args: [{ name: "to", ty: "address" }, { name: "amount", ty: "u64" }],
+    doc: "Transfers `amount` coins to `to`.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = {};
+exports.resources = resources;
+
+/** All struct types. */
+const structs = { Coin: "0x1::coin::Coin" };
+exports.structs = structs;
+
+/** Payload generators for module `0x1::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** Coins. */
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
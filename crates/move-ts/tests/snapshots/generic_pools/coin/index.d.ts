@@ -0,0 +1,91 @@
+/**
+ * Coins.
+ *
+ * **Module ID:** `0x1::coin`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/**
+ * A coin of type `T`.
+ *
+ * Type name: `0x1::coin::Coin`
+ */
+export interface ICoin {
+  value: p.U64;
+}
+
+/** Payload arguments for {@link entry.transfer}. */
+export type TransferArgs = {
+  args: {
+    /** IDL type: `Address` */
+    to: p.RawAddress;
+    /** IDL type: `U64` */
+    amount: p.U64;
+  };
+  typeArgs: { CoinType: string };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export declare const ADDRESS: "0x1";
+
+/** The full module name. */
+export declare const FULL_NAME: "0x1::coin";
+
+/** The name of the module. */
+export declare const NAME: "coin";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+export * as errors from "./errors.js";
+
+/** Module error codes. */
+export declare const errorCodes: {
+  readonly "7": { readonly name: "EINSUFFICIENT_BALANCE" };
+};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly transfer: {
+    readonly args: readonly [
+      { readonly name: "to"; readonly ty: "address" },
+      { readonly name: "amount"; readonly ty: "u64" }
+    ];
+    readonly doc: "Transfers `amount` coins to `to`.";
+    readonly name: "transfer";
+    readonly ty_args: readonly ["CoinType"];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: {};
+
+/** All struct types. */
+export declare const structs: { readonly Coin: "0x1::coin::Coin" };
+
+/** Payload generators for module `0x1::coin`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+/** Coins. */
+export declare const moduleDefinition: typeof moduleImpl;
@@ -0,0 +1,60 @@
+/**
+ * Coins.
+ *
+ * **Module ID:** `0x1::coin`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x1";
+
+/** The full module name. */
+export const FULL_NAME = "0x1::coin";
+
+/** The name of the module. */
+export const NAME = "coin";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+export * as errors from "./errors.js";
+
+/** Module error codes. */
+export const errorCodes = { "7": { name: "EINSUFFICIENT_BALANCE" } };
+
+/** All module function IDLs. */
+export const functions = {
+  transfer: {
+    args: [{ name: "to", ty: "address" }, { name: "amount", ty: "u64" }],
+    doc: "Transfers `amount` coins to `to`.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+};
+
+/** All struct types with ability `key`. */
+export const resources = {};
+
+/** All struct types. */
+export const structs = { Coin: "0x1::coin::Coin" };
+
+/** Payload generators for module `0x1::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** Coins. */
+export const moduleDefinition = moduleImpl;
@@ -0,0 +1,85 @@
+/**
+ * Coins.
+ *
+ * **Module ID:** `0x1::coin`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/**
+ * A coin of type `T`.
+ *
+ * Type name: `0x1::coin::Coin`
+ */
+export interface ICoin {
+  value: p.U64;
+}
+
+/** Payload arguments for {@link entry.transfer}. */
+export type TransferArgs = {
+  args: {
+    /** IDL type: `Address` */
+    to: p.RawAddress;
+    /** IDL type: `U64` */
+    amount: p.U64;
+  };
+  typeArgs: { CoinType: string };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x1" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x1::coin" as const;
+
+/** The name of the module. */
+export const NAME = "coin" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+export * as errors from "./errors.js";
+
+/** Module error codes. */
+export const errorCodes = { "7": { name: "EINSUFFICIENT_BALANCE" } } as const;
+
+/** All module function IDLs. */
+export const functions = {
+  transfer: {
+    args: [{ name: "to", ty: "address" }, { name: "amount", ty: "u64" }],
+    doc: "Transfers `amount` coins to `to`.",
+    name: "transfer",
+    ty_args: ["CoinType"],
+  },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = {} as const;
+
+/** All struct types. */
+export const structs = { Coin: "0x1::coin::Coin" } as const;
+
+/** Payload generators for module `0x1::coin`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+/** Coins. */
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x1",
+  "coin"
+> as typeof moduleImpl;
@@ -0,0 +1,5 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
@@ -0,0 +1,26 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+const p = require("./prelude.cjs");
+
+/** All errors in this package, keyed by module ID and error code. */
+const errmap = {
+  "0x1::coin": { "7": { name: "EINSUFFICIENT_BALANCE" } },
+  "0x2::pool": {
+    "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+    "2": { name: "EZERO_AMOUNT" },
+    "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+  },
+};
+exports.errmap = errmap;
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+const parseAbort = (vmStatus) => p.decodeAbort(errmap, vmStatus);
+exports.parseAbort = parseAbort;
@@ -0,0 +1,32 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+import * as p from "./prelude.js";
+
+/** All errors in this package, keyed by module ID and error code. */
+export declare const errmap: {
+  readonly "0x1::coin": {
+    readonly "7": { readonly name: "EINSUFFICIENT_BALANCE" };
+  };
+  readonly "0x2::pool": {
+    readonly "1": {
+      readonly doc: "The pool already exists.";
+      readonly name: "EPOOL_EXISTS";
+    };
+    readonly "2": { readonly name: "EZERO_AMOUNT" };
+    readonly "393219": {
+      readonly doc: "No pool was found.";
+      readonly name: "EPOOL_NOT_FOUND";
+    };
+  };
+};
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+export declare const parseAbort: (vmStatus: string) => p.DecodedAbort | null;
@@ -0,0 +1,24 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+import * as p from "./prelude.js";
+
+/** All errors in this package, keyed by module ID and error code. */
+export const errmap = {
+  "0x1::coin": { "7": { name: "EINSUFFICIENT_BALANCE" } },
+  "0x2::pool": {
+    "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+    "2": { name: "EZERO_AMOUNT" },
+    "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+  },
+};
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+export const parseAbort = (vmStatus) => p.decodeAbort(errmap, vmStatus);
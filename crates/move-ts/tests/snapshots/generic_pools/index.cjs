@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `GenericPools`.
+ *
+ * @module
+ */
+
+exports.generic_pools_pool = require("./pool/index.cjs");
+exports.generic_pools_ASCII = require("./ASCII/index.cjs");
+exports.generic_pools_coin = require("./coin/index.cjs");
+exports.generic_pools_errmap = require("./errmap.cjs").errmap;
+exports.generic_pools_parse_abort = require("./errmap.cjs").parseAbort;
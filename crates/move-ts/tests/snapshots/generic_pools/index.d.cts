@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `GenericPools`.
+ *
+ * @module
+ */
+
+export * as generic_pools_pool from "./pool/index.cjs";
+export * as generic_pools_ASCII from "./ASCII/index.cjs";
+export * as generic_pools_coin from "./coin/index.cjs";
+export { errmap as generic_pools_errmap } from "./errmap.cjs";
+export { parseAbort as generic_pools_parse_abort } from "./errmap.cjs";
@@ -0,0 +1,21 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x2::pool::create`.
+ *
+ * Creates a pool.
+ */
+export declare const create: ({ args, typeArgs }: mod.CreateArgs) => Uint8Array;
+
+/** BCS-encoded `TransactionPayload` calling `0x2::pool::noop`. */
+export declare const noop: () => Uint8Array;
@@ -0,0 +1,55 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x2::pool::create`.
+ *
+ * Creates a pool.
+ */
+export const create = ({ args, typeArgs }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0x2",
+      module: "pool",
+      function: "create",
+      typeArgs: [typeArgs.X, typeArgs.Y],
+      args: [
+        p.bcs.vector(args.amounts, (inner_args__amounts) =>
+          p.bcs.vector(inner_args__amounts, (inner_inner_args__amounts) =>
+            p.bcs.u64(inner_inner_args__amounts)
+          )
+        ),
+        p.bcs.vector(args.labels, (inner_args__labels) =>
+          p.bcs.string(inner_args__labels)
+        ),
+        p.bcs.vector(args.data, (inner_args__data) =>
+          p.bcs.bytes(inner_args__data)
+        ),
+        p.bcs.u128(args.limit),
+        p.bcs.bool(args.enabled),
+        p.bcs.u8(args.fee_bps),
+      ],
+    },
+    ADDRESS_LENGTH
+  );
+
+/** BCS-encoded `TransactionPayload` calling `0x2::pool::noop`. */
+export const noop = () =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0x2",
+      module: "pool",
+      function: "noop",
+      typeArgs: [],
+      args: [],
+    },
+    ADDRESS_LENGTH
+  );
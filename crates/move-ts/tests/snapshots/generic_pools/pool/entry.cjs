@@ -0,0 +1,37 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Creates a pool. */
+const create = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0x2::pool::create",
+  type_arguments: [typeArgs.X, typeArgs.Y],
+  arguments: [
+    args.amounts.map((inner_args__amounts) =>
+      inner_args__amounts.map((inner_inner_args__amounts) =>
+        p.serializers.u64(inner_inner_args__amounts)
+      )
+    ),
+    args.labels,
+    args.data.map((inner_args__data) =>
+      p.serializers.hexString(inner_args__data)
+    ),
+    p.serializers.u128(args.limit),
+    args.enabled,
+    args.fee_bps,
+  ],
+});
+exports.create = create;
+
+const noop = () => ({
+  type: "script_function_payload",
+  function: "0x2::pool::noop",
+  type_arguments: [],
+  arguments: [],
+});
+exports.noop = noop;
@@ -0,0 +1,16 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+/** Creates a pool. */
+export declare const create: (
+  { args, typeArgs }: mod.CreateArgs
+) => payloads.Create;
+
+export declare const noop: () => payloads.Noop;
@@ -0,0 +1,16 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Creates a pool. */
+export declare const create: (
+  { args, typeArgs }: mod.CreateArgs
+) => payloads.Create;
+
+export declare const noop: () => payloads.Noop;
@@ -0,0 +1,35 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Creates a pool. */
+export const create = ({ args, typeArgs }) => ({
+  type: "script_function_payload",
+  function: "0x2::pool::create",
+  type_arguments: [typeArgs.X, typeArgs.Y],
+  arguments: [
+    args.amounts.map((inner_args__amounts) =>
+      inner_args__amounts.map((inner_inner_args__amounts) =>
+        p.serializers.u64(inner_inner_args__amounts)
+      )
+    ),
+    args.labels,
+    args.data.map((inner_args__data) =>
+      p.serializers.hexString(inner_args__data)
+    ),
+    p.serializers.u128(args.limit),
+    args.enabled,
+    args.fee_bps,
+  ],
+});
+
+export const noop = () => ({
+  type: "script_function_payload",
+  function: "0x2::pool::noop",
+  type_arguments: [],
+  arguments: [],
+});
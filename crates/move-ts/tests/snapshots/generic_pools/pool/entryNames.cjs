@@ -0,0 +1,13 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x2::pool::create`. */
+const create = "0x2::pool::create";
+exports.create = create;
+
+/** Script function type for `0x2::pool::noop`. */
+const noop = "0x2::pool::noop";
+exports.noop = noop;
@@ -0,0 +1,11 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x2::pool::create`. */
+export const create = "0x2::pool::create";
+
+/** Script function type for `0x2::pool::noop`. */
+export const noop = "0x2::pool::noop";
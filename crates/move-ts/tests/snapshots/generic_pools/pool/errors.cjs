@@ -0,0 +1,43 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** The pool already exists. */
+const EPOOL_EXISTS = {
+  category: null,
+  code: 1,
+  doc: "The pool already exists.",
+  name: "EPOOL_EXISTS",
+  reason: 1,
+};
+exports.EPOOL_EXISTS = EPOOL_EXISTS;
+
+const EZERO_AMOUNT = {
+  category: null,
+  code: 2,
+  name: "EZERO_AMOUNT",
+  reason: 2,
+};
+exports.EZERO_AMOUNT = EZERO_AMOUNT;
+
+/** No pool was found. */
+const EPOOL_NOT_FOUND = {
+  category: "NOT_FOUND",
+  code: 393219,
+  doc: "No pool was found.",
+  name: "EPOOL_NOT_FOUND",
+  reason: 3,
+};
+exports.EPOOL_NOT_FOUND = EPOOL_NOT_FOUND;
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+const isError = (code, error) => p.matchesError(code, error);
+exports.isError = isError;
@@ -0,0 +1,42 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** The pool already exists. */
+export declare const EPOOL_EXISTS: {
+  readonly category: null;
+  readonly code: 1;
+  readonly doc: "The pool already exists.";
+  readonly name: "EPOOL_EXISTS";
+  readonly reason: 1;
+};
+
+export declare const EZERO_AMOUNT: {
+  readonly category: null;
+  readonly code: 2;
+  readonly name: "EZERO_AMOUNT";
+  readonly reason: 2;
+};
+
+/** No pool was found. */
+export declare const EPOOL_NOT_FOUND: {
+  readonly category: "NOT_FOUND";
+  readonly code: 393219;
+  readonly doc: "No pool was found.";
+  readonly name: "EPOOL_NOT_FOUND";
+  readonly reason: 3;
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export declare const isError: (
+  code: number,
+  error: typeof EPOOL_EXISTS | typeof EZERO_AMOUNT | typeof EPOOL_NOT_FOUND
+) => boolean;
@@ -0,0 +1,39 @@
+/**
+ * Module errors.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** The pool already exists. */
+export const EPOOL_EXISTS = {
+  category: null,
+  code: 1,
+  doc: "The pool already exists.",
+  name: "EPOOL_EXISTS",
+  reason: 1,
+};
+
+export const EZERO_AMOUNT = {
+  category: null,
+  code: 2,
+  name: "EZERO_AMOUNT",
+  reason: 2,
+};
+
+/** No pool was found. */
+export const EPOOL_NOT_FOUND = {
+  category: "NOT_FOUND",
+  code: 393219,
+  doc: "No pool was found.",
+  name: "EPOOL_NOT_FOUND",
+  reason: 3,
+};
+
+/**
+ * Whether the abort code `code` is the error `error` of this module.
+ *
+ * Codes are compared by their reason, so that an error declared without a `std::error` category matches aborts with any category, and conversely.
+ */
+export const isError = (code, error) => p.matchesError(code, error);
@@ -0,0 +1,161 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly doc: "Generic liquidity pools.";
+  readonly errors: {
+    readonly "1": {
+      readonly doc: "The pool already exists.";
+      readonly name: "EPOOL_EXISTS";
+    };
+    readonly "2": { readonly name: "EZERO_AMOUNT" };
+    readonly "393219": {
+      readonly doc: "No pool was found.";
+      readonly name: "EPOOL_NOT_FOUND";
+    };
+  };
+  readonly functions: readonly [
+    {
+      readonly args: readonly [
+        { readonly name: "account"; readonly ty: "signer" },
+        {
+          readonly name: "amounts";
+          readonly ty: { readonly vector: { readonly vector: "u64" } };
+        },
+        {
+          readonly name: "labels";
+          readonly ty: {
+            readonly vector: {
+              readonly struct: { readonly name: "0x1::string::String" };
+            };
+          };
+        },
+        {
+          readonly name: "data";
+          readonly ty: { readonly vector: { readonly vector: "u8" } };
+        },
+        { readonly name: "limit"; readonly ty: "u128" },
+        { readonly name: "enabled"; readonly ty: "bool" },
+        { readonly name: "fee_bps"; readonly ty: "u8" }
+      ];
+      readonly doc: "Creates a pool.";
+      readonly name: "create";
+      readonly ty_args: readonly ["X", "Y"];
+    },
+    {
+      readonly args: readonly [];
+      readonly name: "noop";
+      readonly ty_args: readonly [];
+    }
+  ];
+  readonly module_id: "0x2::pool";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["copy", "drop", "store"];
+      readonly doc: "A pair of values.";
+      readonly fields: readonly [
+        { readonly name: "first"; readonly ty: { readonly type_param: 0 } },
+        { readonly name: "second"; readonly ty: { readonly type_param: 1 } }
+      ];
+      readonly name: "0x2::pool::Pair";
+      readonly type_params: readonly [
+        { readonly name: "A" },
+        { readonly name: "B" }
+      ];
+    },
+    {
+      readonly abilities: readonly ["key"];
+      readonly doc: "A liquidity pool.\n\nHolds coins of two types.";
+      readonly fields: readonly [
+        {
+          readonly doc: "Reserves per epoch.";
+          readonly name: "reserves";
+          readonly ty: { readonly vector: { readonly vector: "u64" } };
+        },
+        {
+          readonly name: "pairs";
+          readonly ty: {
+            readonly vector: {
+              readonly struct: {
+                readonly name: "0x2::pool::Pair";
+                readonly ty_args: readonly ["u64", "address"];
+              };
+            };
+          };
+        },
+        {
+          readonly name: "label";
+          readonly ty: {
+            readonly struct: { readonly name: "0x1::string::String" };
+          };
+        },
+        {
+          readonly name: "symbol";
+          readonly ty: {
+            readonly struct: { readonly name: "0x1::ASCII::String" };
+          };
+        },
+        { readonly name: "total_supply"; readonly ty: "u128" },
+        { readonly name: "frozen"; readonly ty: "bool" },
+        { readonly name: "decimals"; readonly ty: "u8" },
+        {
+          readonly name: "coin_x";
+          readonly ty: {
+            readonly struct: {
+              readonly name: "0x1::coin::Coin";
+              readonly ty_args: readonly [{ readonly type_param: 0 }];
+            };
+          };
+        },
+        {
+          readonly name: "marker";
+          readonly ty: {
+            readonly struct: { readonly name: "0x2::pool::Marker" };
+          };
+        }
+      ];
+      readonly name: "0x2::pool::Pool";
+      readonly type_params: readonly [
+        { readonly is_phantom: true; readonly name: "X" },
+        { readonly is_phantom: true; readonly name: "Y" }
+      ];
+    },
+    {
+      readonly abilities: readonly ["drop"];
+      readonly doc: "An empty struct.";
+      readonly fields: readonly [
+        { readonly name: "dummy_field"; readonly ty: "bool" }
+      ];
+      readonly name: "0x2::pool::Marker";
+    },
+    {
+      readonly abilities: readonly ["store"];
+      readonly fields: readonly [
+        {
+          readonly name: "inner";
+          readonly ty: {
+            readonly vector: {
+              readonly vector: {
+                readonly struct: {
+                  readonly name: "0x2::pool::Pair";
+                  readonly ty_args: readonly [
+                    { readonly type_param: 0 },
+                    {
+                      readonly struct: { readonly name: "0x1::string::String" };
+                    }
+                  ];
+                };
+              };
+            };
+          };
+        }
+      ];
+      readonly name: "0x2::pool::Wrapper";
+      readonly type_params: readonly [{ readonly name: "T" }];
+    }
+  ];
+};
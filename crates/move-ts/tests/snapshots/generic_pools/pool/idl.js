@@ -0,0 +1,113 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export const idl = {
+  doc: "Generic liquidity pools.",
+  errors: {
+    "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+    "2": { name: "EZERO_AMOUNT" },
+    "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+  },
+  functions: [
+    {
+      args: [
+        { name: "account", ty: "signer" },
+        { name: "amounts", ty: { vector: { vector: "u64" } } },
+        {
+          name: "labels",
+          ty: { vector: { struct: { name: "0x1::string::String" } } },
+        },
+        { name: "data", ty: { vector: { vector: "u8" } } },
+        { name: "limit", ty: "u128" },
+        { name: "enabled", ty: "bool" },
+        { name: "fee_bps", ty: "u8" },
+      ],
+      doc: "Creates a pool.",
+      name: "create",
+      ty_args: ["X", "Y"],
+    },
+    { args: [], name: "noop", ty_args: [] },
+  ],
+  module_id: "0x2::pool",
+  structs: [
+    {
+      abilities: ["copy", "drop", "store"],
+      doc: "A pair of values.",
+      fields: [
+        { name: "first", ty: { type_param: 0 } },
+        { name: "second", ty: { type_param: 1 } },
+      ],
+      name: "0x2::pool::Pair",
+      type_params: [{ name: "A" }, { name: "B" }],
+    },
+    {
+      abilities: ["key"],
+      doc: "A liquidity pool.\n\nHolds coins of two types.",
+      fields: [
+        {
+          doc: "Reserves per epoch.",
+          name: "reserves",
+          ty: { vector: { vector: "u64" } },
+        },
+        {
+          name: "pairs",
+          ty: {
+            vector: {
+              struct: { name: "0x2::pool::Pair", ty_args: ["u64", "address"] },
+            },
+          },
+        },
+        { name: "label", ty: { struct: { name: "0x1::string::String" } } },
+        { name: "symbol", ty: { struct: { name: "0x1::ASCII::String" } } },
+        { name: "total_supply", ty: "u128" },
+        { name: "frozen", ty: "bool" },
+        { name: "decimals", ty: "u8" },
+        {
+          name: "coin_x",
+          ty: {
+            struct: { name: "0x1::coin::Coin", ty_args: [{ type_param: 0 }] },
+          },
+        },
+        { name: "marker", ty: { struct: { name: "0x2::pool::Marker" } } },
+      ],
+      name: "0x2::pool::Pool",
+      type_params: [
+        { is_phantom: true, name: "X" },
+        { is_phantom: true, name: "Y" },
+      ],
+    },
+    {
+      abilities: ["drop"],
+      doc: "An empty struct.",
+      fields: [{ name: "dummy_field", ty: "bool" }],
+      name: "0x2::pool::Marker",
+    },
+    {
+      abilities: ["store"],
+      fields: [
+        {
+          name: "inner",
+          ty: {
+            vector: {
+              vector: {
+                struct: {
+                  name: "0x2::pool::Pair",
+                  ty_args: [
+                    { type_param: 0 },
+                    { struct: { name: "0x1::string::String" } },
+                  ],
+                },
+              },
+            },
+          },
+        },
+      ],
+      name: "0x2::pool::Wrapper",
+      type_params: [{ name: "T" }],
+    },
+  ],
+};
@@ -0,0 +1,90 @@
+/**
+ * Generic liquidity pools.
+ *
+ * **Module ID:** `0x2::pool`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0x2";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0x2::pool";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "pool";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+exports.errors = require("./errors.cjs");
+
+/** Module error codes. */
+const errorCodes = {
+  "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+  "2": { name: "EZERO_AMOUNT" },
+  "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  create: {
+    args: [
+      { name: "account", ty: "signer" },
+      { name: "amounts", ty: { vector: { vector: "u64" } } },
+      {
+        name: "labels",
+        ty: { vector: { struct: { name: "0x1::string::String" } } },
+      },
+      { name: "data", ty: { vector: { vector: "u8" } } },
+      { name: "limit", ty: "u128" },
+      { name: "enabled", ty: "bool" },
+      { name: "fee_bps", ty: "u8" },
+    ],
+    doc: "Creates a pool.",
+    name: "create",
+    ty_args: ["X", "Y"],
+  },
+  noop: { args: [], name: "noop", ty_args: [] },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = { Pool: "0x2::pool::Pool" };
+exports.resources = resources;
+
+/** All struct types. */
+const structs = {
+  Marker: "0x2::pool::Marker",
+  Pair: "0x2::pool::Pair",
+  Pool: "0x2::pool::Pool",
+  Wrapper: "0x2::pool::Wrapper",
+};
+exports.structs = structs;
+
+/** Payload generators for module `0x2::pool`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** Generic liquidity pools. */
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
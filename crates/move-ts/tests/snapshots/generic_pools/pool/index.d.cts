@@ -0,0 +1,162 @@
+/**
+ * Generic liquidity pools.
+ *
+ * **Module ID:** `0x2::pool`
+ *
+ * @module
+ */
+
+import * as mod_coin from "../coin/index.cjs";
+import * as p from "../prelude.cjs";
+
+/**
+ * A pair of values.
+ *
+ * Type name: `0x2::pool::Pair`
+ */
+export interface IPair<_A = unknown, _B = unknown> {
+  first: _A;
+  second: _B;
+}
+
+/**
+ * A liquidity pool.
+ *
+ * Holds coins of two types.
+ *
+ * Type name: `0x2::pool::Pool`
+ */
+export interface IPool {
+  /** Reserves per epoch. */
+  reserves: ReadonlyArray<ReadonlyArray<p.U64>>;
+  pairs: ReadonlyArray<IPair<p.U64, p.RawAddress>>;
+  label: string;
+  symbol: string;
+  total_supply: p.U128;
+  frozen: boolean;
+  decimals: number;
+  coin_x: mod_coin.ICoin;
+  marker: { dummy_field: boolean };
+}
+
+/** Type name: `0x2::pool::Wrapper` */
+export interface IWrapper<_T = unknown> {
+  inner: ReadonlyArray<ReadonlyArray<IPair<_T, string>>>;
+}
+
+/** Payload arguments for {@link entry.create}. */
+export type CreateArgs = {
+  args: {
+    /** IDL type: `Vector(Vector(U64))` */
+    amounts: ReadonlyArray<ReadonlyArray<p.U64>>;
+    /** IDL type: `Vector(Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] }))` */
+    labels: ReadonlyArray<string>;
+    /** IDL type: `Vector(Vector(U8))` */
+    data: ReadonlyArray<p.ByteString>;
+    /** IDL type: `U128` */
+    limit: p.U128;
+    /** IDL type: `Bool` */
+    enabled: boolean;
+    /** IDL type: `U8` */
+    fee_bps: number;
+  };
+  typeArgs: { X: string; Y: string };
+};
+
+export * as entry from "./entry.cjs";
+export * as payloads from "./payloads.cjs";
+export * as entryNames from "./entryNames.cjs";
+export * as bcs from "./bcs.cjs";
+export { idl } from "./idl.cjs";
+
+/** The address of the module. */
+export declare const ADDRESS: "0x2";
+
+/** The full module name. */
+export declare const FULL_NAME: "0x2::pool";
+
+/** The name of the module. */
+export declare const NAME: "pool";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+export * as errors from "./errors.cjs";
+
+/** Module error codes. */
+export declare const errorCodes: {
+  readonly "1": {
+    readonly doc: "The pool already exists.";
+    readonly name: "EPOOL_EXISTS";
+  };
+  readonly "2": { readonly name: "EZERO_AMOUNT" };
+  readonly "393219": {
+    readonly doc: "No pool was found.";
+    readonly name: "EPOOL_NOT_FOUND";
+  };
+};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly create: {
+    readonly args: readonly [
+      { readonly name: "account"; readonly ty: "signer" },
+      {
+        readonly name: "amounts";
+        readonly ty: { readonly vector: { readonly vector: "u64" } };
+      },
+      {
+        readonly name: "labels";
+        readonly ty: {
+          readonly vector: {
+            readonly struct: { readonly name: "0x1::string::String" };
+          };
+        };
+      },
+      {
+        readonly name: "data";
+        readonly ty: { readonly vector: { readonly vector: "u8" } };
+      },
+      { readonly name: "limit"; readonly ty: "u128" },
+      { readonly name: "enabled"; readonly ty: "bool" },
+      { readonly name: "fee_bps"; readonly ty: "u8" }
+    ];
+    readonly doc: "Creates a pool.";
+    readonly name: "create";
+    readonly ty_args: readonly ["X", "Y"];
+  };
+  readonly noop: {
+    readonly args: readonly [];
+    readonly name: "noop";
+    readonly ty_args: readonly [];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: { readonly Pool: "0x2::pool::Pool" };
+
+/** All struct types. */
+export declare const structs: {
+  readonly Marker: "0x2::pool::Marker";
+  readonly Pair: "0x2::pool::Pair";
+  readonly Pool: "0x2::pool::Pool";
+  readonly Wrapper: "0x2::pool::Wrapper";
+};
+
+/** Payload generators for module `0x2::pool`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+/** Generic liquidity pools. */
+export declare const moduleDefinition: typeof moduleImpl;
@@ -0,0 +1,81 @@
+/**
+ * Generic liquidity pools.
+ *
+ * **Module ID:** `0x2::pool`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x2";
+
+/** The full module name. */
+export const FULL_NAME = "0x2::pool";
+
+/** The name of the module. */
+export const NAME = "pool";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+export * as errors from "./errors.js";
+
+/** Module error codes. */
+export const errorCodes = {
+  "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+  "2": { name: "EZERO_AMOUNT" },
+  "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+};
+
+/** All module function IDLs. */
+export const functions = {
+  create: {
+    args: [
+      { name: "account", ty: "signer" },
+      { name: "amounts", ty: { vector: { vector: "u64" } } },
+      {
+        name: "labels",
+        ty: { vector: { struct: { name: "0x1::string::String" } } },
+      },
+      { name: "data", ty: { vector: { vector: "u8" } } },
+      { name: "limit", ty: "u128" },
+      { name: "enabled", ty: "bool" },
+      { name: "fee_bps", ty: "u8" },
+    ],
+    doc: "Creates a pool.",
+    name: "create",
+    ty_args: ["X", "Y"],
+  },
+  noop: { args: [], name: "noop", ty_args: [] },
+};
+
+/** All struct types with ability `key`. */
+export const resources = { Pool: "0x2::pool::Pool" };
+
+/** All struct types. */
+export const structs = {
+  Marker: "0x2::pool::Marker",
+  Pair: "0x2::pool::Pair",
+  Pool: "0x2::pool::Pool",
+  Wrapper: "0x2::pool::Wrapper",
+};
+
+/** Payload generators for module `0x2::pool`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** Generic liquidity pools. */
+export const moduleDefinition = moduleImpl;
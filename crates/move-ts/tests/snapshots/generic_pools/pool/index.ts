@@ -0,0 +1,141 @@
+/**
+ * Generic liquidity pools.
+ *
+ * **Module ID:** `0x2::pool`
+ *
+ * @module
+ */
+
+import * as mod_coin from "../coin/index.js";
+import * as p from "../prelude.js";
+
+/**
+ * A pair of values.
+ *
+ * Type name: `0x2::pool::Pair`
+ */
+export interface IPair<_A = unknown, _B = unknown> {
+  first: _A;
+  second: _B;
+}
+
+/**
+ * A liquidity pool.
+ *
+ * Holds coins of two types.
+ *
+ * Type name: `0x2::pool::Pool`
+ */
+export interface IPool {
+  /** Reserves per epoch. */
+  reserves: ReadonlyArray<ReadonlyArray<p.U64>>;
+  pairs: ReadonlyArray<IPair<p.U64, p.RawAddress>>;
+  label: string;
+  symbol: string;
+  total_supply: p.U128;
+  frozen: boolean;
+  decimals: number;
+  coin_x: mod_coin.ICoin;
+  marker: { dummy_field: boolean };
+}
+
+/** Type name: `0x2::pool::Wrapper` */
+export interface IWrapper<_T = unknown> {
+  inner: ReadonlyArray<ReadonlyArray<IPair<_T, string>>>;
+}
+
+/** Payload arguments for {@link entry.create}. */
+export type CreateArgs = {
+  args: {
+    /** IDL type: `Vector(Vector(U64))` */
+    amounts: ReadonlyArray<ReadonlyArray<p.U64>>;
+    /** IDL type: `Vector(Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] }))` */
+    labels: ReadonlyArray<string>;
+    /** IDL type: `Vector(Vector(U8))` */
+    data: ReadonlyArray<p.ByteString>;
+    /** IDL type: `U128` */
+    limit: p.U128;
+    /** IDL type: `Bool` */
+    enabled: boolean;
+    /** IDL type: `U8` */
+    fee_bps: number;
+  };
+  typeArgs: { X: string; Y: string };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x2" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x2::pool" as const;
+
+/** The name of the module. */
+export const NAME = "pool" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+export * as errors from "./errors.js";
+
+/** Module error codes. */
+export const errorCodes = {
+  "1": { doc: "The pool already exists.", name: "EPOOL_EXISTS" },
+  "2": { name: "EZERO_AMOUNT" },
+  "393219": { doc: "No pool was found.", name: "EPOOL_NOT_FOUND" },
+} as const;
+
+/** All module function IDLs. */
+export const functions = {
+  create: {
+    args: [
+      { name: "account", ty: "signer" },
+      { name: "amounts", ty: { vector: { vector: "u64" } } },
+      {
+        name: "labels",
+        ty: { vector: { struct: { name: "0x1::string::String" } } },
+      },
+      { name: "data", ty: { vector: { vector: "u8" } } },
+      { name: "limit", ty: "u128" },
+      { name: "enabled", ty: "bool" },
+      { name: "fee_bps", ty: "u8" },
+    ],
+    doc: "Creates a pool.",
+    name: "create",
+    ty_args: ["X", "Y"],
+  },
+  noop: { args: [], name: "noop", ty_args: [] },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = { Pool: "0x2::pool::Pool" } as const;
+
+/** All struct types. */
+export const structs = {
+  Marker: "0x2::pool::Marker",
+  Pair: "0x2::pool::Pair",
+  Pool: "0x2::pool::Pool",
+  Wrapper: "0x2::pool::Wrapper",
+} as const;
+
+/** Payload generators for module `0x2::pool`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+/** Generic liquidity pools. */
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x2",
+  "pool"
+> as typeof moduleImpl;
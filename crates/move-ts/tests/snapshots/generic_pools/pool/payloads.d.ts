@@ -0,0 +1,32 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
+
+/**
+ * Script function payload for `0x2::pool::create`.
+ *
+ * Creates a pool.
+ */
+export type Create = {
+  readonly type: "script_function_payload";
+  readonly function: "0x2::pool::create";
+  readonly arguments: [
+    amounts: ReadonlyArray<ReadonlyArray<string>>,
+    labels: ReadonlyArray<string>,
+    data: ReadonlyArray<string>,
+    limit: string,
+    enabled: boolean,
+    fee_bps: number
+  ];
+  readonly type_arguments: [X: string, Y: string];
+};
+
+/** Script function payload for `0x2::pool::noop`. */
+export type Noop = {
+  readonly type: "script_function_payload";
+  readonly function: "0x2::pool::noop";
+  readonly arguments: [];
+  readonly type_arguments: [];
+};
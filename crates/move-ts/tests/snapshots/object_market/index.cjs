@@ -0,0 +1,12 @@
+/**
+ * This module contains generated types and helper functions for the package `ObjectMarket`.
+ *
+ * @module
+ */
+
+exports.object_market_market = require("./market/index.cjs");
+exports.object_market_object = require("./object/index.cjs");
+exports.object_market_option = require("./option/index.cjs");
+exports.object_market_string = require("./string/index.cjs");
+exports.object_market_errmap = require("./errmap.cjs").errmap;
+exports.object_market_parse_abort = require("./errmap.cjs").parseAbort;
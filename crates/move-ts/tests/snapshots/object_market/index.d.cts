@@ -0,0 +1,12 @@
+/**
+ * This module contains generated types and helper functions for the package `ObjectMarket`.
+ *
+ * @module
+ */
+
+export * as object_market_market from "./market/index.cjs";
+export * as object_market_object from "./object/index.cjs";
+export * as object_market_option from "./option/index.cjs";
+export * as object_market_string from "./string/index.cjs";
+export { errmap as object_market_errmap } from "./errmap.cjs";
+export { parseAbort as object_market_parse_abort } from "./errmap.cjs";
@@ -0,0 +1,12 @@
+/**
+ * This module contains generated types and helper functions for the package `ObjectMarket`.
+ *
+ * @module
+ */
+
+export * as object_market_market from "./market/index.js";
+export * as object_market_object from "./object/index.js";
+export * as object_market_option from "./option/index.js";
+export * as object_market_string from "./string/index.js";
+export { errmap as object_market_errmap } from "./errmap.js";
+export { parseAbort as object_market_parse_abort } from "./errmap.js";
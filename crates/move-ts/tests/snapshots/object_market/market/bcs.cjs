@@ -0,0 +1,41 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x3::market::list`.
+ *
+ * Lists an object for sale.
+ */
+const list = ({ args }) =>
+  p.bcs.entryFunctionPayload(
+    {
+      address: "0x3",
+      module: "market",
+      function: "list",
+      typeArgs: [],
+      args: [
+        p.bcs.address(args.listing, ADDRESS_LENGTH),
+        p.bcs.option(args.price, (inner_args__price) =>
+          p.bcs.u64(inner_args__price)
+        ),
+        p.bcs.option(args.memo, (inner_args__memo) =>
+          p.bcs.string(inner_args__memo)
+        ),
+        p.bcs.vector(args.buyers, (inner_args__buyers) =>
+          p.bcs.option(inner_args__buyers, (inner_inner_args__buyers) =>
+            p.bcs.address(inner_inner_args__buyers, ADDRESS_LENGTH)
+          )
+        ),
+      ],
+    },
+    ADDRESS_LENGTH
+  );
+exports.list = list;
@@ -0,0 +1,18 @@
+/**
+ * Builders of BCS-encoded `TransactionPayload`s calling the entry functions.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/**
+ * BCS-encoded `TransactionPayload` calling `0x3::market::list`.
+ *
+ * Lists an object for sale.
+ */
+export declare const list: ({ args }: mod.ListArgs) => Uint8Array;
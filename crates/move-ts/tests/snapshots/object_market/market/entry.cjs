@@ -0,0 +1,25 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Lists an object for sale. */
+const list = ({ args }) => ({
+  type: "script_function_payload",
+  function: "0x3::market::list",
+  type_arguments: [],
+  arguments: [
+    p.serializers.hexString(args.listing),
+    args.price == null ? [] : [p.serializers.u64(args.price)],
+    args.memo == null ? [] : [args.memo],
+    args.buyers.map((inner_args__buyers) =>
+      inner_args__buyers == null
+        ? []
+        : [p.serializers.hexString(inner_args__buyers)]
+    ),
+  ],
+});
+exports.list = list;
@@ -0,0 +1,12 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+/** Lists an object for sale. */
+export declare const list: ({ args }: mod.ListArgs) => payloads.List;
@@ -0,0 +1,12 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Lists an object for sale. */
+export declare const list: ({ args }: mod.ListArgs) => payloads.List;
@@ -0,0 +1,9 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x3::market::list`. */
+const list = "0x3::market::list";
+exports.list = list;
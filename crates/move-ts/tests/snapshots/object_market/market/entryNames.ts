@@ -0,0 +1,8 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x3::market::list`. */
+export const list = "0x3::market::list" as const;
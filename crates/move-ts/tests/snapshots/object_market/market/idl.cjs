@@ -0,0 +1,61 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  doc: "A market of objects.",
+  errors: {},
+  functions: [
+    {
+      args: [
+        { name: "seller", ty: "signer" },
+        {
+          name: "listing",
+          ty: {
+            struct: {
+              name: "0x1::object::Object",
+              ty_args: [{ struct: { name: "0x3::market::Listing" } }],
+            },
+          },
+        },
+        {
+          name: "price",
+          ty: { struct: { name: "0x1::option::Option", ty_args: ["u64"] } },
+        },
+        {
+          name: "memo",
+          ty: {
+            struct: {
+              name: "0x1::option::Option",
+              ty_args: [{ struct: { name: "0x1::string::String" } }],
+            },
+          },
+        },
+        {
+          name: "buyers",
+          ty: {
+            vector: {
+              struct: { name: "0x1::option::Option", ty_args: ["address"] },
+            },
+          },
+        },
+      ],
+      doc: "Lists an object for sale.",
+      name: "list",
+      ty_args: [],
+    },
+  ],
+  module_id: "0x3::market",
+  structs: [
+    {
+      abilities: ["key"],
+      doc: "A listing of an Aptos object.",
+      fields: [{ name: "price", ty: "u64" }],
+      name: "0x3::market::Listing",
+    },
+  ],
+};
+exports.idl = idl;
@@ -0,0 +1,74 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly doc: "A market of objects.";
+  readonly errors: {};
+  readonly functions: readonly [
+    {
+      readonly args: readonly [
+        { readonly name: "seller"; readonly ty: "signer" },
+        {
+          readonly name: "listing";
+          readonly ty: {
+            readonly struct: {
+              readonly name: "0x1::object::Object";
+              readonly ty_args: readonly [
+                { readonly struct: { readonly name: "0x3::market::Listing" } }
+              ];
+            };
+          };
+        },
+        {
+          readonly name: "price";
+          readonly ty: {
+            readonly struct: {
+              readonly name: "0x1::option::Option";
+              readonly ty_args: readonly ["u64"];
+            };
+          };
+        },
+        {
+          readonly name: "memo";
+          readonly ty: {
+            readonly struct: {
+              readonly name: "0x1::option::Option";
+              readonly ty_args: readonly [
+                { readonly struct: { readonly name: "0x1::string::String" } }
+              ];
+            };
+          };
+        },
+        {
+          readonly name: "buyers";
+          readonly ty: {
+            readonly vector: {
+              readonly struct: {
+                readonly name: "0x1::option::Option";
+                readonly ty_args: readonly ["address"];
+              };
+            };
+          };
+        }
+      ];
+      readonly doc: "Lists an object for sale.";
+      readonly name: "list";
+      readonly ty_args: readonly [];
+    }
+  ];
+  readonly module_id: "0x3::market";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["key"];
+      readonly doc: "A listing of an Aptos object.";
+      readonly fields: readonly [
+        { readonly name: "price"; readonly ty: "u64" }
+      ];
+      readonly name: "0x3::market::Listing";
+    }
+  ];
+};
@@ -0,0 +1,99 @@
+/**
+ * A market of objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0x3";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0x3::market";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "market";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+/** Module error codes. */
+const errorCodes = {};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  list: {
+    args: [
+      { name: "seller", ty: "signer" },
+      {
+        name: "listing",
+        ty: {
+          struct: {
+            name: "0x1::object::Object",
+            ty_args: [{ struct: { name: "0x3::market::Listing" } }],
+          },
+        },
+      },
+      {
+        name: "price",
+        ty: { struct: { name: "0x1::option::Option", ty_args: ["u64"] } },
+      },
+      {
+        name: "memo",
+        ty: {
+          struct: {
+            name: "0x1::option::Option",
+            ty_args: [{ struct: { name: "0x1::string::String" } }],
+          },
+        },
+      },
+      {
+        name: "buyers",
+        ty: {
+          vector: {
+            struct: { name: "0x1::option::Option", ty_args: ["address"] },
+          },
+        },
+      },
+    ],
+    doc: "Lists an object for sale.",
+    name: "list",
+    ty_args: [],
+  },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = { Listing: "0x3::market::Listing" };
+exports.resources = resources;
+
+/** All struct types. */
+const structs = { Listing: "0x3::market::Listing" };
+exports.structs = structs;
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A market of objects. */
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
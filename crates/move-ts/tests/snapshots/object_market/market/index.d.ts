@@ -0,0 +1,131 @@
+/**
+ * A market of objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/**
+ * A listing of an Aptos object.
+ *
+ * Type name: `0x3::market::Listing`
+ */
+export interface IListing {
+  price: p.U64;
+}
+
+/** Payload arguments for {@link entry.list}. */
+export type ListArgs = {
+  args: {
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("object"), name: Identifier("Object"), type_params: [] }), ty_args: [Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000003, module: Identifier("market"), name: Identifier("Listing"), type_params: [] }), ty_args: [] })] })` */
+    listing: p.RawAddress;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [U64] })` */
+    price: p.U64 | null | undefined;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] })] })` */
+    memo: string | null | undefined;
+    /** IDL type: `Vector(Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [Address] }))` */
+    buyers: ReadonlyArray<p.RawAddress | null | undefined>;
+  };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export declare const ADDRESS: "0x3";
+
+/** The full module name. */
+export declare const FULL_NAME: "0x3::market";
+
+/** The name of the module. */
+export declare const NAME: "market";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+/** Module error codes. */
+export declare const errorCodes: {};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly list: {
+    readonly args: readonly [
+      { readonly name: "seller"; readonly ty: "signer" },
+      {
+        readonly name: "listing";
+        readonly ty: {
+          readonly struct: {
+            readonly name: "0x1::object::Object";
+            readonly ty_args: readonly [
+              { readonly struct: { readonly name: "0x3::market::Listing" } }
+            ];
+          };
+        };
+      },
+      {
+        readonly name: "price";
+        readonly ty: {
+          readonly struct: {
+            readonly name: "0x1::option::Option";
+            readonly ty_args: readonly ["u64"];
+          };
+        };
+      },
+      {
+        readonly name: "memo";
+        readonly ty: {
+          readonly struct: {
+            readonly name: "0x1::option::Option";
+            readonly ty_args: readonly [
+              { readonly struct: { readonly name: "0x1::string::String" } }
+            ];
+          };
+        };
+      },
+      {
+        readonly name: "buyers";
+        readonly ty: {
+          readonly vector: {
+            readonly struct: {
+              readonly name: "0x1::option::Option";
+              readonly ty_args: readonly ["address"];
+            };
+          };
+        };
+      }
+    ];
+    readonly doc: "Lists an object for sale.";
+    readonly name: "list";
+    readonly ty_args: readonly [];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: { readonly Listing: "0x3::market::Listing" };
+
+/** All struct types. */
+export declare const structs: { readonly Listing: "0x3::market::Listing" };
+
+/** Payload generators for module `0x3::market`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+/** A market of objects. */
+export declare const moduleDefinition: typeof moduleImpl;
@@ -0,0 +1,90 @@
+/**
+ * A market of objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x3";
+
+/** The full module name. */
+export const FULL_NAME = "0x3::market";
+
+/** The name of the module. */
+export const NAME = "market";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+/** Module error codes. */
+export const errorCodes = {};
+
+/** All module function IDLs. */
+export const functions = {
+  list: {
+    args: [
+      { name: "seller", ty: "signer" },
+      {
+        name: "listing",
+        ty: {
+          struct: {
+            name: "0x1::object::Object",
+            ty_args: [{ struct: { name: "0x3::market::Listing" } }],
+          },
+        },
+      },
+      {
+        name: "price",
+        ty: { struct: { name: "0x1::option::Option", ty_args: ["u64"] } },
+      },
+      {
+        name: "memo",
+        ty: {
+          struct: {
+            name: "0x1::option::Option",
+            ty_args: [{ struct: { name: "0x1::string::String" } }],
+          },
+        },
+      },
+      {
+        name: "buyers",
+        ty: {
+          vector: {
+            struct: { name: "0x1::option::Option", ty_args: ["address"] },
+          },
+        },
+      },
+    ],
+    doc: "Lists an object for sale.",
+    name: "list",
+    ty_args: [],
+  },
+};
+
+/** All struct types with ability `key`. */
+export const resources = { Listing: "0x3::market::Listing" };
+
+/** All struct types. */
+export const structs = { Listing: "0x3::market::Listing" };
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A market of objects. */
+export const moduleDefinition = moduleImpl;
@@ -0,0 +1,118 @@
+/**
+ * A market of objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/**
+ * A listing of an Aptos object.
+ *
+ * Type name: `0x3::market::Listing`
+ */
+export interface IListing {
+  price: p.U64;
+}
+
+/** Payload arguments for {@link entry.list}. */
+export type ListArgs = {
+  args: {
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("object"), name: Identifier("Object"), type_params: [] }), ty_args: [Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000003, module: Identifier("market"), name: Identifier("Listing"), type_params: [] }), ty_args: [] })] })` */
+    listing: p.RawAddress;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [U64] })` */
+    price: p.U64 | null | undefined;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("string"), name: Identifier("String"), type_params: [] }), ty_args: [] })] })` */
+    memo: string | null | undefined;
+    /** IDL type: `Vector(Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000001, module: Identifier("option"), name: Identifier("Option"), type_params: [] }), ty_args: [Address] }))` */
+    buyers: ReadonlyArray<p.RawAddress | null | undefined>;
+  };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x3" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x3::market" as const;
+
+/** The name of the module. */
+export const NAME = "market" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+/** Module error codes. */
+export const errorCodes = {} as const;
+
+/** All module function IDLs. */
+export const functions = {
+  list: {
+    args: [
+      { name: "seller", ty: "signer" },
+      {
+        name: "listing",
+        ty: {
+          struct: {
+            name: "0x1::object::Object",
+            ty_args: [{ struct: { name: "0x3::market::Listing" } }],
+          },
+        },
+      },
+      {
+        name: "price",
+        ty: { struct: { name: "0x1::option::Option", ty_args: ["u64"] } },
+      },
+      {
+        name: "memo",
+        ty: {
+          struct: {
+            name: "0x1::option::Option",
+            ty_args: [{ struct: { name: "0x1::string::String" } }],
+          },
+        },
+      },
+      {
+        name: "buyers",
+        ty: {
+          vector: {
+            struct: { name: "0x1::option::Option", ty_args: ["address"] },
+          },
+        },
+      },
+    ],
+    doc: "Lists an object for sale.",
+    name: "list",
+    ty_args: [],
+  },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = { Listing: "0x3::market::Listing" } as const;
+
+/** All struct types. */
+export const structs = { Listing: "0x3::market::Listing" } as const;
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+/** A market of objects. */
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x3",
+  "market"
+> as typeof moduleImpl;
@@ -0,0 +1,22 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
+
+/**
+ * Script function payload for `0x3::market::list`.
+ *
+ * Lists an object for sale.
+ */
+export type List = {
+  readonly type: "script_function_payload";
+  readonly function: "0x3::market::list";
+  readonly arguments: [
+    listing: string,
+    price: ReadonlyArray<string>,
+    memo: ReadonlyArray<string>,
+    buyers: ReadonlyArray<ReadonlyArray<string>>
+  ];
+  readonly type_arguments: [];
+};
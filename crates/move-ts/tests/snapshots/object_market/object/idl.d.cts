@@ -0,0 +1,24 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x1::object";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["copy", "drop", "store"];
+      readonly fields: readonly [
+        { readonly name: "inner"; readonly ty: "address" }
+      ];
+      readonly name: "0x1::object::Object";
+      readonly type_params: readonly [
+        { readonly is_phantom: true; readonly name: "T" }
+      ];
+    }
+  ];
+};
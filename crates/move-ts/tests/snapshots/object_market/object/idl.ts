@@ -0,0 +1,20 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x1::object",
+  structs: [
+    {
+      abilities: ["copy", "drop", "store"],
+      fields: [{ name: "inner", ty: "address" }],
+      name: "0x1::object::Object",
+      type_params: [{ is_phantom: true, name: "T" }],
+    },
+  ],
+} as const;
@@ -0,0 +1,21 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x1::option",
+  structs: [
+    {
+      abilities: ["copy", "drop", "store"],
+      fields: [{ name: "vec", ty: { vector: { type_param: 0 } } }],
+      name: "0x1::option::Option",
+      type_params: [{ name: "Element" }],
+    },
+  ],
+};
+exports.idl = idl;
@@ -0,0 +1,25 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x1::option";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["copy", "drop", "store"];
+      readonly fields: readonly [
+        {
+          readonly name: "vec";
+          readonly ty: { readonly vector: { readonly type_param: 0 } };
+        }
+      ];
+      readonly name: "0x1::option::Option";
+      readonly type_params: readonly [{ readonly name: "Element" }];
+    }
+  ];
+};
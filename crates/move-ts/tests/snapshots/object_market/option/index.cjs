@@ -0,0 +1,53 @@
+/**
+ * **Module ID:** `0x1::option`
+ *
+ * @module
+ */
+
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0x1";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0x1::option";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "option";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+/** Module error codes. */
+const errorCodes = {};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = {};
+exports.resources = resources;
+
+/** All struct types. */
+const structs = { Option: "0x1::option::Option" };
+exports.structs = structs;
+
+/** Payload generators for module `0x1::option`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
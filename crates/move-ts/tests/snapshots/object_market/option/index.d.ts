@@ -0,0 +1,55 @@
+/**
+ * **Module ID:** `0x1::option`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Type name: `0x1::option::Option` */
+export interface IOption<_Element = unknown> {
+  vec: ReadonlyArray<_Element>;
+}
+
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export declare const ADDRESS: "0x1";
+
+/** The full module name. */
+export declare const FULL_NAME: "0x1::option";
+
+/** The name of the module. */
+export declare const NAME: "option";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+/** Module error codes. */
+export declare const errorCodes: {};
+
+/** All module function IDLs. */
+export declare const functions: {};
+
+/** All struct types with ability `key`. */
+export declare const resources: {};
+
+/** All struct types. */
+export declare const structs: { readonly Option: "0x1::option::Option" };
+
+/** Payload generators for module `0x1::option`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+export declare const moduleDefinition: typeof moduleImpl;
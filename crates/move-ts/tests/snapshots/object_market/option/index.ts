@@ -0,0 +1,54 @@
+/**
+ * **Module ID:** `0x1::option`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Type name: `0x1::option::Option` */
+export interface IOption<_Element = unknown> {
+  vec: ReadonlyArray<_Element>;
+}
+
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x1" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x1::option" as const;
+
+/** The name of the module. */
+export const NAME = "option" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+/** Module error codes. */
+export const errorCodes = {} as const;
+
+/** All module function IDLs. */
+export const functions = {} as const;
+
+/** All struct types with ability `key`. */
+export const resources = {} as const;
+
+/** All struct types. */
+export const structs = { Option: "0x1::option::Option" } as const;
+
+/** Payload generators for module `0x1::option`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x1",
+  "option"
+> as typeof moduleImpl;
@@ -0,0 +1,20 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x1::string",
+  structs: [
+    {
+      abilities: ["copy", "drop", "store"],
+      fields: [{ name: "bytes", ty: { vector: "u8" } }],
+      name: "0x1::string::String",
+    },
+  ],
+};
+exports.idl = idl;
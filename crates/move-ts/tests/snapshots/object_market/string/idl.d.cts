@@ -0,0 +1,21 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x1::string";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["copy", "drop", "store"];
+      readonly fields: readonly [
+        { readonly name: "bytes"; readonly ty: { readonly vector: "u8" } }
+      ];
+      readonly name: "0x1::string::String";
+    }
+  ];
+};
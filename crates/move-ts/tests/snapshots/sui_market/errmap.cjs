@@ -0,0 +1,19 @@
+/**
+ * Module containing all errors in this package.
+ *
+ * @module
+ */
+
+const p = require("./prelude.cjs");
+
+/** All errors in this package, keyed by module ID and error code. */
+const errmap = {};
+exports.errmap = errmap;
+
+/**
+ * Decodes the abort of a VM status into the error of this package which caused it.
+ *
+ * Returns `null` if the transaction did not abort in a module of this package or with an unknown code.
+ */
+const parseAbort = (vmStatus) => p.decodeAbort(errmap, vmStatus);
+exports.parseAbort = parseAbort;
@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `SuiMarket`.
+ *
+ * @module
+ */
+
+exports.sui_market_market = require("./market/index.cjs");
+exports.sui_market_object = require("./object/index.cjs");
+exports.sui_market_tx_context = require("./tx_context/index.cjs");
+exports.sui_market_errmap = require("./errmap.cjs").errmap;
+exports.sui_market_parse_abort = require("./errmap.cjs").parseAbort;
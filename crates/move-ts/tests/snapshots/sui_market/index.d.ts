@@ -0,0 +1,11 @@
+/**
+ * This module contains generated types and helper functions for the package `SuiMarket`.
+ *
+ * @module
+ */
+
+export * as sui_market_market from "./market/index.js";
+export * as sui_market_object from "./object/index.js";
+export * as sui_market_tx_context from "./tx_context/index.js";
+export { errmap as sui_market_errmap } from "./errmap.js";
+export { parseAbort as sui_market_parse_abort } from "./errmap.js";
@@ -0,0 +1,18 @@
+/**
+ * Builders of the Move calls of the entry functions, with BCS-encoded pure arguments.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+
+/** Length of an account address in bytes. */
+declare const ADDRESS_LENGTH: 32;
+
+/**
+ * Move call of `0x3::market::claim`, with BCS-encoded pure arguments.
+ *
+ * Claims a ticket.
+ */
+export declare const claim: ({ args }: mod.ClaimArgs) => p.MoveCall;
@@ -0,0 +1,24 @@
+/**
+ * Builders of the Move calls of the entry functions, with BCS-encoded pure arguments.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32;
+
+/**
+ * Move call of `0x3::market::claim`, with BCS-encoded pure arguments.
+ *
+ * Claims a ticket.
+ */
+export const claim = ({ args }) => ({
+  target: "0x3::market::claim",
+  typeArguments: [],
+  arguments: [
+    { object: p.serializers.hexString(args.ticket) },
+    { pure: p.bcs.address(args.receipt, ADDRESS_LENGTH) },
+  ],
+});
@@ -0,0 +1,25 @@
+/**
+ * Builders of the Move calls of the entry functions, with BCS-encoded pure arguments.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+
+/** Length of an account address in bytes. */
+const ADDRESS_LENGTH = 32 as const;
+
+/**
+ * Move call of `0x3::market::claim`, with BCS-encoded pure arguments.
+ *
+ * Claims a ticket.
+ */
+export const claim = ({ args }: mod.ClaimArgs): p.MoveCall => ({
+  target: "0x3::market::claim",
+  typeArguments: [],
+  arguments: [
+    { object: p.serializers.hexString(args.ticket) },
+    { pure: p.bcs.address(args.receipt, ADDRESS_LENGTH) },
+  ],
+});
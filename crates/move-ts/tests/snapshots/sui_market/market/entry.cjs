@@ -0,0 +1,19 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+const p = require("../prelude.cjs");
+
+/** Claims a ticket. */
+const claim = ({ args }) => ({
+  type: "script_function_payload",
+  function: "0x3::market::claim",
+  type_arguments: [],
+  arguments: [
+    p.serializers.hexString(args.ticket),
+    p.serializers.hexString(args.receipt),
+  ],
+});
+exports.claim = claim;
@@ -0,0 +1,12 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.cjs";
+import * as mod from "./index.cjs";
+import * as payloads from "./payloads.cjs";
+
+/** Claims a ticket. */
+export declare const claim: ({ args }: mod.ClaimArgs) => payloads.Claim;
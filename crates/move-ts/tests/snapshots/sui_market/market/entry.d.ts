@@ -0,0 +1,12 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Claims a ticket. */
+export declare const claim: ({ args }: mod.ClaimArgs) => payloads.Claim;
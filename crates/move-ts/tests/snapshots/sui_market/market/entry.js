@@ -0,0 +1,18 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Claims a ticket. */
+export const claim = ({ args }) => ({
+  type: "script_function_payload",
+  function: "0x3::market::claim",
+  type_arguments: [],
+  arguments: [
+    p.serializers.hexString(args.ticket),
+    p.serializers.hexString(args.receipt),
+  ],
+});
@@ -0,0 +1,20 @@
+/**
+ * Entrypoint builders.
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+import * as mod from "./index.js";
+import * as payloads from "./payloads.js";
+
+/** Claims a ticket. */
+export const claim = ({ args }: mod.ClaimArgs): payloads.Claim => ({
+  type: "script_function_payload",
+  function: "0x3::market::claim",
+  type_arguments: [],
+  arguments: [
+    p.serializers.hexString(args.ticket),
+    p.serializers.hexString(args.receipt),
+  ],
+});
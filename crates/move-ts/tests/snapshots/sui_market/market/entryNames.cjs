@@ -0,0 +1,9 @@
+/**
+ * Names of all script functions.
+ *
+ * @module
+ */
+
+/** Script function type for `0x3::market::claim`. */
+const claim = "0x3::market::claim";
+exports.claim = claim;
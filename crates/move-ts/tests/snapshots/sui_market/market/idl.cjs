@@ -0,0 +1,36 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  doc: "A market of Sui objects.",
+  errors: {},
+  functions: [
+    {
+      args: [
+        { name: "ticket", ty: { struct: { name: "0x3::market::Ticket" } } },
+        { name: "receipt", ty: { struct: { name: "0x2::object::ID" } } },
+        { name: "ctx", ty: { struct: { name: "0x2::tx_context::TxContext" } } },
+      ],
+      doc: "Claims a ticket.",
+      name: "claim",
+      ty_args: [],
+    },
+  ],
+  module_id: "0x3::market",
+  structs: [
+    {
+      abilities: ["store", "key"],
+      doc: "A Sui object.",
+      fields: [
+        { name: "id", ty: { struct: { name: "0x2::object::UID" } } },
+        { name: "seat", ty: "u64" },
+      ],
+      name: "0x3::market::Ticket",
+    },
+  ],
+};
+exports.idl = idl;
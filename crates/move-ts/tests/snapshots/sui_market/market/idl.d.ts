@@ -0,0 +1,55 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly doc: "A market of Sui objects.";
+  readonly errors: {};
+  readonly functions: readonly [
+    {
+      readonly args: readonly [
+        {
+          readonly name: "ticket";
+          readonly ty: {
+            readonly struct: { readonly name: "0x3::market::Ticket" };
+          };
+        },
+        {
+          readonly name: "receipt";
+          readonly ty: {
+            readonly struct: { readonly name: "0x2::object::ID" };
+          };
+        },
+        {
+          readonly name: "ctx";
+          readonly ty: {
+            readonly struct: { readonly name: "0x2::tx_context::TxContext" };
+          };
+        }
+      ];
+      readonly doc: "Claims a ticket.";
+      readonly name: "claim";
+      readonly ty_args: readonly [];
+    }
+  ];
+  readonly module_id: "0x3::market";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["store", "key"];
+      readonly doc: "A Sui object.";
+      readonly fields: readonly [
+        {
+          readonly name: "id";
+          readonly ty: {
+            readonly struct: { readonly name: "0x2::object::UID" };
+          };
+        },
+        { readonly name: "seat"; readonly ty: "u64" }
+      ];
+      readonly name: "0x3::market::Ticket";
+    }
+  ];
+};
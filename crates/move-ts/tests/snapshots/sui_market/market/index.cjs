@@ -0,0 +1,71 @@
+/**
+ * A market of Sui objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+exports.entry = require("./entry.cjs");
+exports.payloads = require("./payloads.cjs");
+exports.entryNames = require("./entryNames.cjs");
+exports.bcs = require("./bcs.cjs");
+exports.idl = require("./idl.cjs").idl;
+
+/** The address of the module. */
+const ADDRESS = "0x3";
+exports.ADDRESS = ADDRESS;
+
+/** The full module name. */
+const FULL_NAME = "0x3::market";
+exports.FULL_NAME = FULL_NAME;
+
+/** The name of the module. */
+const NAME = "market";
+exports.NAME = NAME;
+
+/** Module ID information. */
+const id = { ADDRESS, FULL_NAME, NAME };
+exports.id = id;
+
+/** Module error codes. */
+const errorCodes = {};
+exports.errorCodes = errorCodes;
+
+/** All module function IDLs. */
+const functions = {
+  claim: {
+    args: [
+      { name: "ticket", ty: { struct: { name: "0x3::market::Ticket" } } },
+      { name: "receipt", ty: { struct: { name: "0x2::object::ID" } } },
+      { name: "ctx", ty: { struct: { name: "0x2::tx_context::TxContext" } } },
+    ],
+    doc: "Claims a ticket.",
+    name: "claim",
+    ty_args: [],
+  },
+};
+exports.functions = functions;
+
+/** All struct types with ability `key`. */
+const resources = { Ticket: "0x3::market::Ticket" };
+exports.resources = resources;
+
+/** All struct types. */
+const structs = { Ticket: "0x3::market::Ticket" };
+exports.structs = structs;
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A market of Sui objects. */
+const moduleDefinition = moduleImpl;
+exports.moduleDefinition = moduleDefinition;
@@ -0,0 +1,102 @@
+/**
+ * A market of Sui objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+import * as mod_object from "../object/index.cjs";
+import * as p from "../prelude.cjs";
+
+/**
+ * A Sui object.
+ *
+ * Type name: `0x3::market::Ticket`
+ */
+export interface ITicket {
+  id: mod_object.IUID;
+  seat: p.U64;
+}
+
+/** Payload arguments for {@link entry.claim}. */
+export type ClaimArgs = {
+  args: {
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000003, module: Identifier("market"), name: Identifier("Ticket"), type_params: [] }), ty_args: [] })` */
+    ticket: p.RawAddress;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000002, module: Identifier("object"), name: Identifier("ID"), type_params: [] }), ty_args: [] })` */
+    receipt: p.RawAddress;
+  };
+};
+
+export * as entry from "./entry.cjs";
+export * as payloads from "./payloads.cjs";
+export * as entryNames from "./entryNames.cjs";
+export * as bcs from "./bcs.cjs";
+export { idl } from "./idl.cjs";
+
+/** The address of the module. */
+export declare const ADDRESS: "0x3";
+
+/** The full module name. */
+export declare const FULL_NAME: "0x3::market";
+
+/** The name of the module. */
+export declare const NAME: "market";
+
+/** Module ID information. */
+export declare const id: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+};
+
+/** Module error codes. */
+export declare const errorCodes: {};
+
+/** All module function IDLs. */
+export declare const functions: {
+  readonly claim: {
+    readonly args: readonly [
+      {
+        readonly name: "ticket";
+        readonly ty: {
+          readonly struct: { readonly name: "0x3::market::Ticket" };
+        };
+      },
+      {
+        readonly name: "receipt";
+        readonly ty: { readonly struct: { readonly name: "0x2::object::ID" } };
+      },
+      {
+        readonly name: "ctx";
+        readonly ty: {
+          readonly struct: { readonly name: "0x2::tx_context::TxContext" };
+        };
+      }
+    ];
+    readonly doc: "Claims a ticket.";
+    readonly name: "claim";
+    readonly ty_args: readonly [];
+  };
+};
+
+/** All struct types with ability `key`. */
+export declare const resources: { readonly Ticket: "0x3::market::Ticket" };
+
+/** All struct types. */
+export declare const structs: { readonly Ticket: "0x3::market::Ticket" };
+
+/** Payload generators for module `0x3::market`. */
+declare const moduleImpl: {
+  readonly ADDRESS: typeof ADDRESS;
+  readonly FULL_NAME: typeof FULL_NAME;
+  readonly NAME: typeof NAME;
+  readonly errorCodes: typeof errorCodes;
+  readonly functions: typeof functions;
+  readonly resources: typeof resources;
+  readonly structs: typeof structs;
+};
+
+/** A market of Sui objects. */
+export declare const moduleDefinition: typeof moduleImpl;
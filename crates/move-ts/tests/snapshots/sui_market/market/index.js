@@ -0,0 +1,62 @@
+/**
+ * A market of Sui objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x3";
+
+/** The full module name. */
+export const FULL_NAME = "0x3::market";
+
+/** The name of the module. */
+export const NAME = "market";
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME };
+
+/** Module error codes. */
+export const errorCodes = {};
+
+/** All module function IDLs. */
+export const functions = {
+  claim: {
+    args: [
+      { name: "ticket", ty: { struct: { name: "0x3::market::Ticket" } } },
+      { name: "receipt", ty: { struct: { name: "0x2::object::ID" } } },
+      { name: "ctx", ty: { struct: { name: "0x2::tx_context::TxContext" } } },
+    ],
+    doc: "Claims a ticket.",
+    name: "claim",
+    ty_args: [],
+  },
+};
+
+/** All struct types with ability `key`. */
+export const resources = { Ticket: "0x3::market::Ticket" };
+
+/** All struct types. */
+export const structs = { Ticket: "0x3::market::Ticket" };
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+};
+
+/** A market of Sui objects. */
+export const moduleDefinition = moduleImpl;
@@ -0,0 +1,88 @@
+/**
+ * A market of Sui objects.
+ *
+ * **Module ID:** `0x3::market`
+ *
+ * @module
+ */
+
+import * as mod_object from "../object/index.js";
+import * as p from "../prelude.js";
+
+/**
+ * A Sui object.
+ *
+ * Type name: `0x3::market::Ticket`
+ */
+export interface ITicket {
+  id: mod_object.IUID;
+  seat: p.U64;
+}
+
+/** Payload arguments for {@link entry.claim}. */
+export type ClaimArgs = {
+  args: {
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000003, module: Identifier("market"), name: Identifier("Ticket"), type_params: [] }), ty_args: [] })` */
+    ticket: p.RawAddress;
+    /** IDL type: `Struct(IDLStructType { name: StructTagData(StructTag { address: 00000000000000000000000000000002, module: Identifier("object"), name: Identifier("ID"), type_params: [] }), ty_args: [] })` */
+    receipt: p.RawAddress;
+  };
+};
+
+export * as entry from "./entry.js";
+export * as payloads from "./payloads.js";
+export * as entryNames from "./entryNames.js";
+export * as bcs from "./bcs.js";
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x3" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x3::market" as const;
+
+/** The name of the module. */
+export const NAME = "market" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+/** Module error codes. */
+export const errorCodes = {} as const;
+
+/** All module function IDLs. */
+export const functions = {
+  claim: {
+    args: [
+      { name: "ticket", ty: { struct: { name: "0x3::market::Ticket" } } },
+      { name: "receipt", ty: { struct: { name: "0x2::object::ID" } } },
+      { name: "ctx", ty: { struct: { name: "0x2::tx_context::TxContext" } } },
+    ],
+    doc: "Claims a ticket.",
+    name: "claim",
+    ty_args: [],
+  },
+} as const;
+
+/** All struct types with ability `key`. */
+export const resources = { Ticket: "0x3::market::Ticket" } as const;
+
+/** All struct types. */
+export const structs = { Ticket: "0x3::market::Ticket" } as const;
+
+/** Payload generators for module `0x3::market`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+/** A market of Sui objects. */
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x3",
+  "market"
+> as typeof moduleImpl;
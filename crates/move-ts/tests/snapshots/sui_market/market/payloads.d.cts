@@ -0,0 +1,17 @@
+/**
+ * Entrypoint script function payloads.
+ *
+ * @module
+ */
+
+/**
+ * Script function payload for `0x3::market::claim`.
+ *
+ * Claims a ticket.
+ */
+export type Claim = {
+  readonly type: "script_function_payload";
+  readonly function: "0x3::market::claim";
+  readonly arguments: [ticket: string, receipt: string];
+  readonly type_arguments: [];
+};
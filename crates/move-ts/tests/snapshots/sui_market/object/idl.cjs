@@ -0,0 +1,25 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x2::object",
+  structs: [
+    {
+      abilities: ["store"],
+      fields: [{ name: "id", ty: { struct: { name: "0x2::object::ID" } } }],
+      name: "0x2::object::UID",
+    },
+    {
+      abilities: ["copy", "drop", "store"],
+      fields: [{ name: "bytes", ty: "address" }],
+      name: "0x2::object::ID",
+    },
+  ],
+};
+exports.idl = idl;
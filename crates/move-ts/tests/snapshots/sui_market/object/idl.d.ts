@@ -0,0 +1,33 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x2::object";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["store"];
+      readonly fields: readonly [
+        {
+          readonly name: "id";
+          readonly ty: {
+            readonly struct: { readonly name: "0x2::object::ID" };
+          };
+        }
+      ];
+      readonly name: "0x2::object::UID";
+    },
+    {
+      readonly abilities: readonly ["copy", "drop", "store"];
+      readonly fields: readonly [
+        { readonly name: "bytes"; readonly ty: "address" }
+      ];
+      readonly name: "0x2::object::ID";
+    }
+  ];
+};
@@ -0,0 +1,62 @@
+/**
+ * **Module ID:** `0x2::object`
+ *
+ * @module
+ */
+
+import * as p from "../prelude.js";
+
+/** Type name: `0x2::object::UID` */
+export interface IUID {
+  id: IID;
+}
+
+/** Type name: `0x2::object::ID` */
+export interface IID {
+  bytes: p.RawAddress;
+}
+
+export { idl } from "./idl.js";
+
+/** The address of the module. */
+export const ADDRESS = "0x2" as const;
+
+/** The full module name. */
+export const FULL_NAME = "0x2::object" as const;
+
+/** The name of the module. */
+export const NAME = "object" as const;
+
+/** Module ID information. */
+export const id = { ADDRESS, FULL_NAME, NAME } as const;
+
+/** Module error codes. */
+export const errorCodes = {} as const;
+
+/** All module function IDLs. */
+export const functions = {} as const;
+
+/** All struct types with ability `key`. */
+export const resources = {} as const;
+
+/** All struct types. */
+export const structs = {
+  ID: "0x2::object::ID",
+  UID: "0x2::object::UID",
+} as const;
+
+/** Payload generators for module `0x2::object`. */
+const moduleImpl = {
+  ADDRESS,
+  FULL_NAME,
+  NAME,
+  errorCodes,
+  functions,
+  resources,
+  structs,
+} as const;
+
+export const moduleDefinition = moduleImpl as p.MoveModuleDefinition<
+  "0x2",
+  "object"
+> as typeof moduleImpl;
@@ -0,0 +1,20 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+const idl = {
+  errors: {},
+  functions: [],
+  module_id: "0x2::tx_context",
+  structs: [
+    {
+      abilities: ["drop"],
+      fields: [{ name: "sender", ty: "address" }],
+      name: "0x2::tx_context::TxContext",
+    },
+  ],
+};
+exports.idl = idl;
@@ -0,0 +1,21 @@
+/**
+ * The IDL of the module.
+ *
+ * @module
+ */
+
+/** The IDL of the module. */
+export declare const idl: {
+  readonly errors: {};
+  readonly functions: readonly [];
+  readonly module_id: "0x2::tx_context";
+  readonly structs: readonly [
+    {
+      readonly abilities: readonly ["drop"];
+      readonly fields: readonly [
+        { readonly name: "sender"; readonly ty: "address" }
+      ];
+      readonly name: "0x2::tx_context::TxContext";
+    }
+  ];
+};
@@ -0,0 +1,104 @@
+//! Tests of the templates laying out the generated files.
+
+mod common;
+
+use anyhow::*;
+use common::load_fixture;
+use move_ts::{
+    emit::{EmitTarget, ModuleFormat},
+    idl_package::IDLPackageGenerator,
+    templates::Templates,
+};
+use std::collections::BTreeMap;
+
+/// A module index exporting its ID and a custom definition instead of `moduleDefinition`.
+const MODULE_INDEX: &str = r#"{header}
+
+{imports}
+
+{sections.types}
+
+{sections.id}
+
+{{ if target.typescript }}
+/** Custom definition of `{module.id}`. */
+{{ endif }}
+export const definition = \{ address: ADDRESS, name: NAME, structs: {module.idl.structs | json} };
+"#;
+
+fn generate_with(templates: Templates, target: EmitTarget) -> Result<BTreeMap<String, String>> {
+    let idl = load_fixture("coin_demo")?.idl;
+    Ok(IDLPackageGenerator::new(&idl, true)
+        .with_target(target)
+        .with_templates(templates)
+        .generate_all()?
+        .into_iter()
+        .map(|(path, code)| (path.to_string_lossy().replace('\\', "/"), code.to_string()))
+        .collect())
+}
+
+#[test]
+fn module_index_template_overrides_layout() -> Result<()> {
+    let templates = Templates::new().with_template("module/index", MODULE_INDEX)?;
+    let files = generate_with(templates, EmitTarget::TypeScript)?;
+    let index = files.get("vault/index.ts").context("missing index")?;
+    assert!(index.contains("/** Custom definition of `0xa::vault`. */"));
+    assert!(index.contains("export const definition = { address: ADDRESS, name: NAME, structs: "));
+    assert!(index.contains("export const ADDRESS = \"0xa\" as const;"));
+    assert!(!index.contains("moduleImpl"));
+    assert!(!index.contains("\n\n\n"));
+
+    // Files without a template of their own are unchanged.
+    let defaults = generate_with(Templates::new(), EmitTarget::TypeScript)?;
+    for (path, code) in files
+        .iter()
+        .filter(|(path, _)| !path.ends_with("/index.ts"))
+    {
+        assert_eq!(&defaults[path], code, "{}", path);
+    }
+    Ok(())
+}
+
+#[test]
+fn templates_branch_on_target() -> Result<()> {
+    let templates = Templates::new().with_template("module/index", MODULE_INDEX)?;
+    let files = generate_with(templates, EmitTarget::JavaScript(ModuleFormat::Esm))?;
+    let index = files.get("vault/index.js").context("missing index")?;
+    assert!(!index.contains("Custom definition"));
+    assert!(index.contains("export const definition = "));
+    Ok(())
+}
+
+#[test]
+fn templates_are_loaded_from_dir() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("move-ts-templates-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("module"))?;
+    std::fs::write(dir.join("module").join("index.tpl"), MODULE_INDEX)?;
+    std::fs::write(
+        dir.join("errmap.tpl"),
+        "{header}\n\n{body}\n\n// {package.name}\n",
+    )?;
+    std::fs::write(dir.join("notes.txt"), "not a template")?;
+    let templates = Templates::new().with_dir(&dir);
+    std::fs::remove_dir_all(&dir)?;
+
+    let templates = templates?;
+    assert_eq!(
+        templates.names(),
+        vec!["errmap", "file", "index", "module/index", "readme"]
+    );
+    assert_eq!(templates.get("module/index"), Some(MODULE_INDEX));
+
+    let files = generate_with(templates, EmitTarget::TypeScript)?;
+    let errmap = files.get("errmap.ts").context("missing errmap")?;
+    assert!(errmap.ends_with("\n\n// CoinDemo\n"));
+    Ok(())
+}
+
+#[test]
+fn invalid_templates_are_rejected() {
+    assert!(Templates::new().with_template("file", "{{ if }}").is_err());
+    assert!(Templates::new()
+        .with_template("file", "{{ for x }}")
+        .is_err());
+}
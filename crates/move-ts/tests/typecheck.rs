@@ -0,0 +1,149 @@
+//! Type-checks the code generated for every fixture with the TypeScript compiler.
+//!
+//! The code is checked once with the bundled prelude, and once importing `@movingco/prelude`,
+//! against the declarations of the published package in `published_prelude.d.ts`, so that the
+//! default output cannot rely on helpers only the bundled prelude provides.
+//!
+//! The compiler is taken from the `TSC` environment variable, or `tsc` on the `PATH`. The test
+//! is skipped if no compiler is available, unless the `CI` environment variable is set: CI
+//! installs the compiler, so a missing compiler there is an error rather than a silent pass.
+
+mod common;
+
+use anyhow::*;
+use common::{bundled, fixture_names, generate_with_prelude, load_fixture};
+use move_ts::{
+    emit::{EmitTarget, ModuleFormat},
+    ordering::DeclarationOrder,
+    prelude::{bundled_prelude, PreludeConfig, BUNDLED_PRELUDE_NAME, DEFAULT_PRELUDE_PACKAGE},
+};
+use serde_json::json;
+use std::{path::Path, process::Command};
+
+/// Targets checked by the compiler. JavaScript is covered by its declarations.
+const TARGETS: &[EmitTarget] = &[
+    EmitTarget::TypeScript,
+    EmitTarget::Declarations(ModuleFormat::Esm),
+];
+
+/// Declarations of the published prelude package.
+const PUBLISHED_PRELUDE: &str = include_str!("published_prelude.d.ts");
+
+/// Preludes the code is checked with, named after how they are provided.
+fn preludes() -> [(&'static str, PreludeConfig); 2] {
+    [
+        ("bundled", bundled()),
+        ("package", PreludeConfig::default()),
+    ]
+}
+
+/// Returns the command of the TypeScript compiler, if it is available.
+fn find_tsc() -> Option<String> {
+    let tsc = std::env::var("TSC").unwrap_or_else(|_| "tsc".to_string());
+    let output = Command::new(&tsc).arg("--version").output().ok()?;
+    if output.status.success() {
+        Some(tsc)
+    } else {
+        None
+    }
+}
+
+/// Writes the generated files of a fixture along with their prelude: the bundled prelude, or
+/// the declarations of the published package.
+fn write_project(dir: &Path, name: &str, target: EmitTarget, prelude: PreludeConfig) -> Result<()> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)?;
+    }
+    let fixture = load_fixture(name)?;
+    let is_bundled = prelude.is_bundled();
+    for (path, code) in
+        generate_with_prelude(&fixture, target, DeclarationOrder::default(), prelude)?
+    {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, code)?;
+    }
+
+    if is_bundled {
+        std::fs::write(
+            dir.join(format!("{}.{}", BUNDLED_PRELUDE_NAME, target.extension())),
+            bundled_prelude(target),
+        )?;
+    } else {
+        let package = dir.join("node_modules").join(DEFAULT_PRELUDE_PACKAGE);
+        std::fs::create_dir_all(&package)?;
+        std::fs::write(package.join("index.d.ts"), PUBLISHED_PRELUDE)?;
+    }
+
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2020",
+            "module": "ES2020",
+            "moduleResolution": "node",
+            "strict": true,
+            "noEmit": true,
+        },
+        "include": ["**/*.ts"],
+        "exclude": ["node_modules"],
+    });
+    std::fs::write(
+        dir.join("tsconfig.json"),
+        serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+    Ok(())
+}
+
+#[test]
+fn generated_code_type_checks() -> Result<()> {
+    let tsc = match find_tsc() {
+        Some(tsc) => tsc,
+        None if std::env::var_os("CI").is_some() => {
+            bail!("tsc not found, set TSC to the TypeScript compiler")
+        }
+        None => {
+            eprintln!("skipping type check: tsc not found, set TSC to the TypeScript compiler");
+            return Ok(());
+        }
+    };
+
+    let mut failures = vec![];
+    for name in fixture_names()? {
+        for target in TARGETS {
+            for (mode, prelude) in preludes() {
+                let dir = std::env::temp_dir().join(format!(
+                    "move-ts-typecheck-{}-{}-{}-{}",
+                    std::process::id(),
+                    name,
+                    target.extension(),
+                    mode
+                ));
+                write_project(&dir, &name, *target, prelude)?;
+                let output = Command::new(&tsc)
+                    .arg("--project")
+                    .arg(&dir)
+                    .output()
+                    .with_context(|| format!("running {}", tsc))?;
+                if output.status.success() {
+                    std::fs::remove_dir_all(&dir)?;
+                } else {
+                    failures.push(format!(
+                        "{} ({}, {} prelude) in {}:\n{}{}",
+                        name,
+                        target.extension(),
+                        mode,
+                        dir.display(),
+                        String::from_utf8_lossy(&output.stdout),
+                        String::from_utf8_lossy(&output.stderr)
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "generated code does not type check:\n\n{}",
+        failures.join("\n")
+    );
+    Ok(())
+}
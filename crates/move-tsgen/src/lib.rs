@@ -3,9 +3,33 @@ use std::{collections::BTreeMap, path::PathBuf};
 
 use anyhow::*;
 use json_cli::{CliTool, CliTypedResult};
-use move_idl::IDLBuilder;
+use move_idl::{IDLBuilder, IDLPackage};
 use move_package::BuildConfig;
-use move_ts::{idl_package::IDLPackageGenerator, Codegen};
+use move_ts::{
+    chain::Chain,
+    emit::OutputFormat,
+    format::{FormatOptions, QuoteStyle, TrailingCommas},
+    generator::GeneratorRegistry,
+    idl_constant::IDLConstants,
+    idl_enum::IDLEnums,
+    idl_package::IDLPackageGenerator,
+    idl_view::IDLViews,
+    module_filter::ModuleFilter,
+    naming::{Case, NamingStrategy},
+    ordering::DeclarationOrder,
+    prelude::{PreludeConfig, PreludeSource},
+    templates::Templates,
+    CodeText,
+};
+
+/// Language of the generated files.
+#[derive(clap::ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    /// TypeScript sources.
+    Ts,
+    /// JavaScript with TypeScript declaration files.
+    Js,
+}
 
 /// Parses a Move workspace into a set of IDLs.
 #[derive(clap::Parser)]
@@ -21,6 +45,221 @@ pub struct MoveTSGenTool {
     /// Whether to generate module TypeScript files for dependencies.
     #[clap(short, long)]
     pub with_dependencies: bool,
+
+    /// Only generate dependency modules matching this pattern, e.g. `0x1::coin` or `std::*`.
+    #[clap(long)]
+    pub include: Vec<String>,
+
+    /// Skip modules matching this pattern, e.g. `*_tests`.
+    #[clap(long)]
+    pub exclude: Vec<String>,
+
+    /// Module specifier to import the prelude runtime from.
+    #[clap(long, default_value = move_ts::prelude::DEFAULT_PRELUDE_PACKAGE)]
+    pub prelude_import: String,
+
+    /// Namespace the prelude is imported as in generated code.
+    #[clap(long, default_value = move_ts::prelude::DEFAULT_PRELUDE_NAMESPACE)]
+    pub prelude_namespace: String,
+
+    /// Whether to emit a self-contained `prelude.ts` instead of importing the prelude package.
+    #[clap(long)]
+    pub bundle_prelude: bool,
+
+    /// Casing of entry function builders: preserve, camel, pascal or snake.
+    #[clap(long, default_value = "preserve")]
+    pub function_case: Case,
+
+    /// Casing of struct fields and entry function arguments: preserve, camel, pascal or snake.
+    #[clap(long, default_value = "preserve")]
+    pub field_case: Case,
+
+    /// Casing of the module exports of the package index: preserve, camel, pascal or snake.
+    #[clap(long, default_value = "preserve")]
+    pub module_case: Case,
+
+    /// Prefix of generated struct interfaces.
+    #[clap(long, default_value = "I")]
+    pub struct_type_prefix: String,
+
+    /// Order of generated modules and declarations: declaration or name.
+    #[clap(long, default_value = "declaration")]
+    pub order: DeclarationOrder,
+
+    /// Chain whose entry functions are called by the generated code: aptos or sui.
+    #[clap(long, default_value = "aptos")]
+    pub chain: Chain,
+
+    /// Number of spaces per indentation level of generated code.
+    #[clap(long, default_value = "2")]
+    pub indent_width: usize,
+
+    /// Maximum line width of generated code.
+    #[clap(long, default_value = "80")]
+    pub line_width: usize,
+
+    /// Quotes of generated string literals: double or single.
+    #[clap(long, default_value = "double")]
+    pub quote_style: QuoteStyle,
+
+    /// Trailing commas in multi-line lists of generated code: all, es5 or none.
+    #[clap(long, default_value = "es5")]
+    pub trailing_commas: TrailingCommas,
+
+    /// Language of the generated files.
+    #[clap(long, arg_enum, default_value = "ts")]
+    pub lang: Language,
+
+    /// Whether to also emit CommonJS modules when generating JavaScript.
+    #[clap(long)]
+    pub commonjs: bool,
+
+    /// Whether to emit a `package.json`, `tsconfig.json` and `README.md` so the output can be published.
+    #[clap(long)]
+    pub emit_package: bool,
+
+    /// Version of the npm package written by `--emit-package`.
+    #[clap(long, default_value = "0.1.0")]
+    pub package_version: String,
+
+    /// Name of a registered generator of additional output for each module.
+    #[clap(long = "generator")]
+    pub generators: Vec<String>,
+
+    /// Generators which can be selected with `--generator`. Tools embedding the CLI register
+    /// their generators here.
+    #[clap(skip)]
+    pub registry: GeneratorRegistry,
+
+    /// Directory of templates overriding the default layout of the generated files, e.g.
+    /// `module/index.tpl`.
+    #[clap(long)]
+    pub templates: Option<PathBuf>,
+
+    /// Whether the package index exports a client class calling the entry functions and
+    /// reading the resources of every generated module. Requires `--bundle-prelude`.
+    #[clap(long)]
+    pub client: bool,
+
+    /// Whether each error constant gets a subclass of `MoveAbortError`, which can be checked
+    /// with `instanceof`. Requires `--bundle-prelude`.
+    #[clap(long)]
+    pub error_classes: bool,
+
+    /// JSON file of the module constants other than error codes, keyed by module ID, e.g.
+    /// `{"0x1::coin": [{"name": "MAX_SUPPLY", "doc": null, "ty": "u64", "value": "1000"}]}`.
+    #[clap(long)]
+    pub constants: Option<PathBuf>,
+
+    /// JSON file of the Move 2 enums of the modules, keyed by module ID, e.g.
+    /// `{"0x1::coin": [{"name": "0x1::coin::Status", "doc": null, "variants": [{"name":
+    /// "Active", "doc": null, "fields": []}]}]}`.
+    #[clap(long)]
+    pub enums: Option<PathBuf>,
+
+    /// JSON file of the view functions of the modules, keyed by module ID, called by the client
+    /// of `--client`, e.g. `{"0x1::coin": [{"name": "balance", "doc": null, "ty_args":
+    /// ["CoinType"], "args": [{"name": "owner", "ty": "address"}], "returns": ["u64"]}]}`.
+    #[clap(long)]
+    pub views: Option<PathBuf>,
+}
+
+impl MoveTSGenTool {
+    fn prelude_config(&self) -> PreludeConfig {
+        PreludeConfig {
+            source: if self.bundle_prelude {
+                PreludeSource::Bundled
+            } else {
+                PreludeSource::Package(self.prelude_import.clone())
+            },
+            namespace: self.prelude_namespace.clone(),
+        }
+    }
+
+    fn module_filter(&self, idl: &IDLPackage) -> Result<ModuleFilter> {
+        ModuleFilter {
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+        }
+        .resolve(idl)
+    }
+
+    fn naming_strategy(&self) -> NamingStrategy {
+        NamingStrategy {
+            functions: self.function_case,
+            fields: self.field_case,
+            modules: self.module_case,
+            struct_type_prefix: self.struct_type_prefix.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn format_options(&self) -> FormatOptions {
+        FormatOptions {
+            indent_width: self.indent_width,
+            line_width: self.line_width,
+            quote_style: self.quote_style,
+            trailing_commas: self.trailing_commas,
+        }
+    }
+
+    fn package_generator<'a>(&self, idl: &'a IDLPackage) -> Result<IDLPackageGenerator<'a>> {
+        let mut package_gen = IDLPackageGenerator::new_filtered(
+            idl,
+            self.with_dependencies,
+            &self.module_filter(idl)?,
+        )
+        .with_prelude(self.prelude_config())
+        .with_naming(self.naming_strategy())
+        .with_format(self.format_options())
+        .with_order(self.order)
+        .with_chain(self.chain)
+        .with_client(self.client)
+        .with_error_classes(self.error_classes);
+        if let Some(path) = &self.constants {
+            let constants: IDLConstants = serde_json::from_str(&std::fs::read_to_string(path)?)
+                .with_context(|| format!("parsing {}", path.display()))?;
+            package_gen = package_gen.with_constants(constants);
+        }
+        if let Some(path) = &self.enums {
+            let enums: IDLEnums = serde_json::from_str(&std::fs::read_to_string(path)?)
+                .with_context(|| format!("parsing {}", path.display()))?;
+            package_gen = package_gen.with_enums(enums);
+        }
+        if let Some(path) = &self.views {
+            let views: IDLViews = serde_json::from_str(&std::fs::read_to_string(path)?)
+                .with_context(|| format!("parsing {}", path.display()))?;
+            package_gen = package_gen.with_views(views);
+        }
+        if let Some(dir) = &self.templates {
+            package_gen = package_gen.with_templates(Templates::new().with_dir(dir)?);
+        }
+        for name in self.generators.iter() {
+            package_gen = package_gen.with_generator(self.registry.get(name)?);
+        }
+        Ok(package_gen)
+    }
+
+    fn output_format(&self) -> OutputFormat {
+        match self.lang {
+            Language::Ts => OutputFormat::TypeScript,
+            Language::Js => OutputFormat::JavaScript {
+                commonjs: self.commonjs,
+            },
+        }
+    }
+
+    /// Writes files keyed by path relative to the output directory.
+    fn write_files(&self, files: BTreeMap<PathBuf, CodeText>) -> Result<()> {
+        for (path, code) in files {
+            let path = self.out_dir.join(path);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, code)?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -39,51 +278,16 @@ impl CliTool<()> for MoveTSGenTool {
 
         std::fs::create_dir_all(&self.out_dir)?;
 
-        let package_gen = IDLPackageGenerator::new(&idl, self.with_dependencies);
-        for gen in package_gen.module_generators() {
-            let module_dir = &self.out_dir.join(gen.module.module_id.name().to_string());
-            std::fs::create_dir_all(module_dir)?;
-
-            if gen.has_entrypoints() {
-                std::fs::write(
-                    module_dir.join("entry").with_extension("ts"),
-                    gen.generate_entrypoint_module(&package_gen.ctx)?,
-                )?;
-                std::fs::write(
-                    module_dir.join("entryNames").with_extension("ts"),
-                    gen.generate_entry_names_module(&package_gen.ctx)?,
-                )?;
-                std::fs::write(
-                    module_dir.join("payloads").with_extension("ts"),
-                    gen.generate_entry_payloads_module(&package_gen.ctx)?,
-                )?;
-            }
-
-            std::fs::write(
-                module_dir.join("idl").with_extension("ts"),
-                gen.generate_idl_module()?,
-            )?;
-
-            if let Some(errors_module) = gen.generate_errors_module()? {
-                std::fs::write(
-                    module_dir.join("errors").with_extension("ts"),
-                    errors_module,
-                )?;
-            }
-
-            let ts = gen.module.generate_typescript(&package_gen.ctx)?;
-            std::fs::write(module_dir.join("index").with_extension("ts"), ts)?;
+        let format = self.output_format();
+        for target in format.targets() {
+            let package_gen = self.package_generator(&idl)?.with_target(target);
+            self.write_files(package_gen.generate_all()?)?;
         }
 
-        std::fs::write(
-            self.out_dir.join("errmap").with_extension("ts"),
-            &package_gen.generate_errmap_module()?,
-        )?;
-
-        std::fs::write(
-            self.out_dir.join("index").with_extension("ts"),
-            package_gen.generate_index()?,
-        )?;
+        if self.emit_package {
+            let package_gen = self.package_generator(&idl)?;
+            self.write_files(package_gen.generate_package_files(&self.package_version, format)?)?;
+        }
 
         Ok(())
     }